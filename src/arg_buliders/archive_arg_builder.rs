@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct ArchiveArgBuilder;
+
+impl CommandBuilder for ArchiveArgBuilder {
+    /// Adds the `--archive` argument to the given command.
+    ///
+    /// The `--archive` argument requires a non-empty string value representing the path of a
+    /// `.tar`, `.tar.gz`, or `.zip` archive. Each file in the archive is searched as its own source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::archive_arg_builder::ArchiveArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// ArchiveArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--archive` argument should be added to the command.
+    /// let archive_arg = cmd.get_arguments().find(|arg| arg.get_id() == "archive");
+    /// assert!(archive_arg.is_some(), "The 'archive' argument was not added.");
+    ///
+    /// // The `--archive` argument should accept an archive path.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--archive", "bundle.zip"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("archive").unwrap(), "bundle.zip");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("archive")
+                .long("archive")
+                .value_name("ARCHIVE_PATH")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Search each file in a tar or zip archive, named like archive.zip!/path"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn archive_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        ArchiveArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--archive"]);
+        assert!(
+            matches.is_err(),
+            "The 'archive' argument did not require a value."
+        );
+    }
+}
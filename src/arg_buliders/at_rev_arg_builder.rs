@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct AtRevArgBuilder;
+
+impl CommandBuilder for AtRevArgBuilder {
+    /// Adds the `--at-rev` argument to the given command.
+    ///
+    /// The `--at-rev` argument requires a git revision, like a commit, tag, or branch. The files
+    /// given with `--dir` or `--files` are then searched as they were at that revision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::at_rev_arg_builder::AtRevArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// AtRevArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--at-rev` argument should be added to the command.
+    /// let at_rev_arg = cmd.get_arguments().find(|arg| arg.get_id() == "at-rev");
+    /// assert!(at_rev_arg.is_some(), "The 'at-rev' argument was not added.");
+    ///
+    /// // The `--at-rev` argument should accept a revision.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--at-rev", "v1.0"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("at-rev").unwrap(), "v1.0");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("at-rev")
+                .long("at-rev")
+                .value_name("REV")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Search --dir or --files as they were at a git revision"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn at_rev_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        AtRevArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--at-rev"]);
+        assert!(
+            matches.is_err(),
+            "The 'at-rev' argument did not require a value."
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct BalancedArgBuilder;
+
+impl CommandBuilder for BalancedArgBuilder {
+    /// Adds the `--balanced` argument to the given command.
+    ///
+    /// The `--balanced` argument is a flag that, when provided, pairs `--find_between` delimiters by nesting depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::balanced_arg_builder::BalancedArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// BalancedArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--balanced` argument should be added to the command.
+    /// let balanced_arg = cmd.get_arguments().find(|arg| arg.get_id() == "balanced");
+    /// assert!(balanced_arg.is_some(), "The 'balanced' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--balanced` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--balanced"]).unwrap();
+    /// assert!(matches.get_one::<bool>("balanced").copied().unwrap_or(false), "The 'balanced' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("balanced")
+                .long("balanced")
+                .help("Skip over nested delimiters, string literals and escapes in find_between")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct BinaryArgBuilder;
+
+impl CommandBuilder for BinaryArgBuilder {
+    /// Adds the `--binary` argument to the given command.
+    ///
+    /// The `--binary` argument decides what happens to sources that look binary, because their
+    /// leading bytes hold a NUL byte or invalid UTF-8. It accepts `skip`, `text`, or `report`,
+    /// and defaults to `skip`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::binary_arg_builder::BinaryArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// BinaryArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--binary` argument should be added to the command.
+    /// let binary_arg = cmd.get_arguments().find(|arg| arg.get_id() == "binary");
+    /// assert!(binary_arg.is_some(), "The 'binary' argument was not added.");
+    ///
+    /// // The `--binary` argument should accept a policy.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--binary", "report"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("binary").unwrap(), "report");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("binary")
+                .long("binary")
+                .value_name("POLICY")
+                .value_parser(["skip", "text", "report"])
+                .default_value("skip")
+                .help("Skip binary files, search them as text, or only report that they match"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn binary_argument_throws_error_without_a_value_given() {
+        let mut cmd = Command::new("test_command");
+        BinaryArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--binary"]);
+        assert!(
+            matches.is_err(),
+            "The 'binary' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn binary_argument_requires_a_known_policy() {
+        let mut cmd = Command::new("test_command");
+        BinaryArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--binary", "ignore"]);
+        assert!(
+            matches.is_err(),
+            "The 'binary' argument accepted an unknown policy."
+        );
+    }
+}
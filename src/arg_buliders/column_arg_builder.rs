@@ -0,0 +1,77 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use crate::strategies::enums::column::Column;
+use clap::{Arg, Command};
+
+pub struct ColumnArgBuilder;
+
+impl CommandBuilder for ColumnArgBuilder {
+    /// Adds the `--column` argument to the given command.
+    ///
+    /// The `--column` argument takes the name of a column in the header row, or its index counting from 1,
+    /// and matches search patterns only within that column of a `--csv` or `--tsv` source.
+    /// An empty name and the index 0 are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::column_arg_builder::ColumnArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    /// use crate::strategies::enums::column::Column;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// ColumnArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--column` argument should be added to the command.
+    /// let column_arg = cmd.get_arguments().find(|arg| arg.get_id() == "column");
+    /// assert!(column_arg.is_some(), "The 'column' argument was not added.");
+    ///
+    /// // The `--column` argument should accept a name.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--column", "email"]).unwrap();
+    /// assert_eq!(matches.get_one::<Column>("column").unwrap(), &Column::Name(String::from("email")));
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("column")
+                .long("column")
+                .value_name("NAME|INDEX")
+                .value_parser(clap::value_parser!(Column))
+                .help("Search only this column of a CSV or TSV source, by header name or index from 1"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn column_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        ColumnArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--column"]);
+        assert!(
+            matches.is_err(),
+            "The 'column' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn column_argument_takes_an_index_from_1() {
+        let mut cmd = Command::new("test_command");
+        ColumnArgBuilder::build(&mut cmd);
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--column", "2"])
+            .unwrap();
+        assert_eq!(matches.get_one::<Column>("column"), Some(&Column::Index(2)));
+
+        let error = cmd
+            .try_get_matches_from(vec!["test_command", "--column", "0"])
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("The column index must be at least 1"));
+    }
+}
@@ -27,7 +27,7 @@ impl CommandBuilder for CountBySourceArgBuilder {
     /// assert!(matches.get_one::<bool>("count_by_source").copied().unwrap_or(false), "The 'count_by_source' argument did not return true.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("count_by_source")
                 .long("count_by_source")
                 .help("Count matches for each source")
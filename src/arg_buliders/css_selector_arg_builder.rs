@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct CssSelectorArgBuilder;
+
+impl CommandBuilder for CssSelectorArgBuilder {
+    /// Adds the `--css-selector` argument to the given command.
+    ///
+    /// The `--css-selector` argument requires a CSS selector, like `a.external::attr(href)`.
+    /// The element text or attribute values it selects in HTML or XML sources are found, and edits change only those values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::css_selector_arg_builder::CssSelectorArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// CssSelectorArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--css-selector` argument should be added to the command.
+    /// let css_selector_arg = cmd.get_arguments().find(|arg| arg.get_id() == "css-selector");
+    /// assert!(css_selector_arg.is_some(), "The 'css-selector' argument was not added.");
+    ///
+    /// // The `--css-selector` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--css-selector", "a.external::attr(href)"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("css-selector").unwrap(), "a.external::attr(href)");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("css-selector")
+                .long("css-selector")
+                .value_name("CSS_SELECTOR")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the element text or ::attr(name) values a CSS selector matches in HTML or XML sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn css_selector_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        CssSelectorArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--css-selector"]);
+        assert!(
+            matches.is_err(),
+            "The 'css-selector' argument did not require a value."
+        );
+    }
+}
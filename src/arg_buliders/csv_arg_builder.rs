@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct CsvArgBuilder;
+
+impl CommandBuilder for CsvArgBuilder {
+    /// Adds the `--csv` argument to the given command.
+    ///
+    /// The `--csv` argument is a flag that, when provided, parses each source as comma-separated values and matches search patterns within each field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::csv_arg_builder::CsvArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// CsvArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--csv` argument should be added to the command.
+    /// let csv_arg = cmd.get_arguments().find(|arg| arg.get_id() == "csv");
+    /// assert!(csv_arg.is_some(), "The 'csv' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--csv` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--csv"]).unwrap();
+    /// assert!(matches.get_one::<bool>("csv").copied().unwrap_or(false), "The 'csv' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("csv")
+                .long("csv")
+                .help("Parse sources as CSV and match within each field")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -33,7 +33,7 @@ impl CommandBuilder for DirArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("dir")
                 .long("dir")
                 .value_name("DIR_PATH")
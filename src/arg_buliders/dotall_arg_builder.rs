@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct DotallArgBuilder;
+
+impl CommandBuilder for DotallArgBuilder {
+    /// Adds the `--dotall` argument to the given command.
+    ///
+    /// The `--dotall` argument is a flag that, when provided, lets regex patterns match across lines, with `.` also matching line terminators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::dotall_arg_builder::DotallArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// DotallArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--dotall` argument should be added to the command.
+    /// let dotall_arg = cmd.get_arguments().find(|arg| arg.get_id() == "dotall");
+    /// assert!(dotall_arg.is_some(), "The 'dotall' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--dotall` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--dotall"]).unwrap();
+    /// assert!(matches.get_one::<bool>("dotall").copied().unwrap_or(false), "The 'dotall' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("dotall")
+                .long("dotall")
+                .help("Match regexes across lines, with . also matching line terminators")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -27,7 +27,7 @@ impl CommandBuilder for EditInPlaceArgBuilder {
     /// assert!(matches.get_one::<bool>("edit_in_place").copied().unwrap_or(false), "The 'edit_in_place' argument did not return true.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("edit_in_place")
                 .long("edit_in_place")
                 .help("Edit files in place")
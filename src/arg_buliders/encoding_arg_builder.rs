@@ -0,0 +1,76 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct EncodingArgBuilder;
+
+impl CommandBuilder for EncodingArgBuilder {
+    /// Adds the `--encoding` argument to the given command.
+    ///
+    /// The `--encoding` argument sets the encoding sources are read and written in. It accepts `auto`,
+    /// `utf-8`, `utf-16le`, `utf-16be`, `latin1`, or `windows-1252`, and defaults to `auto`,
+    /// which detects UTF-8 and UTF-16 from the byte order mark and otherwise reads UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::encoding_arg_builder::EncodingArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// EncodingArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--encoding` argument should be added to the command.
+    /// let encoding_arg = cmd.get_arguments().find(|arg| arg.get_id() == "encoding");
+    /// assert!(encoding_arg.is_some(), "The 'encoding' argument was not added.");
+    ///
+    /// // The `--encoding` argument should accept an encoding.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--encoding", "utf-16le"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("encoding").unwrap(), "utf-16le");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("encoding")
+                .long("encoding")
+                .value_name("ENCODING")
+                .value_parser([
+                    "auto",
+                    "utf-8",
+                    "utf-16le",
+                    "utf-16be",
+                    "latin1",
+                    "windows-1252",
+                ])
+                .default_value("auto")
+                .help("Read and write sources in this encoding, detected from the BOM by default"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn encoding_argument_throws_error_without_a_value_given() {
+        let mut cmd = Command::new("test_command");
+        EncodingArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--encoding"]);
+        assert!(
+            matches.is_err(),
+            "The 'encoding' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn encoding_argument_requires_a_known_encoding() {
+        let mut cmd = Command::new("test_command");
+        EncodingArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--encoding", "ebcdic"]);
+        assert!(
+            matches.is_err(),
+            "The 'encoding' argument accepted an unknown encoding."
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct EolArgBuilder;
+
+impl CommandBuilder for EolArgBuilder {
+    /// Adds the `--eol` argument to the given command.
+    ///
+    /// The `--eol` argument decides the line endings of edited sources. It accepts `preserve`,
+    /// `lf`, or `crlf`, and defaults to `preserve`, which keeps every byte outside of the edits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::eol_arg_builder::EolArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// EolArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--eol` argument should be added to the command.
+    /// let eol_arg = cmd.get_arguments().find(|arg| arg.get_id() == "eol");
+    /// assert!(eol_arg.is_some(), "The 'eol' argument was not added.");
+    ///
+    /// // The `--eol` argument should accept a line ending mode.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--eol", "crlf"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("eol").unwrap(), "crlf");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("eol")
+                .long("eol")
+                .value_name("MODE")
+                .value_parser(["preserve", "lf", "crlf"])
+                .default_value("preserve")
+                .help("Keep the line endings of edited files, or convert them to LF or CRLF"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn eol_argument_throws_error_without_a_value_given() {
+        let mut cmd = Command::new("test_command");
+        EolArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--eol"]);
+        assert!(
+            matches.is_err(),
+            "The 'eol' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn eol_argument_requires_a_known_mode() {
+        let mut cmd = Command::new("test_command");
+        EolArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--eol", "cr"]);
+        assert!(
+            matches.is_err(),
+            "The 'eol' argument accepted an unknown mode."
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub struct EveryNthArgBuilder;
 impl CommandBuilder for EveryNthArgBuilder {
     /// Adds the `--every_nth` argument to the given command.
     ///
-    /// The `--every_nth` argument requires an integer value greater than or equal to 0.
+    /// The `--every_nth` argument requires an integer value greater than or equal to 1.
     ///
     /// # Examples
     ///
@@ -33,12 +33,12 @@ impl CommandBuilder for EveryNthArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("every_nth")
                 .long("every_nth")
                 .value_name("N")
-                .value_parser(clap::value_parser!(u64).range(0..))
-                .help("Process every nth match"),
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("Process every nth match, counting from 1"),
         );
     }
 }
@@ -69,4 +69,15 @@ mod tests {
             "The 'every_nth' argument accepted an invalid value."
         );
     }
+
+    #[test]
+    fn every_nth_argument_rejects_zero() {
+        let mut cmd = Command::new("test_command");
+        EveryNthArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--every_nth", "0"]);
+        assert!(
+            matches.is_err(),
+            "The 'every_nth' argument accepted 0, but hits are counted from 1."
+        );
+    }
 }
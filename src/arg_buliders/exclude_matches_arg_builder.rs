@@ -27,7 +27,7 @@ impl CommandBuilder for ExcludeMatchesArgBuilder {
     /// assert!(matches.get_one::<bool>("exclude_matches").copied().unwrap_or(false), "The 'exclude_matches' argument did not return true.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("exclude_matches")
                 .long("exclude_matches")
                 .help("Exclude all matches")
@@ -34,7 +34,7 @@ impl CommandBuilder for FilesArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("files")
                 .long("files")
                 .value_name("FILES")
@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct FilesFromArgBuilder;
+
+impl CommandBuilder for FilesFromArgBuilder {
+    /// Adds the `--files-from` argument to the given command.
+    ///
+    /// The `--files-from` argument requires the path of a file that lists the files to search,
+    /// one per line, or `-` to read the list from stdin. Unlike `--files`, paths may hold commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::files_from_arg_builder::FilesFromArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// FilesFromArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--files-from` argument should be added to the command.
+    /// let files_from_arg = cmd.get_arguments().find(|arg| arg.get_id() == "files-from");
+    /// assert!(files_from_arg.is_some(), "The 'files-from' argument was not added.");
+    ///
+    /// // The `--files-from` argument should accept `-` for stdin.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--files-from", "-"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("files-from").unwrap(), "-");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("files-from")
+                .long("files-from")
+                .value_name("FILE")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Search the files listed in a file, or in stdin with '-'"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn files_from_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        FilesFromArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--files-from"]);
+        assert!(
+            matches.is_err(),
+            "The 'files-from' argument did not require a value."
+        );
+    }
+}
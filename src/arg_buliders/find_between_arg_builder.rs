@@ -30,7 +30,7 @@ impl CommandBuilder for FindBetweenArgBuilder {
     /// assert_eq!(values, vec!["start", "end"], "The 'find_between' argument did not capture the correct values.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("find_between")
                 .long("find_between")
                 .value_names(["START", "END"])
@@ -1,5 +1,5 @@
 use crate::handlers::build_command_handler::CommandBuilder;
-use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+use clap::{builder::NonEmptyStringValueParser, Arg, ArgAction, Command};
 
 pub struct FindRegexArgBuilder;
 
@@ -7,6 +7,7 @@ impl CommandBuilder for FindRegexArgBuilder {
     /// Adds the `--find_regex` argument to the given command.
     ///
     /// The `--find_regex` argument requires a non-empty string value to be provided.
+    /// It may be repeated to search for every value in the same scan.
     ///
     /// # Examples
     ///
@@ -33,12 +34,13 @@ impl CommandBuilder for FindRegexArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("find_regex")
                 .long("find_regex")
                 .value_name("REGEX_PATTERN")
                 .value_parser(NonEmptyStringValueParser::new())
-                .help("Find matches using the given regex pattern"),
+                .action(ArgAction::Append)
+                .help("Find matches using the given regex pattern, repeatable"),
         );
     }
 }
@@ -60,4 +62,22 @@ mod tests {
             "The 'find_regex' argument did not require a value."
         );
     }
+
+    #[test]
+    fn find_regex_argument_can_be_repeated() {
+        let mut cmd = Command::new("test_command");
+        FindRegexArgBuilder::build(&mut cmd);
+
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test_command",
+                "--find_regex",
+                "a",
+                "--find_regex",
+                "b",
+            ])
+            .unwrap();
+        let values: Vec<&String> = matches.get_many::<String>("find_regex").unwrap().collect();
+        assert_eq!(values, vec!["a", "b"]);
+    }
 }
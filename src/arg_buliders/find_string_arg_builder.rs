@@ -1,5 +1,5 @@
 use crate::handlers::build_command_handler::CommandBuilder;
-use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+use clap::{builder::NonEmptyStringValueParser, Arg, ArgAction, Command};
 
 pub struct FindStringArgBuilder;
 
@@ -7,6 +7,7 @@ impl CommandBuilder for FindStringArgBuilder {
     /// Adds the `--find_string` argument to the given command.
     ///
     /// The `--find_string` argument requires a non-empty string value to be provided.
+    /// It may be repeated to search for every value in the same scan.
     ///
     /// # Examples
     ///
@@ -33,12 +34,13 @@ impl CommandBuilder for FindStringArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("find_string")
                 .long("find_string")
                 .value_name("STRING_VALUE")
                 .value_parser(NonEmptyStringValueParser::new())
-                .help("Find matches using the given string value"),
+                .action(ArgAction::Append)
+                .help("Find matches using the given string value, repeatable"),
         );
     }
 }
@@ -60,4 +62,22 @@ mod tests {
             "The 'find_string' argument did not require a value."
         );
     }
+
+    #[test]
+    fn find_string_argument_can_be_repeated() {
+        let mut cmd = Command::new("test_command");
+        FindStringArgBuilder::build(&mut cmd);
+
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test_command",
+                "--find_string",
+                "a",
+                "--find_string",
+                "b",
+            ])
+            .unwrap();
+        let values: Vec<&String> = matches.get_many::<String>("find_string").unwrap().collect();
+        assert_eq!(values, vec!["a", "b"]);
+    }
 }
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct GitChangedArgBuilder;
+
+impl CommandBuilder for GitChangedArgBuilder {
+    /// Adds the `--git-changed` argument to the given command.
+    ///
+    /// The `--git-changed` argument is a flag that, when provided, searches the files changed in the working copy since the last commit, including untracked files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::git_changed_arg_builder::GitChangedArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitChangedArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--git-changed` argument should be added to the command.
+    /// let git_changed_arg = cmd.get_arguments().find(|arg| arg.get_id() == "git-changed");
+    /// assert!(git_changed_arg.is_some(), "The 'git-changed' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--git-changed` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--git-changed"]).unwrap();
+    /// assert!(matches.get_one::<bool>("git-changed").copied().unwrap_or(false), "The 'git-changed' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("git-changed")
+                .long("git-changed")
+                .help("Search the files changed since the last commit, including untracked files")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
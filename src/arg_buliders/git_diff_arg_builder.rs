@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct GitDiffArgBuilder;
+
+impl CommandBuilder for GitDiffArgBuilder {
+    /// Adds the `--git-diff` argument to the given command.
+    ///
+    /// The `--git-diff` argument requires a git revision range, like `main..HEAD`,
+    /// and searches the files changed in that range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::git_diff_arg_builder::GitDiffArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitDiffArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--git-diff` argument should be added to the command.
+    /// let git_diff_arg = cmd.get_arguments().find(|arg| arg.get_id() == "git-diff");
+    /// assert!(git_diff_arg.is_some(), "The 'git-diff' argument was not added.");
+    ///
+    /// // The `--git-diff` argument should accept a revision range.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--git-diff", "v1..HEAD"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("git-diff").unwrap(), "v1..HEAD");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("git-diff")
+                .long("git-diff")
+                .value_name("REV..REV")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Search the files changed in a git revision range"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn git_diff_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        GitDiffArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--git-diff"]);
+        assert!(
+            matches.is_err(),
+            "The 'git-diff' argument did not require a value."
+        );
+    }
+}
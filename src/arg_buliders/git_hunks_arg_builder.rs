@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct GitHunksArgBuilder;
+
+impl CommandBuilder for GitHunksArgBuilder {
+    /// Adds the `--git-hunks` argument to the given command.
+    ///
+    /// The `--git-hunks` argument is a flag that, when provided, searches only the lines added by the git changes, instead of the whole changed files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::git_hunks_arg_builder::GitHunksArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitHunksArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--git-hunks` argument should be added to the command.
+    /// let git_hunks_arg = cmd.get_arguments().find(|arg| arg.get_id() == "git-hunks");
+    /// assert!(git_hunks_arg.is_some(), "The 'git-hunks' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--git-hunks` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--git-hunks"]).unwrap();
+    /// assert!(matches.get_one::<bool>("git-hunks").copied().unwrap_or(false), "The 'git-hunks' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("git-hunks")
+                .long("git-hunks")
+                .help("Search only the lines added by the git changes")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
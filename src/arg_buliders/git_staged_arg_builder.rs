@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct GitStagedArgBuilder;
+
+impl CommandBuilder for GitStagedArgBuilder {
+    /// Adds the `--git-staged` argument to the given command.
+    ///
+    /// The `--git-staged` argument is a flag that, when provided, searches the files with changes staged for the next commit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::git_staged_arg_builder::GitStagedArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitStagedArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--git-staged` argument should be added to the command.
+    /// let git_staged_arg = cmd.get_arguments().find(|arg| arg.get_id() == "git-staged");
+    /// assert!(git_staged_arg.is_some(), "The 'git-staged' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--git-staged` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--git-staged"]).unwrap();
+    /// assert!(matches.get_one::<bool>("git-staged").copied().unwrap_or(false), "The 'git-staged' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("git-staged")
+                .long("git-staged")
+                .help("Search the files with staged changes")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct GlobalArgBuilder;
+
+impl CommandBuilder for GlobalArgBuilder {
+    /// Adds the `--global` argument to the given command.
+    ///
+    /// The `--global` argument is a flag that, when provided, counts frequency across all sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::global_arg_builder::GlobalArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GlobalArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--global` argument should be added to the command.
+    /// let global_arg = cmd.get_arguments().find(|arg| arg.get_id() == "global");
+    /// assert!(global_arg.is_some(), "The 'global' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--global` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--global"]).unwrap();
+    /// assert!(matches.get_one::<bool>("global").copied().unwrap_or(false), "The 'global' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("global")
+                .long("global")
+                .help("Apply the frequency across all sources (default)")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
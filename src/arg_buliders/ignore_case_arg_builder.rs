@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct IgnoreCaseArgBuilder;
+
+impl CommandBuilder for IgnoreCaseArgBuilder {
+    /// Adds the `--ignore-case` argument to the given command.
+    ///
+    /// The `--ignore-case` argument is a flag that, when provided, matches search patterns without regard to case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::ignore_case_arg_builder::IgnoreCaseArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// IgnoreCaseArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--ignore-case` argument should be added to the command.
+    /// let ignore_case_arg = cmd.get_arguments().find(|arg| arg.get_id() == "ignore-case");
+    /// assert!(ignore_case_arg.is_some(), "The 'ignore-case' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--ignore-case` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--ignore-case"]).unwrap();
+    /// assert!(matches.get_one::<bool>("ignore-case").copied().unwrap_or(false), "The 'ignore-case' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("ignore-case")
+                .long("ignore-case")
+                .help("Match search patterns without regard to case")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct InvertArgBuilder;
+
+impl CommandBuilder for InvertArgBuilder {
+    /// Adds the `--invert` argument to the given command.
+    ///
+    /// The `--invert` argument is a flag that, when provided, selects the lines without a hit, so the edit stage operates on those lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::invert_arg_builder::InvertArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// InvertArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--invert` argument should be added to the command.
+    /// let invert_arg = cmd.get_arguments().find(|arg| arg.get_id() == "invert");
+    /// assert!(invert_arg.is_some(), "The 'invert' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--invert` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--invert"]).unwrap();
+    /// assert!(matches.get_one::<bool>("invert").copied().unwrap_or(false), "The 'invert' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("invert")
+                .long("invert")
+                .help("Select the lines without a hit, like grep -v")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct JsonPathArgBuilder;
+
+impl CommandBuilder for JsonPathArgBuilder {
+    /// Adds the `--json-path` argument to the given command.
+    ///
+    /// The `--json-path` argument requires a JSONPath, like `$.services[*].image`.
+    /// The values it selects in JSON sources are found, and edits change only those values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::json_path_arg_builder::JsonPathArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// JsonPathArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--json-path` argument should be added to the command.
+    /// let json_path_arg = cmd.get_arguments().find(|arg| arg.get_id() == "json-path");
+    /// assert!(json_path_arg.is_some(), "The 'json-path' argument was not added.");
+    ///
+    /// // The `--json-path` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--json-path", "$.services[*].image"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("json-path").unwrap(), "$.services[*].image");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("json-path")
+                .long("json-path")
+                .value_name("JSON_PATH")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the values a JSONPath selects in JSON sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn json_path_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        JsonPathArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--json-path"]);
+        assert!(
+            matches.is_err(),
+            "The 'json-path' argument did not require a value."
+        );
+    }
+}
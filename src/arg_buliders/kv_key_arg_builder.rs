@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct KvKeyArgBuilder;
+
+impl CommandBuilder for KvKeyArgBuilder {
+    /// Adds the `--kv-key` argument to the given command.
+    ///
+    /// The `--kv-key` argument requires a key, like `DATABASE_URL`.
+    /// Its values in `.env`, INI, or `.properties` sources are found, and edits change only those values, never comments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::kv_key_arg_builder::KvKeyArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// KvKeyArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--kv-key` argument should be added to the command.
+    /// let kv_key_arg = cmd.get_arguments().find(|arg| arg.get_id() == "kv-key");
+    /// assert!(kv_key_arg.is_some(), "The 'kv-key' argument was not added.");
+    ///
+    /// // The `--kv-key` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--kv-key", "DATABASE_URL"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("kv-key").unwrap(), "DATABASE_URL");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("kv-key")
+                .long("kv-key")
+                .value_name("KEY")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the values of a key in .env, INI, or .properties sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn kv_key_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        KvKeyArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--kv-key"]);
+        assert!(
+            matches.is_err(),
+            "The 'kv-key' argument did not require a value."
+        );
+    }
+}
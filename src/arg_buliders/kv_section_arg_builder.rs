@@ -0,0 +1,57 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct KvSectionArgBuilder;
+
+impl CommandBuilder for KvSectionArgBuilder {
+    /// Adds the `--kv-section` argument to the given command.
+    ///
+    /// The `--kv-section` argument requires the name of an INI section, like `database`.
+    /// Only the values of `--kv-key` under that section's header are found.
+    /// Without it, only the values before the first section header are found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::kv_section_arg_builder::KvSectionArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// KvSectionArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--kv-section` argument should be added to the command.
+    /// let kv_section_arg = cmd.get_arguments().find(|arg| arg.get_id() == "kv-section");
+    /// assert!(kv_section_arg.is_some(), "The 'kv-section' argument was not added.");
+    ///
+    /// // The `--kv-section` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--kv-section", "database"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("kv-section").unwrap(), "database");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("kv-section")
+                .long("kv-section")
+                .value_name("SECTION")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find --kv-key values only within this INI section"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn kv_section_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        KvSectionArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--kv-section"]);
+        assert!(
+            matches.is_err(),
+            "The 'kv-section' argument did not require a value."
+        );
+    }
+}
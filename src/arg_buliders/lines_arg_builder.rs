@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct LinesArgBuilder;
+
+impl CommandBuilder for LinesArgBuilder {
+    /// Adds the `--lines` argument to the given command.
+    ///
+    /// The `--lines` argument is a flag that, when provided, matches search patterns within each line and reports each matching line as a hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::lines_arg_builder::LinesArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// LinesArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--lines` argument should be added to the command.
+    /// let lines_arg = cmd.get_arguments().find(|arg| arg.get_id() == "lines");
+    /// assert!(lines_arg.is_some(), "The 'lines' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--lines` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--lines"]).unwrap();
+    /// assert!(matches.get_one::<bool>("lines").copied().unwrap_or(false), "The 'lines' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("lines")
+                .long("lines")
+                .help("Match within each line and report whole lines, like grep")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -33,7 +33,7 @@ impl CommandBuilder for MaxDepthArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("max-depth")
                 .long("max-depth")
                 .value_name("MAX_DEPTH")
@@ -0,0 +1,38 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct MmapArgBuilder;
+
+impl CommandBuilder for MmapArgBuilder {
+    /// Adds the `--mmap` argument to the given command.
+    ///
+    /// The `--mmap` argument is a flag that, when provided, memory-maps every file source instead of reading it into memory.
+    /// Files edited with `--edit_in_place` are still read, since writing them would change the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::mmap_arg_builder::MmapArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// MmapArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--mmap` argument should be added to the command.
+    /// let mmap_arg = cmd.get_arguments().find(|arg| arg.get_id() == "mmap");
+    /// assert!(mmap_arg.is_some(), "The 'mmap' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--mmap` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--mmap"]).unwrap();
+    /// assert!(matches.get_one::<bool>("mmap").copied().unwrap_or(false), "The 'mmap' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("mmap")
+                .long("mmap")
+                .help("Memory-map file sources instead of reading them, whatever their size")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -1,37 +1,117 @@
 pub mod all_arg_builder;
 pub mod append_arg_builder;
+pub mod archive_arg_builder;
+pub mod at_rev_arg_builder;
+pub mod balanced_arg_builder;
+pub mod binary_arg_builder;
+pub mod column_arg_builder;
 pub mod count_arg_builder;
 pub mod count_by_source_arg_builder;
+pub mod css_selector_arg_builder;
+pub mod csv_arg_builder;
 pub mod dir_arg_builder;
+pub mod dotall_arg_builder;
 pub mod edit_in_place_arg_builder;
+pub mod encoding_arg_builder;
+pub mod eol_arg_builder;
 pub mod every_nth_arg_builder;
 pub mod exclude_matches_arg_builder;
 pub mod file_arg_builder;
 pub mod files_arg_builder;
+pub mod files_from_arg_builder;
 pub mod find_between_arg_builder;
 pub mod find_regex_arg_builder;
 pub mod find_string_arg_builder;
+pub mod git_changed_arg_builder;
+pub mod git_diff_arg_builder;
+pub mod git_hunks_arg_builder;
+pub mod git_staged_arg_builder;
+pub mod global_arg_builder;
+pub mod ignore_case_arg_builder;
+pub mod invert_arg_builder;
+pub mod json_path_arg_builder;
+pub mod kv_key_arg_builder;
+pub mod kv_section_arg_builder;
+pub mod lines_arg_builder;
 pub mod max_depth_arg_builder;
+pub mod mmap_arg_builder;
+pub mod multiline_arg_builder;
+pub mod no_header_arg_builder;
+pub mod no_mmap_arg_builder;
 pub mod nth_arg_builder;
+pub mod null_arg_builder;
+pub mod patterns_file_arg_builder;
+pub mod per_source_arg_builder;
 pub mod prepend_arg_builder;
+pub mod quotes_arg_builder;
 pub mod replace_with_arg_builder;
+pub mod search_zip_arg_builder;
+pub mod smart_case_arg_builder;
+pub mod stdin_arg_builder;
+pub mod stream_arg_builder;
 pub mod text_arg_builder;
+pub mod threads_arg_builder;
+pub mod toml_key_arg_builder;
+pub mod tsv_arg_builder;
+pub mod word_arg_builder;
+pub mod xml_path_arg_builder;
+pub mod yaml_path_arg_builder;
 
 pub use all_arg_builder::AllArgBuilder;
 pub use append_arg_builder::AppendArgBuilder;
+pub use archive_arg_builder::ArchiveArgBuilder;
+pub use at_rev_arg_builder::AtRevArgBuilder;
+pub use balanced_arg_builder::BalancedArgBuilder;
+pub use binary_arg_builder::BinaryArgBuilder;
+pub use column_arg_builder::ColumnArgBuilder;
 pub use count_arg_builder::CountArgBuilder;
 pub use count_by_source_arg_builder::CountBySourceArgBuilder;
+pub use css_selector_arg_builder::CssSelectorArgBuilder;
+pub use csv_arg_builder::CsvArgBuilder;
 pub use dir_arg_builder::DirArgBuilder;
+pub use dotall_arg_builder::DotallArgBuilder;
 pub use edit_in_place_arg_builder::EditInPlaceArgBuilder;
+pub use encoding_arg_builder::EncodingArgBuilder;
+pub use eol_arg_builder::EolArgBuilder;
 pub use every_nth_arg_builder::EveryNthArgBuilder;
 pub use exclude_matches_arg_builder::ExcludeMatchesArgBuilder;
 pub use file_arg_builder::FileArgBuilder;
 pub use files_arg_builder::FilesArgBuilder;
+pub use files_from_arg_builder::FilesFromArgBuilder;
 pub use find_between_arg_builder::FindBetweenArgBuilder;
 pub use find_regex_arg_builder::FindRegexArgBuilder;
 pub use find_string_arg_builder::FindStringArgBuilder;
+pub use git_changed_arg_builder::GitChangedArgBuilder;
+pub use git_diff_arg_builder::GitDiffArgBuilder;
+pub use git_hunks_arg_builder::GitHunksArgBuilder;
+pub use git_staged_arg_builder::GitStagedArgBuilder;
+pub use global_arg_builder::GlobalArgBuilder;
+pub use ignore_case_arg_builder::IgnoreCaseArgBuilder;
+pub use invert_arg_builder::InvertArgBuilder;
+pub use json_path_arg_builder::JsonPathArgBuilder;
+pub use kv_key_arg_builder::KvKeyArgBuilder;
+pub use kv_section_arg_builder::KvSectionArgBuilder;
+pub use lines_arg_builder::LinesArgBuilder;
 pub use max_depth_arg_builder::MaxDepthArgBuilder;
+pub use mmap_arg_builder::MmapArgBuilder;
+pub use multiline_arg_builder::MultilineArgBuilder;
+pub use no_header_arg_builder::NoHeaderArgBuilder;
+pub use no_mmap_arg_builder::NoMmapArgBuilder;
 pub use nth_arg_builder::NthArgBuilder;
+pub use null_arg_builder::NullArgBuilder;
+pub use patterns_file_arg_builder::PatternsFileArgBuilder;
+pub use per_source_arg_builder::PerSourceArgBuilder;
 pub use prepend_arg_builder::PrependArgBuilder;
+pub use quotes_arg_builder::QuotesArgBuilder;
 pub use replace_with_arg_builder::ReplaceWithArgBuilder;
+pub use search_zip_arg_builder::SearchZipArgBuilder;
+pub use smart_case_arg_builder::SmartCaseArgBuilder;
+pub use stdin_arg_builder::StdinArgBuilder;
+pub use stream_arg_builder::StreamArgBuilder;
 pub use text_arg_builder::TextArgBuilder;
+pub use threads_arg_builder::ThreadsArgBuilder;
+pub use toml_key_arg_builder::TomlKeyArgBuilder;
+pub use tsv_arg_builder::TsvArgBuilder;
+pub use word_arg_builder::WordArgBuilder;
+pub use xml_path_arg_builder::XmlPathArgBuilder;
+pub use yaml_path_arg_builder::YamlPathArgBuilder;
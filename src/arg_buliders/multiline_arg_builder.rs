@@ -0,0 +1,38 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct MultilineArgBuilder;
+
+impl CommandBuilder for MultilineArgBuilder {
+    /// Adds the `--multiline` argument to the given command.
+    ///
+    /// The `--multiline` argument is a flag that, when provided, lets regex patterns match across lines, with `^` and `$` matching at the start and end of each line.
+    /// Without it or `--dotall`, regex patterns are matched within each line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::multiline_arg_builder::MultilineArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// MultilineArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--multiline` argument should be added to the command.
+    /// let multiline_arg = cmd.get_arguments().find(|arg| arg.get_id() == "multiline");
+    /// assert!(multiline_arg.is_some(), "The 'multiline' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--multiline` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--multiline"]).unwrap();
+    /// assert!(matches.get_one::<bool>("multiline").copied().unwrap_or(false), "The 'multiline' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("multiline")
+                .long("multiline")
+                .help("Match regexes across lines, with ^ and $ matching at each line")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct NoHeaderArgBuilder;
+
+impl CommandBuilder for NoHeaderArgBuilder {
+    /// Adds the `--no-header` argument to the given command.
+    ///
+    /// The `--no-header` argument is a flag that, when provided, searches the first record of a `--csv` or `--tsv` source
+    /// as data. Without it, the first record is the header and is never searched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::no_header_arg_builder::NoHeaderArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// NoHeaderArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--no-header` argument should be added to the command.
+    /// let no_header_arg = cmd.get_arguments().find(|arg| arg.get_id() == "no-header");
+    /// assert!(no_header_arg.is_some(), "The 'no-header' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--no-header` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--no-header"]).unwrap();
+    /// assert!(matches.get_one::<bool>("no-header").copied().unwrap_or(false), "The 'no-header' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("no-header")
+                .long("no-header")
+                .help("Search the first record of a CSV or TSV source as data instead of a header")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct NoMmapArgBuilder;
+
+impl CommandBuilder for NoMmapArgBuilder {
+    /// Adds the `--no-mmap` argument to the given command.
+    ///
+    /// The `--no-mmap` argument is a flag that, when provided, reads every file source into memory, even above the size at which it would be memory-mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::no_mmap_arg_builder::NoMmapArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// NoMmapArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--no-mmap` argument should be added to the command.
+    /// let no_mmap_arg = cmd.get_arguments().find(|arg| arg.get_id() == "no-mmap");
+    /// assert!(no_mmap_arg.is_some(), "The 'no-mmap' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--no-mmap` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--no-mmap"]).unwrap();
+    /// assert!(matches.get_one::<bool>("no-mmap").copied().unwrap_or(false), "The 'no-mmap' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("no-mmap")
+                .long("no-mmap")
+                .help("Read file sources into memory, even large ones that would be memory-mapped")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub struct NthArgBuilder;
 impl CommandBuilder for NthArgBuilder {
     /// Adds the `--nth` argument to the given command.
     ///
-    /// The `--nth` argument requires an integer value greater than or equal to 0.
+    /// The `--nth` argument requires an integer value greater than or equal to 1.
     ///
     /// # Examples
     ///
@@ -33,12 +33,12 @@ impl CommandBuilder for NthArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("nth")
                 .long("nth")
                 .value_name("NTH")
-                .value_parser(clap::value_parser!(u64).range(0..))
-                .help("Find/edit only the nth match"),
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("Find/edit only the nth match, counting from 1"),
         );
     }
 }
@@ -69,4 +69,15 @@ mod tests {
             "The 'nth' argument accepted an invalid value."
         );
     }
+
+    #[test]
+    fn nth_argument_rejects_zero() {
+        let mut cmd = Command::new("test_command");
+        NthArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--nth", "0"]);
+        assert!(
+            matches.is_err(),
+            "The 'nth' argument accepted 0, but hits are counted from 1."
+        );
+    }
 }
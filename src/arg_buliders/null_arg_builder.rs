@@ -0,0 +1,39 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct NullArgBuilder;
+
+impl CommandBuilder for NullArgBuilder {
+    /// Adds the `-0`/`--null` argument to the given command.
+    ///
+    /// The `--null` argument is a flag that, when provided, separates the paths read with
+    /// `--files-from` by NUL bytes instead of newlines, as `find -print0` writes them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::null_arg_builder::NullArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// NullArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--null` argument should be added to the command.
+    /// let null_arg = cmd.get_arguments().find(|arg| arg.get_id() == "null");
+    /// assert!(null_arg.is_some(), "The 'null' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `-0` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "-0"]).unwrap();
+    /// assert!(matches.get_one::<bool>("null").copied().unwrap_or(false), "The 'null' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("null")
+                .short('0')
+                .long("null")
+                .help("Read the paths given with --files-from as NUL-separated")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
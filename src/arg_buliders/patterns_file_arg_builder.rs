@@ -0,0 +1,65 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct PatternsFileArgBuilder;
+
+impl CommandBuilder for PatternsFileArgBuilder {
+    /// Adds the `--patterns-file` argument to the given command.
+    ///
+    /// The `--patterns-file` argument requires a non-empty string value representing a file path.
+    /// Each non-empty line of the file is an exact string to search for, all in the same scan.
+    /// The lines are not regexes, so characters like `.` and `*` match themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::patterns_file_arg_builder::PatternsFileArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// PatternsFileArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--patterns-file` argument should be added to the command.
+    /// let patterns_file_arg = cmd.get_arguments().find(|arg| arg.get_id() == "patterns-file");
+    /// assert!(patterns_file_arg.is_some(), "The 'patterns-file' argument was not added.");
+    ///
+    /// // The `--patterns-file` argument should accept a valid file path.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--patterns-file", "/path/to/patterns"]);
+    /// assert!(matches.is_ok(), "The 'patterns-file' argument did not accept a valid value.");
+    /// let matches = matches.unwrap();
+    /// assert_eq!(
+    ///     matches.get_one::<String>("patterns-file").unwrap(),
+    ///     "/path/to/patterns",
+    ///     "The 'patterns-file' argument did not capture the correct value."
+    /// );
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("patterns-file")
+                .long("patterns-file")
+                .value_name("FILE_PATH")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help(
+                    "Find matches for each line of the given file as an exact string, not a regex",
+                ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn patterns_file_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        PatternsFileArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--patterns-file"]);
+        assert!(
+            matches.is_err(),
+            "The 'patterns-file' argument did not require a value."
+        );
+    }
+}
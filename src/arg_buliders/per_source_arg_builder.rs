@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct PerSourceArgBuilder;
+
+impl CommandBuilder for PerSourceArgBuilder {
+    /// Adds the `--per-source` argument to the given command.
+    ///
+    /// The `--per-source` argument is a flag that, when provided, restarts the frequency count for each source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::per_source_arg_builder::PerSourceArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// PerSourceArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--per-source` argument should be added to the command.
+    /// let per_source_arg = cmd.get_arguments().find(|arg| arg.get_id() == "per-source");
+    /// assert!(per_source_arg.is_some(), "The 'per-source' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--per-source` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--per-source"]).unwrap();
+    /// assert!(matches.get_one::<bool>("per-source").copied().unwrap_or(false), "The 'per-source' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("per-source")
+                .long("per-source")
+                .help("Apply the frequency to each source separately")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
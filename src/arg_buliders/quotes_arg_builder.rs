@@ -0,0 +1,75 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use crate::strategies::find_between_strategy::DEFAULT_QUOTES;
+use clap::{Arg, Command};
+
+pub struct QuotesArgBuilder;
+
+impl CommandBuilder for QuotesArgBuilder {
+    /// Adds the `--quotes` argument to the given command.
+    ///
+    /// The `--quotes` argument requires a string value listing the characters that start string literals.
+    /// An empty string disables string literals, leaving only escapes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::quotes_arg_builder::QuotesArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// QuotesArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--quotes` argument should be added to the command.
+    /// let quotes_arg = cmd.get_arguments().find(|arg| arg.get_id() == "quotes");
+    /// assert!(quotes_arg.is_some(), "The 'quotes' argument was not added.");
+    ///
+    /// // The `--quotes` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--quotes", "\"`"]);
+    /// assert!(matches.is_ok(), "The 'quotes' argument did not accept a value.");
+    /// let matches = matches.unwrap();
+    /// assert_eq!(
+    ///     matches.get_one::<String>("quotes").unwrap(),
+    ///     "\"`",
+    ///     "The 'quotes' argument did not capture the correct value."
+    /// );
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("quotes")
+                .long("quotes")
+                .value_name("QUOTE_CHARS")
+                .default_value(DEFAULT_QUOTES)
+                .help("Set the characters that start string literals in balanced mode"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn quotes_argument_throws_error_without_a_value_given() {
+        let mut cmd = Command::new("test_command");
+        QuotesArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--quotes"]);
+        assert!(
+            matches.is_err(),
+            "The 'quotes' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn quotes_argument_defaults_to_double_and_single_quotes() {
+        let mut cmd = Command::new("test_command");
+        QuotesArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command"]).unwrap();
+        assert_eq!(
+            matches.get_one::<String>("quotes").unwrap(),
+            DEFAULT_QUOTES,
+            "The 'quotes' argument did not default to the default quotes."
+        );
+    }
+}
@@ -33,7 +33,7 @@ impl CommandBuilder for ReplaceWithArgBuilder {
     /// );
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.arg(
+        *cmd = std::mem::take(cmd).arg(
             Arg::new("replace_with")
                 .long("replace_with")
                 .value_name("REPLACEMENT_STRING")
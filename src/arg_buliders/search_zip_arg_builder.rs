@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct SearchZipArgBuilder;
+
+impl CommandBuilder for SearchZipArgBuilder {
+    /// Adds the `--search-zip` argument to the given command.
+    ///
+    /// The `--search-zip` argument is a flag that, when provided, decompresses `.gz`, `.zst`, `.xz`, and `.bz2` file sources before searching them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::search_zip_arg_builder::SearchZipArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// SearchZipArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--search-zip` argument should be added to the command.
+    /// let search_zip_arg = cmd.get_arguments().find(|arg| arg.get_id() == "search-zip");
+    /// assert!(search_zip_arg.is_some(), "The 'search-zip' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--search-zip` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--search-zip"]).unwrap();
+    /// assert!(matches.get_one::<bool>("search-zip").copied().unwrap_or(false), "The 'search-zip' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("search-zip")
+                .long("search-zip")
+                .help("Search compressed files decompressed, keeping their file names")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct SmartCaseArgBuilder;
+
+impl CommandBuilder for SmartCaseArgBuilder {
+    /// Adds the `--smart-case` argument to the given command.
+    ///
+    /// The `--smart-case` argument is a flag that, when provided, ignores case unless a search pattern contains an uppercase letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::smart_case_arg_builder::SmartCaseArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// SmartCaseArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--smart-case` argument should be added to the command.
+    /// let smart_case_arg = cmd.get_arguments().find(|arg| arg.get_id() == "smart-case");
+    /// assert!(smart_case_arg.is_some(), "The 'smart-case' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--smart-case` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--smart-case"]).unwrap();
+    /// assert!(matches.get_one::<bool>("smart-case").copied().unwrap_or(false), "The 'smart-case' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("smart-case")
+                .long("smart-case")
+                .help("Ignore case unless a search pattern contains an uppercase letter")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct StdinArgBuilder;
+
+impl CommandBuilder for StdinArgBuilder {
+    /// Adds the `--stdin` argument to the given command.
+    ///
+    /// The `--stdin` argument is a flag that, when provided, searches the text piped to stdin.
+    /// Piped text is otherwise only searched when no other source is given. `--file -` does the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::stdin_arg_builder::StdinArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// StdinArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--stdin` argument should be added to the command.
+    /// let stdin_arg = cmd.get_arguments().find(|arg| arg.get_id() == "stdin");
+    /// assert!(stdin_arg.is_some(), "The 'stdin' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--stdin` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--stdin"]).unwrap();
+    /// assert!(matches.get_one::<bool>("stdin").copied().unwrap_or(false), "The 'stdin' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("stdin")
+                .long("stdin")
+                .help("Search the text piped to stdin, like --file -")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
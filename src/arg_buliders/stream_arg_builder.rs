@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct StreamArgBuilder;
+
+impl CommandBuilder for StreamArgBuilder {
+    /// Adds the `--stream` argument to the given command.
+    ///
+    /// The `--stream` argument is a flag that, when provided, searches each source in chunks and prints hits as soon as they are found, keeping memory use bounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::stream_arg_builder::StreamArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// StreamArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--stream` argument should be added to the command.
+    /// let stream_arg = cmd.get_arguments().find(|arg| arg.get_id() == "stream");
+    /// assert!(stream_arg.is_some(), "The 'stream' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--stream` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--stream"]).unwrap();
+    /// assert!(matches.get_one::<bool>("stream").copied().unwrap_or(false), "The 'stream' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("stream")
+                .long("stream")
+                .help("Search sources in chunks and print hits as they are found, for huge files and endless pipes")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
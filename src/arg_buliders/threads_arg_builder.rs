@@ -0,0 +1,68 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::builder::RangedU64ValueParser;
+use clap::{Arg, Command};
+
+pub struct ThreadsArgBuilder;
+
+impl CommandBuilder for ThreadsArgBuilder {
+    /// Adds the `--threads` argument to the given command.
+    ///
+    /// The `--threads` argument requires an integer value greater than or equal to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::threads_arg_builder::ThreadsArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// ThreadsArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--threads` argument should be added to the command.
+    /// let threads_arg = cmd.get_arguments().find(|arg| arg.get_id() == "threads");
+    /// assert!(threads_arg.is_some(), "The 'threads' argument was not added.");
+    ///
+    /// // The `--threads` argument should accept a valid integer.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--threads", "4"]);
+    /// assert!(matches.is_ok(), "The 'threads' argument did not accept a valid value.");
+    /// assert_eq!(matches.unwrap().get_one::<usize>("threads"), Some(&4));
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("threads")
+                .long("threads")
+                .value_name("THREADS")
+                .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                .help("Search sources on this many threads, one per CPU by default"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn threads_argument_throws_error_without_a_value_given() {
+        let mut cmd = Command::new("test_command");
+        ThreadsArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--threads"]);
+        assert!(
+            matches.is_err(),
+            "The 'threads' argument did not require a value."
+        );
+    }
+
+    #[test]
+    fn threads_argument_requires_at_least_one_thread() {
+        let mut cmd = Command::new("test_command");
+        ThreadsArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--threads", "0"]);
+        assert!(
+            matches.is_err(),
+            "The 'threads' argument accepted an invalid value."
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct TomlKeyArgBuilder;
+
+impl CommandBuilder for TomlKeyArgBuilder {
+    /// Adds the `--toml-key` argument to the given command.
+    ///
+    /// The `--toml-key` argument requires a key path, like `dependencies.serde.version`.
+    /// The values of that key in TOML sources are found, whether it is written under a table header,
+    /// as a dotted key, or in an inline table, and edits change only those values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::toml_key_arg_builder::TomlKeyArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// TomlKeyArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--toml-key` argument should be added to the command.
+    /// let toml_key_arg = cmd.get_arguments().find(|arg| arg.get_id() == "toml-key");
+    /// assert!(toml_key_arg.is_some(), "The 'toml-key' argument was not added.");
+    ///
+    /// // The `--toml-key` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--toml-key", "dependencies.serde.version"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("toml-key").unwrap(), "dependencies.serde.version");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("toml-key")
+                .long("toml-key")
+                .value_name("TOML_KEY")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the values of a key in TOML sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn toml_key_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        TomlKeyArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--toml-key"]);
+        assert!(
+            matches.is_err(),
+            "The 'toml-key' argument did not require a value."
+        );
+    }
+}
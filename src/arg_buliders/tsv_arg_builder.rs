@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct TsvArgBuilder;
+
+impl CommandBuilder for TsvArgBuilder {
+    /// Adds the `--tsv` argument to the given command.
+    ///
+    /// The `--tsv` argument is a flag that, when provided, parses each source as tab-separated values and matches search patterns within each field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::tsv_arg_builder::TsvArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// TsvArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--tsv` argument should be added to the command.
+    /// let tsv_arg = cmd.get_arguments().find(|arg| arg.get_id() == "tsv");
+    /// assert!(tsv_arg.is_some(), "The 'tsv' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--tsv` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--tsv"]).unwrap();
+    /// assert!(matches.get_one::<bool>("tsv").copied().unwrap_or(false), "The 'tsv' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("tsv")
+                .long("tsv")
+                .help("Parse sources as TSV and match within each field")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{Arg, Command};
+
+pub struct WordArgBuilder;
+
+impl CommandBuilder for WordArgBuilder {
+    /// Adds the `--word` argument to the given command.
+    ///
+    /// The `--word` argument is a flag that, when provided, only matches search patterns as whole words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::word_arg_builder::WordArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// WordArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--word` argument should be added to the command.
+    /// let word_arg = cmd.get_arguments().find(|arg| arg.get_id() == "word");
+    /// assert!(word_arg.is_some(), "The 'word' argument was not added.");
+    ///
+    /// // Verify that a boolean is retrieved from the `--word` argument.
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--word"]).unwrap();
+    /// assert!(matches.get_one::<bool>("word").copied().unwrap_or(false), "The 'word' argument did not return true.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("word")
+                .long("word")
+                .help("Only match search patterns as whole words")
+                .action(clap::ArgAction::SetTrue),
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct XmlPathArgBuilder;
+
+impl CommandBuilder for XmlPathArgBuilder {
+    /// Adds the `--xml-path` argument to the given command.
+    ///
+    /// The `--xml-path` argument requires a path in a subset of XPath, like `//dependency/version`.
+    /// The element text or attribute values it selects in XML or HTML sources are found, and edits change only those values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::xml_path_arg_builder::XmlPathArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// XmlPathArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--xml-path` argument should be added to the command.
+    /// let xml_path_arg = cmd.get_arguments().find(|arg| arg.get_id() == "xml-path");
+    /// assert!(xml_path_arg.is_some(), "The 'xml-path' argument was not added.");
+    ///
+    /// // The `--xml-path` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--xml-path", "//dependency/version"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("xml-path").unwrap(), "//dependency/version");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("xml-path")
+                .long("xml-path")
+                .value_name("XML_PATH")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the element text or attribute values an XPath selects in XML or HTML sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn xml_path_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        XmlPathArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--xml-path"]);
+        assert!(
+            matches.is_err(),
+            "The 'xml-path' argument did not require a value."
+        );
+    }
+}
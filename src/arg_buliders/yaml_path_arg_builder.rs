@@ -0,0 +1,57 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{builder::NonEmptyStringValueParser, Arg, Command};
+
+pub struct YamlPathArgBuilder;
+
+impl CommandBuilder for YamlPathArgBuilder {
+    /// Adds the `--yaml-path` argument to the given command.
+    ///
+    /// The `--yaml-path` argument requires a key path, like `spec.template.spec.containers[0].image`.
+    /// The scalar values it selects in every document of YAML sources are found,
+    /// and edits change only those values, keeping comments and formatting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_buliders::yaml_path_arg_builder::YamlPathArgBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// YamlPathArgBuilder::build(&mut cmd);
+    ///
+    /// // The `--yaml-path` argument should be added to the command.
+    /// let yaml_path_arg = cmd.get_arguments().find(|arg| arg.get_id() == "yaml-path");
+    /// assert!(yaml_path_arg.is_some(), "The 'yaml-path' argument was not added.");
+    ///
+    /// // The `--yaml-path` argument should accept a string.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--yaml-path", "spec.containers[0].image"]).unwrap();
+    /// assert_eq!(matches.get_one::<String>("yaml-path").unwrap(), "spec.containers[0].image");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).arg(
+            Arg::new("yaml-path")
+                .long("yaml-path")
+                .value_name("YAML_PATH")
+                .value_parser(NonEmptyStringValueParser::new())
+                .help("Find the scalar values a key path selects in YAML sources"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn yaml_path_argument_throws_error_without_a_string_given() {
+        let mut cmd = Command::new("test_command");
+        YamlPathArgBuilder::build(&mut cmd);
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--yaml-path"]);
+        assert!(
+            matches.is_err(),
+            "The 'yaml-path' argument did not require a value."
+        );
+    }
+}
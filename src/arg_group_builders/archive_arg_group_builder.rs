@@ -0,0 +1,78 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct ArchiveArgGroupBuilder;
+
+impl CommandBuilder for ArchiveArgGroupBuilder {
+    /// Builds the `archive_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--archive` argument and conflicts with the `--edit_in_place`
+    /// argument, since the files in an archive are read-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::archive_arg_group_builder::ArchiveArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// ArchiveArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --archive and --edit_in_place
+    /// let matches = cmd.clone().try_get_matches_from(vec![
+    ///     "test_command",
+    ///     "--archive",
+    ///     "bundle.zip",
+    ///     "--edit_in_place",
+    /// ]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("archive_arg_group")
+                .args(["archive"])
+                .conflicts_with("edit_in_place"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{ArchiveArgBuilder, EditInPlaceArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            ArchiveArgBuilder::build(&mut cmd);
+            EditInPlaceArgBuilder::build(&mut cmd);
+            ArchiveArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_archive_allowed_without_edit_in_place() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--archive", "bundle.zip"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_archive_conflicts_with_edit_in_place() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--archive",
+            "bundle.zip",
+            "--edit_in_place",
+        ]);
+        assert!(matches.is_err());
+    }
+}
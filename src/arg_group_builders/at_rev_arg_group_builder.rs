@@ -0,0 +1,123 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct AtRevArgGroupBuilder;
+
+impl CommandBuilder for AtRevArgGroupBuilder {
+    /// Builds the `at_rev_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--at-rev` argument and requires a source from the `source_arg_group`.
+    /// Only `--dir` and `--files` can be read at a revision, so it conflicts with the other sources.
+    /// It also conflicts with `--edit_in_place`, since files at a revision are read-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::at_rev_arg_group_builder::AtRevArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// AtRevArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --at-rev and --text
+    /// let matches = cmd.clone().try_get_matches_from(vec![
+    ///     "test_command",
+    ///     "--at-rev",
+    ///     "v1",
+    ///     "--text",
+    ///     "foo",
+    /// ]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("at_rev_arg_group")
+                .args(["at-rev"])
+                .requires("source_arg_group")
+                .conflicts_with_all([
+                    "archive",
+                    "file",
+                    "files-from",
+                    "git-changed",
+                    "git-diff",
+                    "git-staged",
+                    "stdin",
+                    "text",
+                    "edit_in_place",
+                ]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        ArchiveArgBuilder, AtRevArgBuilder, DirArgBuilder, EditInPlaceArgBuilder, FileArgBuilder,
+        FilesArgBuilder, FilesFromArgBuilder, GitChangedArgBuilder, GitDiffArgBuilder,
+        GitStagedArgBuilder, StdinArgBuilder, TextArgBuilder,
+    };
+    use crate::arg_group_builders::SourceArgGroupBuilder;
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            ArchiveArgBuilder::build(&mut cmd);
+            AtRevArgBuilder::build(&mut cmd);
+            DirArgBuilder::build(&mut cmd);
+            EditInPlaceArgBuilder::build(&mut cmd);
+            FileArgBuilder::build(&mut cmd);
+            FilesArgBuilder::build(&mut cmd);
+            FilesFromArgBuilder::build(&mut cmd);
+            GitChangedArgBuilder::build(&mut cmd);
+            GitDiffArgBuilder::build(&mut cmd);
+            GitStagedArgBuilder::build(&mut cmd);
+            StdinArgBuilder::build(&mut cmd);
+            TextArgBuilder::build(&mut cmd);
+            SourceArgGroupBuilder::build(&mut cmd);
+            AtRevArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_at_rev_allowed_with_dir_or_files() {
+        for source in [vec!["--dir", "src"], vec!["--files", "a.rs,b.rs"]] {
+            let mut args = vec!["test_command", "--at-rev", "v1"];
+            args.extend(&source);
+            let matches = CMD.clone().try_get_matches_from(args);
+            assert!(
+                matches.is_ok(),
+                "'--at-rev' was not allowed with '{}'.",
+                source[0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_at_rev_requires_a_source() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--at-rev", "v1"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_at_rev_conflicts_with_edit_in_place() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--at-rev",
+            "v1",
+            "--dir",
+            "src",
+            "--edit_in_place",
+        ]);
+        assert!(matches.is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct BalancedArgGroupBuilder;
+
+impl CommandBuilder for BalancedArgGroupBuilder {
+    /// Builds the `balanced_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--balanced` argument and requires the `--find_between` argument
+    /// to be present when `--balanced` is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::balanced_arg_group_builder::BalancedArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// BalancedArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with only --balanced
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--balanced"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    ///
+    /// // Test with --find_between and --balanced
+    /// let matches = cmd.clone().try_get_matches_from(vec![
+    ///     "test_command",
+    ///     "--find_between",
+    ///     "{",
+    ///     "}",
+    ///     "--balanced",
+    /// ]);
+    /// assert!(matches.is_ok());
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("balanced_arg_group")
+                .args(["balanced"])
+                .requires("find_between"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{BalancedArgBuilder, FindBetweenArgBuilder};
+    use clap::Command;
+
+    #[test]
+    fn balanced_cannot_be_used_without_find_between() {
+        let mut cmd = Command::new("test_command");
+        BalancedArgBuilder::build(&mut cmd);
+        FindBetweenArgBuilder::build(&mut cmd);
+        BalancedArgGroupBuilder::build(&mut cmd);
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--balanced"]);
+        assert!(
+            matches.is_err(),
+            "The 'balanced' argument was used without 'find_between'."
+        );
+    }
+}
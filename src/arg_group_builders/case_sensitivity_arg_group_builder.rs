@@ -0,0 +1,58 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct CaseSensitivityArgGroupBuilder;
+
+impl CommandBuilder for CaseSensitivityArgGroupBuilder {
+    /// Adds a mutually exclusive argument group for Case Sensitivity arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_group_builders::case_sensitivity_arg_group_builder::CaseSensitivityArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// CaseSensitivityArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Case sensitivity arguments like `ignore-case` cannot be used with `smart-case`.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--ignore-case", "--smart-case"]);
+    /// assert!(matches.is_err(), "The 'ignore-case' argument was used with 'smart-case'.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("case_sensitivity_arg_group")
+                .args(["ignore-case", "smart-case"])
+                .multiple(false),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{IgnoreCaseArgBuilder, SmartCaseArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            IgnoreCaseArgBuilder::build(&mut cmd);
+            SmartCaseArgBuilder::build(&mut cmd);
+            CaseSensitivityArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn ignore_case_cannot_be_used_with_smart_case() {
+        let cmd = CMD.clone();
+        let matches =
+            cmd.try_get_matches_from(vec!["test_command", "--ignore-case", "--smart-case"]);
+        assert!(
+            matches.is_err(),
+            "The 'ignore-case' argument was used with 'smart-case'."
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct ColumnArgGroupBuilder;
+
+impl CommandBuilder for ColumnArgGroupBuilder {
+    /// Builds the `column_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes `--column` and `--no-header`, which may be given together
+    /// and require `--csv` or `--tsv` to split sources into columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::column_arg_group_builder::ColumnArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// ColumnArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --column and without --csv or --tsv
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--column", "email"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("column_arg_group")
+                .args(["column", "no-header"])
+                .multiple(true)
+                .requires("table_arg_group"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        ColumnArgBuilder, CssSelectorArgBuilder, CsvArgBuilder, JsonPathArgBuilder,
+        KvKeyArgBuilder, LinesArgBuilder, NoHeaderArgBuilder, StreamArgBuilder, TomlKeyArgBuilder,
+        TsvArgBuilder, XmlPathArgBuilder, YamlPathArgBuilder,
+    };
+    use crate::arg_group_builders::TableArgGroupBuilder;
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            ColumnArgBuilder::build(&mut cmd);
+            CssSelectorArgBuilder::build(&mut cmd);
+            CsvArgBuilder::build(&mut cmd);
+            JsonPathArgBuilder::build(&mut cmd);
+            KvKeyArgBuilder::build(&mut cmd);
+            LinesArgBuilder::build(&mut cmd);
+            NoHeaderArgBuilder::build(&mut cmd);
+            StreamArgBuilder::build(&mut cmd);
+            TomlKeyArgBuilder::build(&mut cmd);
+            TsvArgBuilder::build(&mut cmd);
+            XmlPathArgBuilder::build(&mut cmd);
+            YamlPathArgBuilder::build(&mut cmd);
+            TableArgGroupBuilder::build(&mut cmd);
+            ColumnArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_column_requires_a_table_format() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--column", "email"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_column_allowed_with_csv() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--csv", "--column", "email"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_column_allowed_with_tsv() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--tsv", "--column", "2"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_no_header_requires_a_table_format() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--no-header"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_no_header_allowed_with_column() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--csv",
+            "--no-header",
+            "--column",
+            "2",
+        ]);
+        assert!(matches.is_ok());
+    }
+}
@@ -21,10 +21,10 @@ impl CommandBuilder for EditEvaluateArgGroupBuilder {
     /// assert!(matches.is_err(), "The 'count' argument was used with 'replace_with'.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("edit_evaluate_arg_group")
-                .args(&["append", "prepend", "replace_with"]) // Edit arguments
-                .conflicts_with("count, count_by_source") // Evaluate arguments
+                .args(["append", "prepend", "replace_with"]) // Edit arguments
+                .conflicts_with_all(["count", "count_by_source"]) // Evaluate arguments
                 .multiple(false),
         );
     }
@@ -33,11 +33,20 @@ impl CommandBuilder for EditEvaluateArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{
+        AppendArgBuilder, CountArgBuilder, CountBySourceArgBuilder, PrependArgBuilder,
+        ReplaceWithArgBuilder,
+    };
     use clap::Command;
 
     lazy_static::lazy_static! {
         static ref CMD: Command = {
             let mut cmd = Command::new("test_command");
+            AppendArgBuilder::build(&mut cmd);
+            CountArgBuilder::build(&mut cmd);
+            CountBySourceArgBuilder::build(&mut cmd);
+            PrependArgBuilder::build(&mut cmd);
+            ReplaceWithArgBuilder::build(&mut cmd);
             EditEvaluateArgGroupBuilder::build(&mut cmd);
             cmd
         };
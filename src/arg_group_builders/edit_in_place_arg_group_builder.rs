@@ -43,10 +43,10 @@ impl CommandBuilder for EditInPlaceArgGroupBuilder {
     /// assert!(matches.is_ok());
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("edit_in_place_arg_group")
                 .args(["edit_in_place"])
-                .requires_all(&["dir", "file", "files"]),
+                .requires("source_arg_group"),
         );
     }
 }
@@ -54,11 +54,29 @@ impl CommandBuilder for EditInPlaceArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{
+        ArchiveArgBuilder, DirArgBuilder, EditInPlaceArgBuilder, FileArgBuilder, FilesArgBuilder,
+        FilesFromArgBuilder, GitChangedArgBuilder, GitDiffArgBuilder, GitStagedArgBuilder,
+        StdinArgBuilder, TextArgBuilder,
+    };
+    use crate::arg_group_builders::SourceArgGroupBuilder;
     use clap::Command;
 
     lazy_static::lazy_static! {
         static ref CMD: Command = {
             let mut cmd = Command::new("test_command");
+            ArchiveArgBuilder::build(&mut cmd);
+            DirArgBuilder::build(&mut cmd);
+            EditInPlaceArgBuilder::build(&mut cmd);
+            FileArgBuilder::build(&mut cmd);
+            FilesArgBuilder::build(&mut cmd);
+            FilesFromArgBuilder::build(&mut cmd);
+            GitChangedArgBuilder::build(&mut cmd);
+            GitDiffArgBuilder::build(&mut cmd);
+            GitStagedArgBuilder::build(&mut cmd);
+            StdinArgBuilder::build(&mut cmd);
+            TextArgBuilder::build(&mut cmd);
+            SourceArgGroupBuilder::build(&mut cmd);
             EditInPlaceArgGroupBuilder::build(&mut cmd);
             cmd
         };
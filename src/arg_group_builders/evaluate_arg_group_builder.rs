@@ -21,9 +21,9 @@ impl CommandBuilder for EvaluateArgGroupBuilder {
     /// assert!(matches.is_err(), "The 'count' argument was used with 'count_by_source'.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("evaluate_arg_group")
-                .args(&["count", "count_by_source"]) // Evaluate arguments
+                .args(["count", "count_by_source"]) // Evaluate arguments
                 .multiple(false), // Ensure zero or one argument is allowed
         );
     }
@@ -32,11 +32,14 @@ impl CommandBuilder for EvaluateArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{CountArgBuilder, CountBySourceArgBuilder};
     use clap::Command;
 
     lazy_static::lazy_static! {
         static ref CMD: Command = {
             let mut cmd = Command::new("test_command");
+            CountArgBuilder::build(&mut cmd);
+            CountBySourceArgBuilder::build(&mut cmd);
             EvaluateArgGroupBuilder::build(&mut cmd);
             cmd
         };
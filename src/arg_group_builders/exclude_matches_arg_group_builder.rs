@@ -48,7 +48,7 @@ impl CommandBuilder for ExcludeMatchesArgGroupBuilder {
     /// assert!(matches.is_ok());
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("exclude_matches_arg_group")
                 .args(["exclude_matches"])
                 .requires("find_between"),
@@ -59,11 +59,14 @@ impl CommandBuilder for ExcludeMatchesArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{ExcludeMatchesArgBuilder, FindBetweenArgBuilder};
     use clap::Command;
 
     #[test]
     fn test_find_between_argument() {
         let mut cmd = Command::new("test_command");
+        ExcludeMatchesArgBuilder::build(&mut cmd);
+        FindBetweenArgBuilder::build(&mut cmd);
         ExcludeMatchesArgGroupBuilder::build(&mut cmd);
 
         // Test with only --find_between
@@ -21,9 +21,9 @@ impl CommandBuilder for FrequencyArgGroupBuilder {
     /// assert!(matches.is_err(), "The 'all' argument was used with 'every_nth'.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("frequency_arg_group")
-                .args(&["all", "every_nth", "nth"])
+                .args(["all", "every_nth", "nth"])
                 .multiple(false),
         );
     }
@@ -32,11 +32,15 @@ impl CommandBuilder for FrequencyArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{AllArgBuilder, EveryNthArgBuilder, NthArgBuilder};
     use clap::Command;
 
     lazy_static::lazy_static! {
         static ref CMD: Command = {
             let mut cmd = Command::new("test_command");
+            AllArgBuilder::build(&mut cmd);
+            EveryNthArgBuilder::build(&mut cmd);
+            NthArgBuilder::build(&mut cmd);
             FrequencyArgGroupBuilder::build(&mut cmd);
             cmd
         };
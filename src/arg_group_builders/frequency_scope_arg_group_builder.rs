@@ -0,0 +1,57 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct FrequencyScopeArgGroupBuilder;
+
+impl CommandBuilder for FrequencyScopeArgGroupBuilder {
+    /// Adds a mutually exclusive argument group for Frequency Scope arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use crate::arg_group_builders::frequency_scope_arg_group_builder::FrequencyScopeArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// FrequencyScopeArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Frequency scope arguments like `per-source` cannot be used with `global`.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--per-source", "--global"]);
+    /// assert!(matches.is_err(), "The 'per-source' argument was used with 'global'.");
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("frequency_scope_arg_group")
+                .args(["global", "per-source"])
+                .multiple(false),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{GlobalArgBuilder, PerSourceArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            GlobalArgBuilder::build(&mut cmd);
+            PerSourceArgBuilder::build(&mut cmd);
+            FrequencyScopeArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn per_source_cannot_be_used_with_global() {
+        let cmd = CMD.clone();
+        let matches = cmd.try_get_matches_from(vec!["test_command", "--per-source", "--global"]);
+        assert!(
+            matches.is_err(),
+            "The 'per-source' argument was used with 'global'."
+        );
+    }
+}
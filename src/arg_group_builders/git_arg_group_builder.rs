@@ -0,0 +1,62 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct GitArgGroupBuilder;
+
+impl CommandBuilder for GitArgGroupBuilder {
+    /// Adds a mutually exclusive argument group for git source arguments.
+    ///
+    /// The group includes the `--git-changed`, `--git-staged`, and `--git-diff` arguments,
+    /// only one of which may be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::git_arg_group_builder::GitArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with both --git-changed and --git-staged
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--git-changed", "--git-staged"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("git_arg_group")
+                .args(["git-changed", "git-staged", "git-diff"])
+                .multiple(false),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{GitChangedArgBuilder, GitDiffArgBuilder, GitStagedArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            GitChangedArgBuilder::build(&mut cmd);
+            GitStagedArgBuilder::build(&mut cmd);
+            GitDiffArgBuilder::build(&mut cmd);
+            GitArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_git_changed_conflicts_with_git_diff() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--git-changed",
+            "--git-diff",
+            "main..HEAD",
+        ]);
+        assert!(matches.is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct GitHunksArgGroupBuilder;
+
+impl CommandBuilder for GitHunksArgGroupBuilder {
+    /// Builds the `git_hunks_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--git-hunks` argument and requires one of the `--git-changed`,
+    /// `--git-staged`, or `--git-diff` arguments in the `git_arg_group`, which select the changes
+    /// whose lines are searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::git_arg_group_builder::GitArgGroupBuilder;
+    /// use crate::arg_group_builders::git_hunks_arg_group_builder::GitHunksArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// GitArgGroupBuilder::build(&mut cmd);
+    /// GitHunksArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with only --git-hunks
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--git-hunks"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("git_hunks_arg_group")
+                .args(["git-hunks"])
+                .requires("git_arg_group"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        GitChangedArgBuilder, GitDiffArgBuilder, GitHunksArgBuilder, GitStagedArgBuilder,
+    };
+    use crate::arg_group_builders::GitArgGroupBuilder;
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            GitChangedArgBuilder::build(&mut cmd);
+            GitStagedArgBuilder::build(&mut cmd);
+            GitDiffArgBuilder::build(&mut cmd);
+            GitHunksArgBuilder::build(&mut cmd);
+            GitArgGroupBuilder::build(&mut cmd);
+            GitHunksArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_git_hunks_allowed_with_a_git_source() {
+        for source in [
+            vec!["--git-changed"],
+            vec!["--git-staged"],
+            vec!["--git-diff", "main..HEAD"],
+        ] {
+            let mut args = vec!["test_command", "--git-hunks"];
+            args.extend(&source);
+            let matches = CMD.clone().try_get_matches_from(args);
+            assert!(
+                matches.is_ok(),
+                "'--git-hunks' was not allowed with '{}'.",
+                source[0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_git_hunks_requires_a_git_source() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--git-hunks"]);
+        assert!(matches.is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct KvSectionArgGroupBuilder;
+
+impl CommandBuilder for KvSectionArgGroupBuilder {
+    /// Builds the `kv_section_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes `--kv-section`, which requires `--kv-key` to name the key to find in the section.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::kv_section_arg_group_builder::KvSectionArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// KvSectionArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --kv-section and without --kv-key
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--kv-section", "database"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("kv_section_arg_group")
+                .args(["kv-section"])
+                .requires("kv-key"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{KvKeyArgBuilder, KvSectionArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            KvKeyArgBuilder::build(&mut cmd);
+            KvSectionArgBuilder::build(&mut cmd);
+            KvSectionArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_kv_section_requires_kv_key() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--kv-section", "database"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_kv_section_allowed_with_kv_key() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--kv-key",
+            "port",
+            "--kv-section",
+            "database",
+        ]);
+        assert!(matches.is_ok());
+    }
+}
@@ -27,9 +27,9 @@ impl CommandBuilder for MaxDepthArgGroupBuilder {
     /// assert!(matches.is_ok(), "The 'dir' and 'max-depth' arguments were not accepted together.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("max_depth_arg_group")
-                .args(&["dir", "max-depth"])
+                .args(["dir", "max-depth"])
                 .multiple(false)
                 .conflicts_with("max-depth"),
         );
@@ -39,11 +39,14 @@ impl CommandBuilder for MaxDepthArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{DirArgBuilder, MaxDepthArgBuilder};
     use clap::Command;
 
     #[test]
     fn dir_can_be_used_without_max_depth() {
         let mut cmd = Command::new("test_command");
+        DirArgBuilder::build(&mut cmd);
+        MaxDepthArgBuilder::build(&mut cmd);
         MaxDepthArgGroupBuilder::build(&mut cmd);
         let matches = cmd.try_get_matches_from(vec!["test_command", "--dir", "/path"]);
         assert!(
@@ -0,0 +1,65 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct MmapArgGroupBuilder;
+
+impl CommandBuilder for MmapArgGroupBuilder {
+    /// Builds the `mmap_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--mmap` and `--no-mmap` arguments, only one of which may be used.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::mmap_arg_group_builder::MmapArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// MmapArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with both --mmap and --no-mmap
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--mmap", "--no-mmap"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(ArgGroup::new("mmap_arg_group").args(["mmap", "no-mmap"]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{MmapArgBuilder, NoMmapArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            MmapArgBuilder::build(&mut cmd);
+            NoMmapArgBuilder::build(&mut cmd);
+            MmapArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_mmap_or_no_mmap_allowed_alone() {
+        for arg in ["--mmap", "--no-mmap"] {
+            let matches = CMD.clone().try_get_matches_from(vec!["test_command", arg]);
+            assert!(matches.is_ok(), "'{}' was not allowed alone.", arg);
+        }
+    }
+
+    #[test]
+    fn test_mmap_conflicts_with_no_mmap() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--mmap", "--no-mmap"]);
+        assert!(matches.is_err());
+    }
+}
@@ -1,15 +1,45 @@
+pub mod archive_arg_group_builder;
+pub mod at_rev_arg_group_builder;
+pub mod balanced_arg_group_builder;
+pub mod case_sensitivity_arg_group_builder;
+pub mod column_arg_group_builder;
 pub mod edit_evaluate_arg_group_builder;
 pub mod edit_in_place_arg_group_builder;
 pub mod evaluate_arg_group_builder;
 pub mod exclude_matches_arg_group_builder;
 pub mod frequency_arg_group_builder;
+pub mod frequency_scope_arg_group_builder;
+pub mod git_arg_group_builder;
+pub mod git_hunks_arg_group_builder;
+pub mod kv_section_arg_group_builder;
 pub mod max_depth_arg_group_builder;
+pub mod mmap_arg_group_builder;
+pub mod null_arg_group_builder;
 pub mod search_arg_group_builder;
+pub mod source_arg_group_builder;
+pub mod stream_arg_group_builder;
+pub mod structured_arg_group_builder;
+pub mod table_arg_group_builder;
 
+pub use archive_arg_group_builder::ArchiveArgGroupBuilder;
+pub use at_rev_arg_group_builder::AtRevArgGroupBuilder;
+pub use balanced_arg_group_builder::BalancedArgGroupBuilder;
+pub use case_sensitivity_arg_group_builder::CaseSensitivityArgGroupBuilder;
+pub use column_arg_group_builder::ColumnArgGroupBuilder;
 pub use edit_evaluate_arg_group_builder::EditEvaluateArgGroupBuilder;
 pub use edit_in_place_arg_group_builder::EditInPlaceArgGroupBuilder;
 pub use evaluate_arg_group_builder::EvaluateArgGroupBuilder;
 pub use exclude_matches_arg_group_builder::ExcludeMatchesArgGroupBuilder;
 pub use frequency_arg_group_builder::FrequencyArgGroupBuilder;
+pub use frequency_scope_arg_group_builder::FrequencyScopeArgGroupBuilder;
+pub use git_arg_group_builder::GitArgGroupBuilder;
+pub use git_hunks_arg_group_builder::GitHunksArgGroupBuilder;
+pub use kv_section_arg_group_builder::KvSectionArgGroupBuilder;
 pub use max_depth_arg_group_builder::MaxDepthArgGroupBuilder;
+pub use mmap_arg_group_builder::MmapArgGroupBuilder;
+pub use null_arg_group_builder::NullArgGroupBuilder;
 pub use search_arg_group_builder::SearchArgGroupBuilder;
+pub use source_arg_group_builder::SourceArgGroupBuilder;
+pub use stream_arg_group_builder::StreamArgGroupBuilder;
+pub use structured_arg_group_builder::StructuredArgGroupBuilder;
+pub use table_arg_group_builder::TableArgGroupBuilder;
@@ -0,0 +1,68 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct NullArgGroupBuilder;
+
+impl CommandBuilder for NullArgGroupBuilder {
+    /// Builds the `null_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--null` argument and requires the `--files-from` argument,
+    /// since only file lists are NUL-separated.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::null_arg_group_builder::NullArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// NullArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with only --null
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--null"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("null_arg_group")
+                .args(["null"])
+                .requires("files-from"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{FilesFromArgBuilder, NullArgBuilder};
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            FilesFromArgBuilder::build(&mut cmd);
+            NullArgBuilder::build(&mut cmd);
+            NullArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_null_allowed_with_files_from() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--files-from", "-", "-0"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_null_requires_files_from() {
+        let matches = CMD.clone().try_get_matches_from(vec!["test_command", "-0"]);
+        assert!(matches.is_err());
+    }
+}
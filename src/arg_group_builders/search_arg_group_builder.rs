@@ -25,9 +25,20 @@ impl CommandBuilder for SearchArgGroupBuilder {
     /// assert!(matches.is_err(), "The 'find_between' argument was used with 'find_regex'.");
     /// ```
     fn build(cmd: &mut Command) {
-        cmd.group(
+        *cmd = std::mem::take(cmd).group(
             ArgGroup::new("search_arg_group")
-                .args(&["find_between", "find_regex", "find_string"]) // Search arguments
+                .args([
+                    "css-selector",
+                    "find_between",
+                    "find_regex",
+                    "find_string",
+                    "json-path",
+                    "kv-key",
+                    "patterns-file",
+                    "toml-key",
+                    "xml-path",
+                    "yaml-path",
+                ]) // Search arguments
                 .multiple(false) // Ensure zero or one argument is allowed
                 .required(true), // Ensure at least one argument is required
         );
@@ -37,11 +48,26 @@ impl CommandBuilder for SearchArgGroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arg_buliders::{
+        CssSelectorArgBuilder, FindBetweenArgBuilder, FindRegexArgBuilder, FindStringArgBuilder,
+        JsonPathArgBuilder, KvKeyArgBuilder, PatternsFileArgBuilder, TomlKeyArgBuilder,
+        XmlPathArgBuilder, YamlPathArgBuilder,
+    };
     use clap::Command;
 
     lazy_static::lazy_static! {
         static ref CMD: Command = {
             let mut cmd = Command::new("test_command");
+            CssSelectorArgBuilder::build(&mut cmd);
+            FindBetweenArgBuilder::build(&mut cmd);
+            FindRegexArgBuilder::build(&mut cmd);
+            FindStringArgBuilder::build(&mut cmd);
+            JsonPathArgBuilder::build(&mut cmd);
+            KvKeyArgBuilder::build(&mut cmd);
+            PatternsFileArgBuilder::build(&mut cmd);
+            TomlKeyArgBuilder::build(&mut cmd);
+            XmlPathArgBuilder::build(&mut cmd);
+            YamlPathArgBuilder::build(&mut cmd);
             SearchArgGroupBuilder::build(&mut cmd);
             cmd
         };
@@ -68,4 +94,15 @@ mod tests {
             "The 'find_regex' argument was used with 'find_string'."
         );
     }
+
+    #[test]
+    fn find_string_cannot_be_used_with_patterns_file() {
+        let cmd = CMD.clone();
+        let matches =
+            cmd.try_get_matches_from(vec!["test_command", "--find_string", "--patterns-file"]);
+        assert!(
+            matches.is_err(),
+            "The 'find_string' argument was used with 'patterns-file'."
+        );
+    }
 }
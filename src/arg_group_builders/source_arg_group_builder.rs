@@ -0,0 +1,98 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct SourceArgGroupBuilder;
+
+impl CommandBuilder for SourceArgGroupBuilder {
+    /// Adds a mutually exclusive argument group for Source arguments.
+    ///
+    /// Only one source may be given, so it is never ambiguous which one is searched.
+    /// No source is required, since piped text is searched when none is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::source_arg_group_builder::SourceArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// SourceArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Source arguments like `file` cannot be used with other source arguments like `stdin`.
+    /// let matches = cmd.try_get_matches_from(vec!["test_command", "--file", "a.txt", "--stdin"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("source_arg_group")
+                .args([
+                    "archive",
+                    "dir",
+                    "file",
+                    "files",
+                    "files-from",
+                    "git-changed",
+                    "git-diff",
+                    "git-staged",
+                    "stdin",
+                    "text",
+                ])
+                .multiple(false),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        ArchiveArgBuilder, DirArgBuilder, FileArgBuilder, FilesArgBuilder, FilesFromArgBuilder,
+        GitChangedArgBuilder, GitDiffArgBuilder, GitStagedArgBuilder, StdinArgBuilder,
+        TextArgBuilder,
+    };
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            ArchiveArgBuilder::build(&mut cmd);
+            DirArgBuilder::build(&mut cmd);
+            FileArgBuilder::build(&mut cmd);
+            FilesArgBuilder::build(&mut cmd);
+            FilesFromArgBuilder::build(&mut cmd);
+            GitChangedArgBuilder::build(&mut cmd);
+            GitDiffArgBuilder::build(&mut cmd);
+            GitStagedArgBuilder::build(&mut cmd);
+            StdinArgBuilder::build(&mut cmd);
+            TextArgBuilder::build(&mut cmd);
+            SourceArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_no_source_allowed() {
+        let matches = CMD.clone().try_get_matches_from(vec!["test_command"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_stdin_conflicts_with_other_sources() {
+        for source in [
+            vec!["--dir", "some_dir"],
+            vec!["--file", "-"],
+            vec!["--files-from", "-"],
+            vec!["--text", "some text"],
+        ] {
+            let mut args = vec!["test_command", "--stdin"];
+            args.extend(&source);
+            let matches = CMD.clone().try_get_matches_from(args);
+            assert!(
+                matches.is_err(),
+                "'--stdin' was allowed with '{}'.",
+                source[0]
+            );
+        }
+    }
+}
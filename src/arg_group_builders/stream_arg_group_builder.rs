@@ -0,0 +1,88 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct StreamArgGroupBuilder;
+
+impl CommandBuilder for StreamArgGroupBuilder {
+    /// Builds the `stream_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the `--stream` argument and conflicts with the `--append`, `--prepend`,
+    /// `--replace_with`, and `--edit_in_place` arguments, since edits need the whole source in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::stream_arg_group_builder::StreamArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// StreamArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --stream and --replace_with
+    /// let matches = cmd.clone().try_get_matches_from(vec![
+    ///     "test_command",
+    ///     "--stream",
+    ///     "--replace_with",
+    ///     "bar",
+    /// ]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("stream_arg_group")
+                .args(["stream"])
+                .conflicts_with_all(["append", "prepend", "replace_with", "edit_in_place"]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        AppendArgBuilder, EditInPlaceArgBuilder, PrependArgBuilder, ReplaceWithArgBuilder,
+        StreamArgBuilder,
+    };
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            StreamArgBuilder::build(&mut cmd);
+            AppendArgBuilder::build(&mut cmd);
+            PrependArgBuilder::build(&mut cmd);
+            ReplaceWithArgBuilder::build(&mut cmd);
+            EditInPlaceArgBuilder::build(&mut cmd);
+            StreamArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_stream_allowed_without_edits() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--stream"]);
+        assert!(matches.is_ok());
+    }
+
+    #[test]
+    fn test_stream_conflicts_with_edits() {
+        for edit in ["--append", "--prepend", "--replace_with"] {
+            let matches =
+                CMD.clone()
+                    .try_get_matches_from(vec!["test_command", "--stream", edit, "foo"]);
+            assert!(matches.is_err(), "'--stream' was allowed with '{}'.", edit);
+        }
+
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--stream", "--edit_in_place"]);
+        assert!(matches.is_err());
+    }
+}
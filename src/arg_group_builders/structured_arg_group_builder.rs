@@ -0,0 +1,153 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct StructuredArgGroupBuilder;
+
+impl CommandBuilder for StructuredArgGroupBuilder {
+    /// Builds the `structured_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the searches that parse each source as a whole document,
+    /// `--json-path`, `--yaml-path`, `--toml-key`, `--xml-path`, `--css-selector`, and `--kv-key`.
+    /// It conflicts with `--lines`, which searches each line on its own,
+    /// and with `--stream`, which searches a source in chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::structured_arg_group_builder::StructuredArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// StructuredArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --json-path and --lines
+    /// let matches = cmd.clone().try_get_matches_from(vec![
+    ///     "test_command",
+    ///     "--json-path",
+    ///     "$.name",
+    ///     "--lines",
+    /// ]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("structured_arg_group")
+                .args([
+                    "css-selector",
+                    "json-path",
+                    "kv-key",
+                    "toml-key",
+                    "xml-path",
+                    "yaml-path",
+                ])
+                .conflicts_with_all(["lines", "stream"]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        CssSelectorArgBuilder, JsonPathArgBuilder, KvKeyArgBuilder, LinesArgBuilder,
+        StreamArgBuilder, TomlKeyArgBuilder, XmlPathArgBuilder, YamlPathArgBuilder,
+    };
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            CssSelectorArgBuilder::build(&mut cmd);
+            JsonPathArgBuilder::build(&mut cmd);
+            KvKeyArgBuilder::build(&mut cmd);
+            LinesArgBuilder::build(&mut cmd);
+            StreamArgBuilder::build(&mut cmd);
+            TomlKeyArgBuilder::build(&mut cmd);
+            XmlPathArgBuilder::build(&mut cmd);
+            YamlPathArgBuilder::build(&mut cmd);
+            StructuredArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_json_path_conflicts_with_lines() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--json-path", "$.a", "--lines"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_json_path_conflicts_with_stream() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--json-path",
+            "$.a",
+            "--stream",
+        ]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_yaml_path_conflicts_with_lines() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--yaml-path", "a.b", "--lines"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_toml_key_conflicts_with_stream() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--toml-key",
+            "package.version",
+            "--stream",
+        ]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_xml_path_conflicts_with_lines() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--xml-path",
+            "//version",
+            "--lines",
+        ]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_css_selector_conflicts_with_stream() {
+        let matches = CMD.clone().try_get_matches_from(vec![
+            "test_command",
+            "--css-selector",
+            "title",
+            "--stream",
+        ]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_kv_key_conflicts_with_lines() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--kv-key", "PORT", "--lines"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_json_path_allowed_alone() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--json-path", "$.a"]);
+        assert!(matches.is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::handlers::build_command_handler::CommandBuilder;
+use clap::{ArgGroup, Command};
+
+pub struct TableArgGroupBuilder;
+
+impl CommandBuilder for TableArgGroupBuilder {
+    /// Builds the `table_arg_group` argument group and adds it to the given command.
+    ///
+    /// The group includes the formats that split each source into records of fields, `--csv` and `--tsv`.
+    /// Only one may be given. It conflicts with `--lines`, the structured searches,
+    /// and `--stream`, which searches a source in chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - The command to which the argument group will be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, error::ErrorKind};
+    /// use crate::arg_group_builders::table_arg_group_builder::TableArgGroupBuilder;
+    /// use crate::handlers::build_command_handler::CommandBuilder;
+    ///
+    /// let mut cmd = Command::new("test_command");
+    /// TableArgGroupBuilder::build(&mut cmd);
+    ///
+    /// // Test with --csv and --tsv
+    /// let matches = cmd.clone().try_get_matches_from(vec!["test_command", "--csv", "--tsv"]);
+    /// assert_eq!(matches.unwrap_err().kind(), ErrorKind::ArgumentConflict);
+    /// ```
+    fn build(cmd: &mut Command) {
+        *cmd = std::mem::take(cmd).group(
+            ArgGroup::new("table_arg_group")
+                .args(["csv", "tsv"])
+                .multiple(false)
+                .conflicts_with_all([
+                    "css-selector",
+                    "json-path",
+                    "kv-key",
+                    "lines",
+                    "stream",
+                    "toml-key",
+                    "xml-path",
+                    "yaml-path",
+                ]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arg_buliders::{
+        CssSelectorArgBuilder, CsvArgBuilder, JsonPathArgBuilder, KvKeyArgBuilder, LinesArgBuilder,
+        StreamArgBuilder, TomlKeyArgBuilder, TsvArgBuilder, XmlPathArgBuilder, YamlPathArgBuilder,
+    };
+    use clap::Command;
+
+    lazy_static::lazy_static! {
+        static ref CMD: Command = {
+            let mut cmd = Command::new("test_command");
+            CssSelectorArgBuilder::build(&mut cmd);
+            CsvArgBuilder::build(&mut cmd);
+            JsonPathArgBuilder::build(&mut cmd);
+            KvKeyArgBuilder::build(&mut cmd);
+            LinesArgBuilder::build(&mut cmd);
+            StreamArgBuilder::build(&mut cmd);
+            TomlKeyArgBuilder::build(&mut cmd);
+            TsvArgBuilder::build(&mut cmd);
+            XmlPathArgBuilder::build(&mut cmd);
+            YamlPathArgBuilder::build(&mut cmd);
+            TableArgGroupBuilder::build(&mut cmd);
+            cmd
+        };
+    }
+
+    #[test]
+    fn test_csv_conflicts_with_tsv() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--csv", "--tsv"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_csv_conflicts_with_lines() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--csv", "--lines"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_tsv_conflicts_with_json_path() {
+        let matches =
+            CMD.clone()
+                .try_get_matches_from(vec!["test_command", "--tsv", "--json-path", "$.a"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn test_csv_allowed_alone() {
+        let matches = CMD
+            .clone()
+            .try_get_matches_from(vec!["test_command", "--csv"]);
+        assert!(matches.is_ok());
+    }
+}
@@ -3,12 +3,16 @@ use super::edit::Edit;
 use super::evaluation::Evaluation;
 use super::hit::Hit;
 use super::source::Source;
+use super::source_text::SourceText;
 use clap::ArgMatches;
 
 pub struct DTO<'a> {
     command_data: CommandData,
     inputs: Option<clap::ArgMatches>,
-    sources: Vec<Source<'a>>,
+    sources: Vec<Source>,
+    skipped_sources: Vec<String>,
+    unsearched_sources: Vec<String>,
+    unwritten_sources: Vec<String>,
     hits: Vec<Hit>,
     edits: Vec<Edit>,
     evalutation: Option<&'a Evaluation<'a>>,
@@ -26,6 +30,9 @@ impl<'a> DTO<'a> {
             command_data: CommandData::new(command_name, version, author, about),
             inputs: None,
             sources: Vec::new(),
+            skipped_sources: Vec::new(),
+            unsearched_sources: Vec::new(),
+            unwritten_sources: Vec::new(),
             hits: Vec::new(),
             edits: Vec::new(),
             evalutation: None,
@@ -35,17 +42,71 @@ impl<'a> DTO<'a> {
     // Adders
 
     // Add a source to the DTO.
-    pub fn add_source(&mut self, name: &'a str, text: &'a str) {
+    pub fn add_source(&mut self, name: &str, text: &str) {
         let source = Source::new(name, text);
         self.sources.push(source);
     }
 
-    // Add a hit to the DTO.
-    pub fn add_hit(&mut self, value: &'a str, position: usize, source: &'a mut Source<'a>) {
-        let hit = Hit::new(value, position);
+    // Add a source whose text was already loaded, possibly mapped from a file.
+    pub fn add_source_text(&mut self, name: &str, text: SourceText) {
+        self.sources.push(Source::from_text(name, text));
+    }
+
+    // Record the name of a source that was left out of the search.
+    pub fn add_skipped_source(&mut self, name: &str) {
+        self.skipped_sources.push(String::from(name));
+    }
+
+    // Record the name of a source that could not be searched as asked.
+    pub fn add_unsearched_source(&mut self, name: &str) {
+        self.unsearched_sources.push(String::from(name));
+    }
+
+    // Record the name of a source whose edits could not be written back.
+    pub fn add_unwritten_source(&mut self, name: &str) {
+        self.unwritten_sources.push(String::from(name));
+    }
+
+    // Add a hit to the DTO and record its index on the source it was found in.
+    pub fn add_hit(&mut self, hit: Hit, source_index: usize) {
         self.hits.push(hit);
         let hit_index = self.hits.len() - 1;
-        source.add_hit_index(hit_index);
+        self.sources[source_index].add_hit_index(hit_index);
+    }
+
+    // Add an edit to the DTO and record its index on the source it applies to.
+    pub fn add_edit(&mut self, edit: Edit, source_index: usize) {
+        self.edits.push(edit);
+        let edit_index = self.edits.len() - 1;
+        self.sources[source_index].add_edit_index(edit_index);
+    }
+
+    // Removers
+
+    // Keep only the hits whose 1-based count is selected, updating each source's hit indices.
+    pub fn retain_hits<F: Fn(usize) -> bool>(&mut self, selects: F) {
+        let mut kept_hits = Vec::new();
+        let mut new_indices = Vec::with_capacity(self.hits.len());
+
+        for (index, hit) in std::mem::take(&mut self.hits).into_iter().enumerate() {
+            if selects(index + 1) {
+                new_indices.push(Some(kept_hits.len()));
+                kept_hits.push(hit);
+            } else {
+                new_indices.push(None);
+            }
+        }
+
+        self.hits = kept_hits;
+
+        for source in self.sources.iter_mut() {
+            let hit_indices = source
+                .get_hit_indices()
+                .iter()
+                .filter_map(|hit_index| new_indices[*hit_index])
+                .collect();
+            source.set_hit_indices(hit_indices);
+        }
     }
 
     // Cloners
@@ -64,15 +125,27 @@ impl<'a> DTO<'a> {
         &self.inputs
     }
 
-    pub fn get_sources(&self) -> &Vec<Source<'a>> {
+    pub fn get_sources(&self) -> &Vec<Source> {
         &self.sources
     }
 
-    pub fn get_hits(&'a self) -> &'a Vec<Hit<'a>> {
+    pub fn get_skipped_sources(&self) -> &Vec<String> {
+        &self.skipped_sources
+    }
+
+    pub fn get_unsearched_sources(&self) -> &Vec<String> {
+        &self.unsearched_sources
+    }
+
+    pub fn get_unwritten_sources(&self) -> &Vec<String> {
+        &self.unwritten_sources
+    }
+
+    pub fn get_hits(&self) -> &Vec<Hit> {
         &self.hits
     }
 
-    pub fn get_edits(&'a self) -> &'a Vec<Edit<'a>> {
+    pub fn get_edits(&self) -> &Vec<Edit> {
         &self.edits
     }
 
@@ -1,14 +1,21 @@
 #[derive(Debug)]
 pub struct Edit {
     position: usize,
+    length: usize,
     new_value: String,
 }
 
 impl Edit {
     // Constructor for Edit
     pub fn new(position: usize, new_value: String) -> Self {
+        Self::replacing(position, 0, new_value)
+    }
+
+    // Constructor for an Edit that replaces `length` bytes, such as a hit
+    pub fn replacing(position: usize, length: usize, new_value: String) -> Self {
         Self {
             position,
+            length,
             new_value,
         }
     }
@@ -18,6 +25,11 @@ impl Edit {
         self.position
     }
 
+    // Returns the number of bytes replaced.
+    pub fn get_length(&self) -> usize {
+        self.length
+    }
+
     // Returns a reference to the new_value string.
     pub fn get_new_value(&self) -> &str {
         &self.new_value
@@ -50,6 +62,13 @@ mod tests {
         assert_eq!(e.get_new_value(), "foo");
     }
 
+    #[test]
+    fn test_edit_replacing() {
+        let e = Edit::replacing(5, 3, "foo".to_string());
+        assert_eq!(e.get_length(), 3);
+        assert_eq!(Edit::new(5, "foo".to_string()).get_length(), 0);
+    }
+
     #[test]
     fn test_set_position() {
         let mut e = Edit::new(5, "foo".to_string());
@@ -8,6 +8,6 @@ impl<'a> Evaluation<'a> {
     }
 
     pub fn value(&self) -> &str {
-        &self.value
+        self.value
     }
 }
@@ -1,13 +1,47 @@
+use crate::helpers::escaping::unescape;
+use crate::strategies::enums::escaping::Escaping;
+use std::borrow::Cow;
+
 #[derive(Debug)]
 pub struct Hit {
     position: usize,
     length: usize,
+    pattern_index: usize,
+    escaping: Escaping,
 }
 
 impl Hit {
     // Constructor for Hit
     pub fn new(position: usize, length: usize) -> Self {
-        Self { position, length }
+        Self::for_pattern(position, length, 0)
+    }
+
+    // Constructor for a Hit found by one of several search patterns.
+    pub fn for_pattern(position: usize, length: usize, pattern_index: usize) -> Self {
+        Self {
+            position,
+            length,
+            pattern_index,
+            escaping: Escaping::None,
+        }
+    }
+
+    // Constructor for a Hit on a value that is escaped in its source, like the contents of a JSON string.
+    pub fn escaped(position: usize, length: usize, escaping: Escaping) -> Self {
+        Self::escaped_for_pattern(position, length, 0, escaping)
+    }
+
+    // Constructor for a Hit found by one of several search patterns on a value that is escaped in its source.
+    pub fn escaped_for_pattern(
+        position: usize,
+        length: usize,
+        pattern_index: usize,
+        escaping: Escaping,
+    ) -> Self {
+        Self {
+            escaping,
+            ..Self::for_pattern(position, length, pattern_index)
+        }
     }
 
     // Returns the length.
@@ -25,11 +59,36 @@ impl Hit {
         self.position + self.length - 1
     }
 
+    // Returns the index of the search pattern that matched, in the order the patterns were given.
+    pub fn get_pattern_index(&self) -> usize {
+        self.pattern_index
+    }
+
+    // Returns how the value of the hit is escaped in its source.
+    pub fn get_escaping(&self) -> Escaping {
+        self.escaping
+    }
+
+    // Returns the slice of the given text that the hit covers.
+    pub fn get_value<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.position..self.position + self.length]
+    }
+
+    // Returns the value of the hit in the given text, with its escaping undone.
+    pub fn get_unescaped_value<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        unescape(self.get_value(text), self.escaping)
+    }
+
     // Sets the length.
     pub fn set_length(&mut self, length: usize) {
         self.length = length;
     }
 
+    // Sets the index of the search pattern that matched.
+    pub fn set_pattern_index(&mut self, pattern_index: usize) {
+        self.pattern_index = pattern_index;
+    }
+
     // Sets the position integer.
     pub fn set_position(&mut self, position: usize) {
         self.position = position;
@@ -58,6 +117,27 @@ mod tests {
         assert_eq!(h.get_end_position(), 8);
     }
 
+    #[test]
+    fn test_hit_pattern_index() {
+        assert_eq!(Hit::new(5, 4).get_pattern_index(), 0);
+        assert_eq!(Hit::for_pattern(5, 4, 2).get_pattern_index(), 2);
+    }
+
+    #[test]
+    fn test_hit_value() {
+        let h = Hit::new(4, 3);
+        assert_eq!(h.get_value("foo bar baz"), "bar");
+    }
+
+    #[test]
+    fn test_hit_unescaped_value() {
+        let text = r#"{"a": "x\"y"}"#;
+        let h = Hit::escaped(7, 4, Escaping::JsonString);
+        assert_eq!(h.get_value(text), r#"x\"y"#);
+        assert_eq!(h.get_unescaped_value(text), r#"x"y"#);
+        assert_eq!(Hit::new(7, 4).get_unescaped_value(text), r#"x\"y"#);
+    }
+
     #[test]
     fn test_set_length() {
         let mut h = Hit::new(5, 4);
@@ -65,6 +145,13 @@ mod tests {
         assert_eq!(h.get_length(), 10);
     }
 
+    #[test]
+    fn test_set_pattern_index() {
+        let mut h = Hit::new(5, 4);
+        h.set_pattern_index(3);
+        assert_eq!(h.get_pattern_index(), 3);
+    }
+
     #[test]
     fn test_set_position() {
         let mut h = Hit::new(5, 4);
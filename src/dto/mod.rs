@@ -1,6 +1,8 @@
 pub mod command_data;
+#[allow(clippy::module_inception)]
 pub mod dto;
 pub mod edit;
 pub mod evaluation;
 pub mod hit;
 pub mod source;
+pub mod source_text;
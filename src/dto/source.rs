@@ -1,16 +1,31 @@
-pub struct Source<'a> {
-    name: &'a str,
-    text: &'a str,
+use super::source_text::SourceText;
+use crate::helpers::encoding::SourceEncoding;
+
+pub struct Source {
+    name: String,
+    text: SourceText,
     hit_indices: Vec<usize>,
+    edit_indices: Vec<usize>,
 }
 
-impl<'a> Source<'a> {
+impl Source {
     // Constructor for Source
-    pub fn new(name: &'a str, text: &'a str) -> Self {
+    pub fn new(name: &str, text: &str) -> Self {
         Self {
-            name,
+            name: String::from(name),
+            text: SourceText::from(text),
+            hit_indices: Vec::new(),
+            edit_indices: Vec::new(),
+        }
+    }
+
+    // Constructor for a Source whose text was already loaded, possibly mapped from a file
+    pub fn from_text(name: &str, text: SourceText) -> Self {
+        Self {
+            name: String::from(name),
             text,
             hit_indices: Vec::new(),
+            edit_indices: Vec::new(),
         }
     }
 
@@ -24,14 +39,29 @@ impl<'a> Source<'a> {
         &self.text
     }
 
+    // Returns the encoding the source was read in.
+    pub fn get_encoding(&self) -> SourceEncoding {
+        self.text.get_encoding()
+    }
+
+    // Returns true if the source looked binary.
+    pub fn is_binary(&self) -> bool {
+        self.text.is_binary()
+    }
+
+    // Returns true if invalid bytes were replaced when the source was decoded.
+    pub fn is_lossy(&self) -> bool {
+        self.text.is_lossy()
+    }
+
     // Sets the value string.
-    pub fn set_name(&mut self, name: &'a str) {
-        self.name = name;
+    pub fn set_name(&mut self, name: &str) {
+        self.name = String::from(name);
     }
 
     // Sets the text string.
-    pub fn set_text(&mut self, text: &'a str) {
-        self.text = text;
+    pub fn set_text(&mut self, text: &str) {
+        self.text = SourceText::from(text);
     }
 
     // Get the hits for the source.
@@ -43,6 +73,21 @@ impl<'a> Source<'a> {
     pub fn add_hit_index(&mut self, hit_index: usize) {
         self.hit_indices.push(hit_index);
     }
+
+    // Replaces the hit indices.
+    pub fn set_hit_indices(&mut self, hit_indices: Vec<usize>) {
+        self.hit_indices = hit_indices;
+    }
+
+    // Get the edits for the source.
+    pub fn get_edit_indices(&self) -> &Vec<usize> {
+        &self.edit_indices
+    }
+
+    // Adds an edit to the edits vector.
+    pub fn add_edit_index(&mut self, edit_index: usize) {
+        self.edit_indices.push(edit_index);
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +123,18 @@ mod tests {
         assert_eq!(source.hit_indices.len(), 1);
         assert_eq!(source.hit_indices[0], index);
     }
+
+    #[test]
+    fn test_set_hit_indices() {
+        let mut source = Source::new("name", "text");
+        source.add_hit_index(3);
+        source.set_hit_indices(vec![0, 1]);
+        assert_eq!(source.get_hit_indices(), &vec![0, 1]);
+    }
+
+    #[test]
+    fn test_source_can_be_shared_with_search_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Source>();
+    }
 }
@@ -0,0 +1,244 @@
+use crate::helpers::binary::is_binary;
+use crate::helpers::encoding::SourceEncoding;
+use crate::strategies::enums::encoding::Encoding;
+use memmap2::Mmap;
+use std::ops::Deref;
+
+/// The text of a source, either read into memory or mapped from a file.
+///
+/// A mapped file is searched in place, so a large file is never copied.
+/// The encoding the source was read in is kept, so an edited source can be written back in it.
+/// A source that looks binary is marked, so it can be skipped or reported instead of printed.
+/// A source whose invalid bytes were replaced when decoding is marked as lossy, so it is never written back.
+///
+/// # Example
+///
+/// ```
+/// use seer::dto::source_text::SourceText;
+///
+/// let text = SourceText::from("foo bar");
+/// assert_eq!(&*text, "foo bar");
+/// ```
+pub struct SourceText {
+    data: TextData,
+    encoding: SourceEncoding,
+    binary: bool,
+    lossy: bool,
+}
+
+enum TextData {
+    Owned(String),
+    Mapped(MappedText),
+}
+
+impl SourceText {
+    /// Decodes the bytes of a source, marking it as binary if it looks binary.
+    ///
+    /// Only UTF-8 sources are checked for being binary, since other encodings were asked for.
+    /// Bytes that are invalid in the encoding are replaced with U+FFFD, and the source is marked as lossy.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes of the source.
+    /// * `requested` - The encoding given with `--encoding`, or `None` to detect it from the BOM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seer::dto::source_text::SourceText;
+    ///
+    /// assert!(!SourceText::from_bytes(b"text".to_vec(), None).is_binary());
+    /// assert!(SourceText::from_bytes(b"bin\0ary".to_vec(), None).is_binary());
+    /// assert!(SourceText::from_bytes(b"\xFFbad".to_vec(), None).is_lossy());
+    /// ```
+    pub fn from_bytes(bytes: Vec<u8>, requested: Option<Encoding>) -> Self {
+        let encoding = SourceEncoding::sniff(&bytes, requested);
+
+        if encoding.get_encoding() != Encoding::Utf8 {
+            return Self::decoded(&bytes, encoding, false);
+        }
+
+        let bom_length = encoding.get_bom_length();
+        if is_binary(&bytes[bom_length..]) {
+            return Self::decoded(&bytes, encoding, true);
+        }
+
+        let mut bytes = bytes;
+        bytes.drain(..bom_length);
+        match String::from_utf8(bytes) {
+            Ok(text) => Self::owned(text, encoding, false),
+            // Invalid UTF-8 after the window that was checked
+            Err(e) => Self {
+                lossy: true,
+                ..Self::owned(
+                    String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                    encoding,
+                    true,
+                )
+            },
+        }
+    }
+
+    /// Wraps a mapped file, or decodes a copy of it if it is not plain UTF-8 text.
+    pub fn from_mmap(mmap: Mmap, requested: Option<Encoding>) -> Self {
+        let encoding = SourceEncoding::sniff(&mmap, requested);
+        let bom_length = encoding.get_bom_length();
+
+        if encoding.get_encoding() != Encoding::Utf8 || is_binary(&mmap[bom_length..]) {
+            return Self::from_bytes(mmap.to_vec(), requested);
+        }
+
+        match MappedText::new(mmap, bom_length) {
+            Ok(text) => Self {
+                data: TextData::Mapped(text),
+                encoding,
+                binary: false,
+                lossy: false,
+            },
+            Err(mmap) => Self::from_bytes(mmap.to_vec(), requested),
+        }
+    }
+
+    // Creates a source text held in memory.
+    fn owned(text: String, encoding: SourceEncoding, binary: bool) -> Self {
+        Self {
+            data: TextData::Owned(text),
+            encoding,
+            binary,
+            lossy: false,
+        }
+    }
+
+    // Decodes a source, marking it as lossy if encoding its text again does not give back its bytes.
+    fn decoded(bytes: &[u8], encoding: SourceEncoding, binary: bool) -> Self {
+        let text = encoding.decode(bytes);
+        // Only a replaced byte can make the text differ
+        let lossy = text.contains(char::REPLACEMENT_CHARACTER)
+            && encoding
+                .encode(&text)
+                .map_or(true, |encoded| encoded != bytes);
+
+        Self {
+            lossy,
+            ..Self::owned(text, encoding, binary)
+        }
+    }
+
+    // Returns the encoding the source was read in.
+    pub fn get_encoding(&self) -> SourceEncoding {
+        self.encoding
+    }
+
+    // Returns true if the source looked binary.
+    pub fn is_binary(&self) -> bool {
+        self.binary
+    }
+
+    // Returns true if invalid bytes were replaced when the source was decoded.
+    pub fn is_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    // Returns true if the text is mapped from a file.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.data, TextData::Mapped(_))
+    }
+}
+
+impl Deref for SourceText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &self.data {
+            TextData::Owned(text) => text,
+            TextData::Mapped(text) => text.as_str(),
+        }
+    }
+}
+
+impl From<&str> for SourceText {
+    fn from(text: &str) -> Self {
+        Self::from(String::from(text))
+    }
+}
+
+impl From<String> for SourceText {
+    fn from(text: String) -> Self {
+        Self::owned(text, SourceEncoding::default(), false)
+    }
+}
+
+/// A memory-mapped file that is known to be valid UTF-8 after its BOM.
+pub struct MappedText {
+    mmap: Mmap,
+    start: usize,
+}
+
+impl MappedText {
+    /// Wraps a mapped file, checking once that it is valid UTF-8 from `start` on.
+    ///
+    /// # Errors
+    ///
+    /// Returns the mapped file back if it is not valid UTF-8.
+    pub fn new(mmap: Mmap, start: usize) -> Result<Self, Mmap> {
+        if std::str::from_utf8(&mmap[start..]).is_err() {
+            return Err(mmap);
+        }
+        Ok(Self { mmap, start })
+    }
+
+    // Returns the mapped bytes as a string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes were valid UTF-8 when the text was created, and stay so while the file
+        // is unchanged. Seer never writes a mapped file, see `load_file`, but a change by another process
+        // while it is mapped can break this
+        unsafe { std::str::from_utf8_unchecked(&self.mmap[self.start..]) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skips_and_remembers_the_bom() {
+        let text = SourceText::from_bytes(b"\xEF\xBB\xBFabc".to_vec(), None);
+        assert_eq!(&*text, "abc");
+        assert_eq!(
+            text.get_encoding(),
+            SourceEncoding::new(Encoding::Utf8, true)
+        );
+    }
+
+    #[test]
+    fn test_decodes_utf16_without_marking_it_binary() {
+        let text = SourceText::from_bytes(b"\xFF\xFEa\0b\0".to_vec(), None);
+        assert_eq!(&*text, "ab");
+        assert!(!text.is_binary());
+    }
+
+    #[test]
+    fn test_marks_replaced_bytes_as_lossy() {
+        let cases: [(&[u8], bool); 5] = [
+            (b"text", false),
+            (b"caf\xC3\xA9 \xEF\xBF\xBD", false),
+            (b"caf\xE9", true),
+            (b"\xFF\xFEa\0\0\xD8", true),
+            (b"\xFF\xFEa\0\xFD\xFF", false),
+        ];
+
+        for (bytes, lossy) in cases {
+            assert_eq!(
+                SourceText::from_bytes(bytes.to_vec(), None).is_lossy(),
+                lossy
+            );
+        }
+    }
+
+    #[test]
+    fn test_decodes_the_requested_encoding() {
+        let text = SourceText::from_bytes(b"caf\xe9".to_vec(), Some(Encoding::Latin1));
+        assert_eq!(&*text, "café");
+        assert!(!text.is_binary());
+    }
+}
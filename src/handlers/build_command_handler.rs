@@ -1,13 +1,26 @@
 use crate::arg_buliders::{
-    AllArgBuilder, AppendArgBuilder, CountArgBuilder, CountBySourceArgBuilder, DirArgBuilder,
-    EditInPlaceArgBuilder, EveryNthArgBuilder, ExcludeMatchesArgBuilder, FileArgBuilder,
-    FilesArgBuilder, FindBetweenArgBuilder, FindRegexArgBuilder, FindStringArgBuilder,
-    MaxDepthArgBuilder, NthArgBuilder, PrependArgBuilder, ReplaceWithArgBuilder, TextArgBuilder,
+    AllArgBuilder, AppendArgBuilder, ArchiveArgBuilder, AtRevArgBuilder, BalancedArgBuilder,
+    BinaryArgBuilder, ColumnArgBuilder, CountArgBuilder, CountBySourceArgBuilder,
+    CssSelectorArgBuilder, CsvArgBuilder, DirArgBuilder, DotallArgBuilder, EditInPlaceArgBuilder,
+    EncodingArgBuilder, EolArgBuilder, EveryNthArgBuilder, ExcludeMatchesArgBuilder,
+    FileArgBuilder, FilesArgBuilder, FilesFromArgBuilder, FindBetweenArgBuilder,
+    FindRegexArgBuilder, FindStringArgBuilder, GitChangedArgBuilder, GitDiffArgBuilder,
+    GitHunksArgBuilder, GitStagedArgBuilder, GlobalArgBuilder, IgnoreCaseArgBuilder,
+    InvertArgBuilder, JsonPathArgBuilder, KvKeyArgBuilder, KvSectionArgBuilder, LinesArgBuilder,
+    MaxDepthArgBuilder, MmapArgBuilder, MultilineArgBuilder, NoHeaderArgBuilder, NoMmapArgBuilder,
+    NthArgBuilder, NullArgBuilder, PatternsFileArgBuilder, PerSourceArgBuilder, PrependArgBuilder,
+    QuotesArgBuilder, ReplaceWithArgBuilder, SearchZipArgBuilder, SmartCaseArgBuilder,
+    StdinArgBuilder, StreamArgBuilder, TextArgBuilder, ThreadsArgBuilder, TomlKeyArgBuilder,
+    TsvArgBuilder, WordArgBuilder, XmlPathArgBuilder, YamlPathArgBuilder,
 };
 use crate::arg_group_builders::{
-    EditEvaluateArgGroupBuilder, EditInPlaceArgGroupBuilder, EvaluateArgGroupBuilder,
-    ExcludeMatchesArgGroupBuilder, FrequencyArgGroupBuilder, MaxDepthArgGroupBuilder,
-    SearchArgGroupBuilder,
+    ArchiveArgGroupBuilder, AtRevArgGroupBuilder, BalancedArgGroupBuilder,
+    CaseSensitivityArgGroupBuilder, ColumnArgGroupBuilder, EditEvaluateArgGroupBuilder,
+    EditInPlaceArgGroupBuilder, EvaluateArgGroupBuilder, ExcludeMatchesArgGroupBuilder,
+    FrequencyArgGroupBuilder, FrequencyScopeArgGroupBuilder, GitArgGroupBuilder,
+    GitHunksArgGroupBuilder, KvSectionArgGroupBuilder, MaxDepthArgGroupBuilder,
+    MmapArgGroupBuilder, NullArgGroupBuilder, SearchArgGroupBuilder, SourceArgGroupBuilder,
+    StreamArgGroupBuilder, StructuredArgGroupBuilder, TableArgGroupBuilder,
 };
 use crate::dto::dto::DTO;
 use crate::handlers::handler::Handler;
@@ -36,31 +49,86 @@ impl<'a> Handler<'a> for BuildCommandHandler {
         // Add arguments
         AllArgBuilder::build(&mut cmd);
         AppendArgBuilder::build(&mut cmd);
+        ArchiveArgBuilder::build(&mut cmd);
+        AtRevArgBuilder::build(&mut cmd);
+        BalancedArgBuilder::build(&mut cmd);
+        BinaryArgBuilder::build(&mut cmd);
+        ColumnArgBuilder::build(&mut cmd);
         CountArgBuilder::build(&mut cmd);
         CountBySourceArgBuilder::build(&mut cmd);
+        CssSelectorArgBuilder::build(&mut cmd);
+        CsvArgBuilder::build(&mut cmd);
         DirArgBuilder::build(&mut cmd);
+        DotallArgBuilder::build(&mut cmd);
         EditInPlaceArgBuilder::build(&mut cmd);
+        EncodingArgBuilder::build(&mut cmd);
+        EolArgBuilder::build(&mut cmd);
         EveryNthArgBuilder::build(&mut cmd);
         ExcludeMatchesArgBuilder::build(&mut cmd);
         FileArgBuilder::build(&mut cmd);
         FilesArgBuilder::build(&mut cmd);
+        FilesFromArgBuilder::build(&mut cmd);
         FindBetweenArgBuilder::build(&mut cmd);
         FindRegexArgBuilder::build(&mut cmd);
         FindStringArgBuilder::build(&mut cmd);
+        GitChangedArgBuilder::build(&mut cmd);
+        GitDiffArgBuilder::build(&mut cmd);
+        GitHunksArgBuilder::build(&mut cmd);
+        GitStagedArgBuilder::build(&mut cmd);
+        GlobalArgBuilder::build(&mut cmd);
+        IgnoreCaseArgBuilder::build(&mut cmd);
+        InvertArgBuilder::build(&mut cmd);
+        JsonPathArgBuilder::build(&mut cmd);
+        KvKeyArgBuilder::build(&mut cmd);
+        KvSectionArgBuilder::build(&mut cmd);
+        LinesArgBuilder::build(&mut cmd);
         MaxDepthArgBuilder::build(&mut cmd);
+        MmapArgBuilder::build(&mut cmd);
+        MultilineArgBuilder::build(&mut cmd);
+        NoHeaderArgBuilder::build(&mut cmd);
+        NoMmapArgBuilder::build(&mut cmd);
         NthArgBuilder::build(&mut cmd);
+        NullArgBuilder::build(&mut cmd);
+        PatternsFileArgBuilder::build(&mut cmd);
+        PerSourceArgBuilder::build(&mut cmd);
         PrependArgBuilder::build(&mut cmd);
+        QuotesArgBuilder::build(&mut cmd);
         ReplaceWithArgBuilder::build(&mut cmd);
+        SearchZipArgBuilder::build(&mut cmd);
+        SmartCaseArgBuilder::build(&mut cmd);
+        StdinArgBuilder::build(&mut cmd);
+        StreamArgBuilder::build(&mut cmd);
         TextArgBuilder::build(&mut cmd);
+        ThreadsArgBuilder::build(&mut cmd);
+        TomlKeyArgBuilder::build(&mut cmd);
+        TsvArgBuilder::build(&mut cmd);
+        WordArgBuilder::build(&mut cmd);
+        XmlPathArgBuilder::build(&mut cmd);
+        YamlPathArgBuilder::build(&mut cmd);
 
         // Add argument groups
+        ArchiveArgGroupBuilder::build(&mut cmd);
+        AtRevArgGroupBuilder::build(&mut cmd);
+        BalancedArgGroupBuilder::build(&mut cmd);
+        CaseSensitivityArgGroupBuilder::build(&mut cmd);
+        ColumnArgGroupBuilder::build(&mut cmd);
         EditEvaluateArgGroupBuilder::build(&mut cmd);
         EditInPlaceArgGroupBuilder::build(&mut cmd);
         EvaluateArgGroupBuilder::build(&mut cmd);
         ExcludeMatchesArgGroupBuilder::build(&mut cmd);
         FrequencyArgGroupBuilder::build(&mut cmd);
+        FrequencyScopeArgGroupBuilder::build(&mut cmd);
+        GitArgGroupBuilder::build(&mut cmd);
+        GitHunksArgGroupBuilder::build(&mut cmd);
+        KvSectionArgGroupBuilder::build(&mut cmd);
         MaxDepthArgGroupBuilder::build(&mut cmd);
+        MmapArgGroupBuilder::build(&mut cmd);
+        NullArgGroupBuilder::build(&mut cmd);
         SearchArgGroupBuilder::build(&mut cmd);
+        SourceArgGroupBuilder::build(&mut cmd);
+        StreamArgGroupBuilder::build(&mut cmd);
+        StructuredArgGroupBuilder::build(&mut cmd);
+        TableArgGroupBuilder::build(&mut cmd);
 
         dto.set_inputs(cmd.get_matches());
     }
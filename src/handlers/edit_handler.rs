@@ -1 +1,153 @@
+use super::handler::Handler;
+use crate::dto::dto::DTO;
+use crate::dto::edit::Edit;
+use crate::helpers::escaping::escape;
+use crate::helpers::lines::dominant_line_ending;
+use crate::strategy_factories::edit_strategy_factory::EditStrategyFactory;
 
+/// Records an edit for every hit, as given by `--append`, `--prepend`, or `--replace_with`.
+///
+/// Inserted text uses the line ending that most lines of the source use.
+/// A hit on an escaped value, like the contents of a JSON string, is edited with its escaping undone,
+/// and the edited value is escaped again, so the document stays valid.
+///
+/// # Panics
+///
+/// Panics if an edited value cannot be written where its hit was, before any source is written.
+pub struct EditHandler;
+
+impl<'a> Handler<'a> for EditHandler {
+    fn handle(&mut self, dto: &mut DTO<'a>) {
+        let inputs = dto
+            .get_inputs()
+            .clone()
+            .expect("The inputs must be set before editing");
+        let Some(edit_strategy) = EditStrategyFactory::make(&inputs) else {
+            return;
+        };
+
+        for source_index in 0..dto.get_source_count() {
+            let source = &dto.get_sources()[source_index];
+            let line_ending = dominant_line_ending(source.get_text());
+            let edits: Vec<Edit> = source
+                .get_hit_indices()
+                .iter()
+                .map(|hit_index| {
+                    let hit = &dto.get_hits()[*hit_index];
+                    let new_value = edit_strategy
+                        .edit(&hit.get_unescaped_value(source.get_text()), line_ending);
+                    let new_value = escape(&new_value, hit.get_escaping())
+                        .unwrap_or_else(|e| {
+                            panic!("Failed to edit source: '{}'. {}", source.get_name(), e)
+                        })
+                        .into_owned();
+                    Edit::replacing(hit.get_position(), hit.get_length(), new_value)
+                })
+                .collect();
+
+            for edit in edits {
+                dto.add_edit(edit, source_index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::hit::Hit;
+    use crate::helpers::edits::apply_edits;
+    use crate::helpers::json_path::JsonPath;
+    use crate::strategies::json_path_strategy::JsonPathSearchStrategy;
+    use crate::strategies::traits::search_strategy::SearchStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    use clap::{Arg, Command};
+
+    // Edits every string a JSON path selects with the given arguments and returns the edited text.
+    fn edit_json(path: &str, text: &str, args: Vec<&str>) -> String {
+        let inputs = Command::new("test")
+            .arg(Arg::new("append").long("append"))
+            .arg(Arg::new("prepend").long("prepend"))
+            .arg(Arg::new("replace_with").long("replace_with"))
+            .try_get_matches_from(args)
+            .unwrap();
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(inputs);
+        dto.add_source("json", text);
+        let mut strategy = JsonPathSearchStrategy::new(
+            JsonPath::parse(path).unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+        for hit in strategy.search(text) {
+            dto.add_hit(hit, 0);
+        }
+
+        EditHandler.handle(&mut dto);
+
+        apply_edits(text, dto.get_edits().iter())
+    }
+
+    #[test]
+    fn test_records_an_edit_for_every_hit() {
+        let inputs = Command::new("test")
+            .arg(Arg::new("append").long("append"))
+            .arg(Arg::new("prepend").long("prepend"))
+            .arg(Arg::new("replace_with").long("replace_with"))
+            .try_get_matches_from(vec!["test", "--replace_with", "baz"])
+            .unwrap();
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(inputs);
+        dto.add_source("first", "foo bar");
+        dto.add_source("second", "bar foo");
+        dto.add_hit(Hit::new(0, 3), 0);
+        dto.add_hit(Hit::new(4, 3), 1);
+
+        EditHandler.handle(&mut dto);
+
+        assert_eq!(dto.get_edit_count(), 2);
+        assert_eq!(dto.get_sources()[1].get_edit_indices(), &vec![1]);
+        assert_eq!(dto.get_edits()[1].get_position(), 4);
+        assert_eq!(dto.get_edits()[1].get_length(), 3);
+        assert_eq!(dto.get_edits()[1].get_new_value(), "baz");
+    }
+
+    #[test]
+    fn test_inserted_text_uses_the_dominant_line_ending() {
+        let inputs = Command::new("test")
+            .arg(Arg::new("append").long("append"))
+            .arg(Arg::new("prepend").long("prepend"))
+            .arg(Arg::new("replace_with").long("replace_with"))
+            .try_get_matches_from(vec!["test", "--append", "\nbar"])
+            .unwrap();
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(inputs);
+        dto.add_source("crlf", "foo\r\nbaz\r\nqux\n");
+        dto.add_hit(Hit::new(0, 3), 0);
+
+        EditHandler.handle(&mut dto);
+
+        assert_eq!(dto.get_edits()[0].get_new_value(), "foo\r\nbar");
+    }
+
+    #[test]
+    fn test_escapes_values_written_into_json_strings() {
+        let text = r#"{"path": "C:\\old \"dir\"", "port": 80}"#;
+
+        assert_eq!(
+            edit_json(
+                "$.path",
+                text,
+                vec!["test", "--replace_with", r#"D:\new "dir""#]
+            ),
+            r#"{"path": "D:\\new \"dir\"", "port": 80}"#
+        );
+        assert_eq!(
+            edit_json("$.path", text, vec!["test", "--append", "\\\n"]),
+            r#"{"path": "C:\\old \"dir\"\\\n", "port": 80}"#
+        );
+        assert_eq!(
+            edit_json("$.port", text, vec!["test", "--replace_with", "8080"]),
+            r#"{"path": "C:\\old \"dir\"", "port": 8080}"#
+        );
+    }
+}
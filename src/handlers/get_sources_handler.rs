@@ -1,10 +1,170 @@
 use super::handler::Handler;
 use crate::dto::dto::DTO;
+use crate::strategies::enums::binary_policy::BinaryPolicy;
+use crate::strategy_factories::source_strategy_factory::SourceStrategyFactory;
+use std::io::Write;
 
-pub struct GetSourcesHandler;
+/// Reads every source into the DTO.
+///
+/// Sources that look binary are recorded as skipped, unless `--binary` says to search them.
+/// A source that cannot be read is reported to `err` and left out, so the others are still read.
+pub struct GetSourcesHandler<E: Write> {
+    pipe: bool,
+    err: E,
+}
+
+impl<E: Write> GetSourcesHandler<E> {
+    // Constructor for GetSourcesHandler
+    pub fn new(pipe: bool, err: E) -> Self {
+        Self { pipe, err }
+    }
+}
 
-impl<'a> Handler<'a> for GetSourcesHandler {
+impl<'a, E: Write> Handler<'a> for GetSourcesHandler<E> {
     fn handle(&mut self, dto: &mut DTO<'a>) {
-        let strategy = 
+        let inputs = dto
+            .get_inputs()
+            .clone()
+            .expect("The inputs must be set before getting sources");
+        let source_strategy = SourceStrategyFactory::make(&inputs, self.pipe);
+        let binary_policy = SourceStrategyFactory::make_binary_policy(&inputs);
+        let encoding = SourceStrategyFactory::make_encoding(&inputs);
+        let names = source_strategy
+            .get_names()
+            .unwrap_or_else(|e| panic!("Failed to list sources. {}", e));
+
+        for name in names {
+            let text = match source_strategy.load(&name, encoding) {
+                Ok(text) => text,
+                Err(e) => {
+                    writeln!(self.err, "Failed to read source: '{}'. {}", name, e)
+                        .expect("Failed to print error");
+                    continue;
+                }
+            };
+
+            if text.is_binary() && binary_policy == BinaryPolicy::Skip {
+                dto.add_skipped_source(&name);
+                continue;
+            }
+
+            dto.add_source_text(&name, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction, Command};
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn get_sources(args: Vec<&str>) -> DTO<'static> {
+        get_sources_with_errors(args).0
+    }
+
+    fn get_sources_with_errors(args: Vec<&str>) -> (DTO<'static>, String) {
+        let inputs = Command::new("test")
+            .arg(Arg::new("dir").long("dir"))
+            .arg(Arg::new("file").long("file"))
+            .arg(Arg::new("files").long("files").num_args(1..))
+            .arg(Arg::new("files-from").long("files-from"))
+            .arg(Arg::new("null").long("null").action(ArgAction::SetTrue))
+            .arg(Arg::new("stdin").long("stdin").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("git-changed")
+                    .long("git-changed")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("git-staged")
+                    .long("git-staged")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("git-diff").long("git-diff"))
+            .arg(Arg::new("at-rev").long("at-rev"))
+            .arg(
+                Arg::new("git-hunks")
+                    .long("git-hunks")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("max-depth")
+                    .long("max-depth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(Arg::new("binary").long("binary"))
+            .arg(Arg::new("encoding").long("encoding"))
+            .arg(Arg::new("mmap").long("mmap").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("no-mmap")
+                    .long("no-mmap")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("search-zip")
+                    .long("search-zip")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("edit_in_place")
+                    .long("edit_in_place")
+                    .action(ArgAction::SetTrue),
+            )
+            .try_get_matches_from(args)
+            .unwrap();
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(inputs);
+        let mut err = Vec::new();
+        GetSourcesHandler::new(false, &mut err).handle(&mut dto);
+        (dto, String::from_utf8(err).unwrap())
+    }
+
+    #[test]
+    fn test_skips_binary_sources() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "text").unwrap();
+        fs::write(dir.path().join("b.bin"), b"\x7fELF\0\xff").unwrap();
+        let dir_path = dir.path().to_str().unwrap();
+
+        let dto = get_sources(vec!["test", "--dir", dir_path]);
+
+        assert_eq!(dto.get_source_count(), 1);
+        assert_eq!(dto.get_sources()[0].get_text(), "text");
+        assert_eq!(dto.get_skipped_sources().len(), 1);
+    }
+
+    #[test]
+    fn test_searches_binary_sources_as_text() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("b.bin"), b"bin\0ary").unwrap();
+        let dir_path = dir.path().to_str().unwrap();
+
+        let dto = get_sources(vec!["test", "--dir", dir_path, "--binary", "text"]);
+
+        assert_eq!(dto.get_source_count(), 1);
+        assert!(dto.get_sources()[0].is_binary());
+        assert!(dto.get_skipped_sources().is_empty());
+    }
+
+    #[test]
+    fn test_reports_unreadable_sources_and_reads_the_others() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("a.txt");
+        fs::write(&file_path, "text").unwrap();
+        let missing_path = dir.path().join("missing.txt");
+        let missing_path = missing_path.to_str().unwrap();
+
+        let (dto, err) = get_sources_with_errors(vec![
+            "test",
+            "--files",
+            missing_path,
+            file_path.to_str().unwrap(),
+        ]);
+
+        assert_eq!(dto.get_source_count(), 1);
+        assert_eq!(dto.get_sources()[0].get_text(), "text");
+        assert!(err.starts_with(&format!("Failed to read source: '{}'.", missing_path)));
     }
 }
@@ -5,4 +5,6 @@ pub mod get_sources_handler;
 pub mod handler;
 pub mod print_handler;
 pub mod search_handler;
+pub mod stream_handler;
 pub mod validate_handler;
+pub mod write_handler;
@@ -1 +1,172 @@
+use super::handler::Handler;
+use crate::dto::dto::DTO;
+use crate::strategies::enums::binary_policy::BinaryPolicy;
+use crate::strategy_factories::source_strategy_factory::SourceStrategyFactory;
+use std::io::{self, Write};
+use std::process;
 
+/// Prints the value of every hit, one per line, with its escaping undone.
+///
+/// When more than one source was searched, each value is prefixed with the name of its source.
+/// With `--binary report`, a binary source with a hit is reported once instead of printing its hits.
+/// The number of skipped binary sources is printed to `err` at the end.
+pub struct PrintHandler<W: Write, E: Write> {
+    out: W,
+    err: E,
+}
+
+impl<W: Write, E: Write> PrintHandler<W, E> {
+    // Constructor for PrintHandler
+    pub fn new(out: W, err: E) -> Self {
+        Self { out, err }
+    }
+}
+
+impl<'a, W: Write, E: Write> Handler<'a> for PrintHandler<W, E> {
+    fn handle(&mut self, dto: &mut DTO<'a>) {
+        let prefix_names = dto.get_source_count() > 1;
+        let binary_policy = dto.get_inputs().as_ref().map_or(
+            BinaryPolicy::Skip,
+            SourceStrategyFactory::make_binary_policy,
+        );
+
+        for source in dto.get_sources() {
+            if source.is_binary() && binary_policy == BinaryPolicy::Report {
+                if !source.get_hit_indices().is_empty() {
+                    writeln!(self.out, "{}: binary file matches", source.get_name())
+                        .unwrap_or_else(|e| exit_on_write_error(e));
+                }
+                continue;
+            }
+
+            let source_name = Some(source.get_name()).filter(|_| prefix_names);
+
+            for hit_index in source.get_hit_indices() {
+                let value = dto.get_hits()[*hit_index].get_unescaped_value(source.get_text());
+                print_hit(&mut self.out, source_name, &value)
+                    .unwrap_or_else(|e| exit_on_write_error(e));
+            }
+        }
+
+        self.out.flush().unwrap_or_else(|e| exit_on_write_error(e));
+
+        let skipped_count = dto.get_skipped_sources().len();
+        if skipped_count > 0 {
+            writeln!(
+                self.err,
+                "Skipped {} binary file{}",
+                skipped_count,
+                if skipped_count == 1 { "" } else { "s" }
+            )
+            .expect("Failed to print summary");
+        }
+    }
+}
+
+/// Prints the value of a hit on its own line.
+///
+/// # Parameters
+/// - `out`: Where to print the hit.
+/// - `source_name`: The name of the source the hit was found in, printed before the value if given.
+/// - `value`: The value of the hit.
+///
+/// # Examples
+///
+/// ```
+/// use seer::handlers::print_handler::print_hit;
+///
+/// let mut out = Vec::new();
+/// print_hit(&mut out, Some("notes.txt"), "foo").unwrap();
+/// print_hit(&mut out, None, "bar").unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "notes.txt:foo\nbar\n");
+/// ```
+pub fn print_hit(out: &mut dyn Write, source_name: Option<&str>, value: &str) -> io::Result<()> {
+    match source_name {
+        Some(source_name) => writeln!(out, "{}:{}", source_name, value),
+        None => writeln!(out, "{}", value),
+    }
+}
+
+/// Ends the process after printing to the output failed.
+///
+/// A reader that closed the pipe, like `head`, wants no more output, so that is a quiet success.
+/// Any other error is reported on stderr and is a failure.
+pub fn exit_on_write_error(e: io::Error) -> ! {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        process::exit(0);
+    }
+
+    eprintln!("Failed to print output. {}", e);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::hit::Hit;
+    use crate::dto::source_text::SourceText;
+    use clap::{Arg, Command};
+
+    fn get_dto() -> DTO<'static> {
+        DTO::new("test", "1.0", "test", "test")
+    }
+
+    fn print(dto: &mut DTO<'static>) -> (String, String) {
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        PrintHandler::new(&mut out, &mut err).handle(dto);
+        (
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_prints_hit_values_of_a_single_source() {
+        let mut dto = get_dto();
+        dto.add_source("text", "foo bar foo");
+        dto.add_hit(Hit::new(0, 3), 0);
+        dto.add_hit(Hit::new(8, 3), 0);
+
+        assert_eq!(print(&mut dto).0, "foo\nfoo\n");
+    }
+
+    #[test]
+    fn test_prefixes_hit_values_with_source_names() {
+        let mut dto = get_dto();
+        dto.add_source("a.txt", "foo");
+        dto.add_source("b.txt", "bar foo");
+        dto.add_hit(Hit::new(0, 3), 0);
+        dto.add_hit(Hit::new(4, 3), 1);
+
+        assert_eq!(print(&mut dto).0, "a.txt:foo\nb.txt:foo\n");
+    }
+
+    #[test]
+    fn test_reports_binary_sources_with_a_hit() {
+        let mut dto = get_dto();
+        dto.set_inputs(
+            Command::new("test")
+                .arg(Arg::new("binary").long("binary"))
+                .try_get_matches_from(vec!["test", "--binary", "report"])
+                .unwrap(),
+        );
+        dto.add_source_text("a.bin", SourceText::from_bytes(b"foo\0".to_vec(), None));
+        dto.add_source_text("b.bin", SourceText::from_bytes(b"bar\0".to_vec(), None));
+        dto.add_hit(Hit::new(0, 3), 0);
+
+        assert_eq!(print(&mut dto).0, "a.bin: binary file matches\n");
+    }
+
+    #[test]
+    fn test_prints_the_number_of_skipped_sources() {
+        let mut dto = get_dto();
+        dto.add_skipped_source("a.bin");
+        dto.add_skipped_source("b.bin");
+
+        assert_eq!(
+            print(&mut dto),
+            (String::new(), String::from("Skipped 2 binary files\n"))
+        );
+    }
+}
@@ -1,22 +1,151 @@
-use super::hit::Hit;
-use super::strategies::search_strategy_factory::SearchStrategyFactory;
+use super::handler::Handler;
 use crate::dto::dto::DTO;
-use crate::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
-use crate::search::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
-use crate::search::search_strategies::search_strategy_factory::SearchStrategyFactory;
+use crate::dto::hit::Hit;
+use crate::helpers::worker_pool::{map_in_order, thread_count};
+use crate::strategies::all_strategy::AllStrategy;
+use crate::strategies::enums::frequency_scope::FrequencyScope;
+use crate::strategies::traits::frequency_strategy::FrequencyStrategy;
+use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+use crate::strategy_factories::search_strategy_factory::SearchStrategyFactory;
 use clap::ArgMatches;
+use std::io::{self, Write};
 
-pub fn search(inputs: &ArgMatches, dto: &mut DTO) {
-    let frequency_strategy = FrequencyStrategyFactory::make(inputs);
-    let mut search_strategy = SearchStrategyFactory::make(inputs, frequency_strategy);
-    search_strategy.search(dto);
+/// Searches the sources in the DTO.
+///
+/// A source that cannot be searched as asked, like a CSV source without the named `--column`,
+/// is reported to `err` and recorded as unsearched, so the others are still searched.
+pub struct SearchHandler<E: Write> {
+    err: E,
+}
+
+impl<E: Write> SearchHandler<E> {
+    // Constructor for SearchHandler
+    pub fn new(err: E) -> Self {
+        Self { err }
+    }
+}
+
+impl<'a, E: Write> Handler<'a> for SearchHandler<E> {
+    /// Searches every source in the DTO and records the hits found.
+    ///
+    /// With `FrequencyScope::PerSource`, the frequency strategy is reset before each source,
+    /// so `--nth 1` finds the first match in every source. With `FrequencyScope::Global`,
+    /// the count carries over between sources and the search stops once the frequency is met.
+    ///
+    /// Several sources are searched on a pool of `--threads` workers, one per CPU by default.
+    /// Hits are still recorded in source order, so the output doesn't depend on the thread count.
+    fn handle(&mut self, dto: &mut DTO<'a>) {
+        let inputs = dto
+            .get_inputs()
+            .clone()
+            .expect("The inputs must be set before searching");
+        let threads = thread_count(inputs.get_one::<usize>("threads").copied());
+
+        if threads > 1 && dto.get_source_count() > 1 {
+            self.search_in_parallel(&inputs, dto, threads);
+        } else {
+            self.search_in_order(&inputs, dto);
+        }
+    }
+}
+
+impl<E: Write> SearchHandler<E> {
+    // Searches the sources one after another on this thread.
+    fn search_in_order(&mut self, inputs: &ArgMatches, dto: &mut DTO) {
+        let scope = FrequencyStrategyFactory::make_scope(inputs);
+        let frequency_strategy = FrequencyStrategyFactory::make(inputs);
+        let mut search_strategy = SearchStrategyFactory::make(inputs, frequency_strategy);
+
+        for source_index in 0..dto.get_source_count() {
+            let frequency_strategy = search_strategy.get_frequency_strategy();
+
+            match scope {
+                FrequencyScope::PerSource => frequency_strategy.reset(),
+                FrequencyScope::Global if frequency_strategy.is_done() => break,
+                FrequencyScope::Global => {}
+            }
+
+            let source = &dto.get_sources()[source_index];
+            search_strategy.set_source_name(source.get_name());
+            if let Err(e) = search_strategy.check(source.get_text()) {
+                let name = String::from(source.get_name());
+                self.report_unsearched(dto, &name, e);
+                continue;
+            }
+
+            let hits = search_strategy.search(source.get_text());
+
+            for hit in hits {
+                dto.add_hit(hit, source_index);
+            }
+        }
+    }
+
+    // Searches the sources on a pool of workers, then records their hits in source order.
+    fn search_in_parallel(&mut self, inputs: &ArgMatches, dto: &mut DTO, threads: usize) {
+        let scope = FrequencyStrategyFactory::make_scope(inputs);
+        let hits_by_source = map_in_order(
+            dto.get_sources(),
+            threads,
+            || {
+                // A global count depends on every earlier source, so it is applied once all are searched
+                let frequency_strategy: Box<dyn FrequencyStrategy> = match scope {
+                    FrequencyScope::PerSource => FrequencyStrategyFactory::make(inputs),
+                    FrequencyScope::Global => Box::new(AllStrategy::new()),
+                };
+                SearchStrategyFactory::make(inputs, frequency_strategy)
+            },
+            |search_strategy, source| {
+                search_strategy.set_source_name(source.get_name());
+                search_strategy.check(source.get_text())?;
+                search_strategy.get_frequency_strategy().reset();
+                Ok(search_strategy.search(source.get_text()))
+            },
+        );
+
+        let mut frequency_strategy = FrequencyStrategyFactory::make(inputs);
+
+        for (source_index, hits) in hits_by_source.into_iter().enumerate() {
+            let hits = match hits {
+                Ok(hits) => hits,
+                Err(e) => {
+                    let name = String::from(dto.get_sources()[source_index].get_name());
+                    self.report_unsearched(dto, &name, e);
+                    continue;
+                }
+            };
+
+            for hit in hits {
+                if scope == FrequencyScope::Global {
+                    if frequency_strategy.is_done() {
+                        return;
+                    }
+                    if !frequency_strategy.matches_frequency() {
+                        continue;
+                    }
+                }
+
+                dto.add_hit(hit, source_index);
+            }
+        }
+    }
+
+    // Reports a source that could not be searched, and records it so the exit status can show it.
+    fn report_unsearched(&mut self, dto: &mut DTO, name: &str, e: io::Error) {
+        writeln!(self.err, "Failed to search source: '{}'. {}", name, e)
+            .expect("Failed to print error");
+        dto.add_unsearched_source(name);
+    }
 }
 
-/// Applies frequency filtering to the hits based on the inputs.
+/// Applies frequency filtering to hits that were found without one.
+///
+/// This uses the same `FrequencyStrategy` as the search itself, so filtering the hits
+/// afterwards keeps exactly the hits that the streaming search would have kept.
 ///
 /// # Parameters
 /// - `inputs`: The command line arguments.
-/// - `hits`: The vector of `Hit` structs to filter.
+/// - `hits`: The vector of `Hit` structs to filter, in the order they were found.
 ///
 /// # Returns
 /// A filtered vector of `Hit` structs.
@@ -25,47 +154,38 @@ pub fn search(inputs: &ArgMatches, dto: &mut DTO) {
 ///
 /// ```
 /// use clap::{ArgMatches, Command};
-/// use seer::hit::Hit;
-/// use seer::search::apply_frequency;
+/// use seer::dto::hit::Hit;
+/// use seer::handlers::search_handler::apply_frequency;
 ///
 /// let inputs = Command::new("test")
 ///     .arg(clap::arg!(--nth <NTH> "Find/replace only the nth match").value_parser(clap::value_parser!(u64)))
-///     .try_get_matches_from(vec!["test", "--nth", "1"])
+///     .arg(clap::arg!(--every_nth <EVERY_NTH> "Process every nth match").value_parser(clap::value_parser!(u64)))
+///     .arg(clap::arg!(--all "Find all matches"))
+///     .try_get_matches_from(vec!["test", "--nth", "2"])
 ///     .unwrap();
 ///
-/// let hits = vec![
-///     Hit::new(String::from("hit1"), 0),
-///     Hit::new(String::from("hit2"), 1),
-///     Hit::new(String::from("hit3"), 2),
-/// ];
+/// let searchable = "hit1 hit2 hit3";
+/// let hits = vec![Hit::new(0, 4), Hit::new(5, 4), Hit::new(10, 4)];
 ///
 /// let filtered_hits = apply_frequency(&inputs, hits);
 /// assert_eq!(filtered_hits.len(), 1);
-/// assert_eq!(filtered_hits[0].get_value(), "hit2");
+/// assert_eq!(filtered_hits[0].get_value(searchable), "hit2");
 /// ```
 pub fn apply_frequency(inputs: &ArgMatches, hits: Vec<Hit>) -> Vec<Hit> {
-    if let Some(nth) = inputs.get_one::<u64>("nth") {
-        hits.into_iter().nth(*nth as usize).into_iter().collect()
-    } else if let Some(every_nth) = inputs.get_one::<u64>("every_nth") {
-        hits.into_iter()
-            .enumerate()
-            .filter_map(|(i, hit)| {
-                if (i + 1) % *every_nth as usize == 0 {
-                    Some(hit)
-                } else {
-                    None
-                }
-            })
-            .collect()
-    } else {
-        hits
-    }
+    FrequencyStrategyFactory::make(inputs).filter(hits)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use clap::{Arg, Command};
+    use crate::helpers::search_modifiers::SearchModifiers;
+    use crate::strategies::enums::column::Column;
+    use crate::strategies::find_string_strategy::ExactSearchStrategy;
+    use crate::strategies::traits::search_strategy::SearchStrategy;
+    use clap::{Arg, ArgAction, Command};
+    use proptest::prelude::*;
+
+    const SEARCHABLE: &str = "hit1 hit2 hit3 hit4";
 
     fn get_test_inputs(args: Vec<&str>) -> ArgMatches {
         Command::new("test")
@@ -79,47 +199,290 @@ mod tests {
                     .long("every_nth")
                     .value_parser(clap::value_parser!(u64)),
             )
+            .arg(Arg::new("all").long("all").action(ArgAction::SetTrue))
             .try_get_matches_from(args)
             .unwrap()
     }
 
+    fn get_test_hits() -> Vec<Hit> {
+        vec![
+            Hit::new(0, 4),
+            Hit::new(5, 4),
+            Hit::new(10, 4),
+            Hit::new(15, 4),
+        ]
+    }
+
     #[test]
     fn test_apply_frequency_nth() {
+        let inputs = get_test_inputs(vec!["test", "--nth", "2"]);
+        let filtered_hits = apply_frequency(&inputs, get_test_hits());
+        assert_eq!(filtered_hits.len(), 1);
+        assert_eq!(filtered_hits[0].get_value(SEARCHABLE), "hit2");
+    }
+
+    #[test]
+    fn test_apply_frequency_nth_counts_from_one() {
         let inputs = get_test_inputs(vec!["test", "--nth", "1"]);
-        let hits = vec![
-            Hit::new(String::from("hit1"), 0),
-            Hit::new(String::from("hit2"), 1),
-            Hit::new(String::from("hit3"), 2),
-        ];
-        let filtered_hits = apply_frequency(&inputs, hits);
+        let filtered_hits = apply_frequency(&inputs, get_test_hits());
         assert_eq!(filtered_hits.len(), 1);
-        assert_eq!(filtered_hits[0].get_value(), "hit2");
+        assert_eq!(filtered_hits[0].get_value(SEARCHABLE), "hit1");
     }
 
     #[test]
     fn test_apply_frequency_every_nth() {
         let inputs = get_test_inputs(vec!["test", "--every_nth", "2"]);
-        let hits = vec![
-            Hit::new(String::from("hit1"), 0),
-            Hit::new(String::from("hit2"), 1),
-            Hit::new(String::from("hit3"), 2),
-            Hit::new(String::from("hit4"), 3),
-        ];
-        let filtered_hits = apply_frequency(&inputs, hits);
+        let filtered_hits = apply_frequency(&inputs, get_test_hits());
         assert_eq!(filtered_hits.len(), 2);
-        assert_eq!(filtered_hits[0].get_value(), "hit2");
-        assert_eq!(filtered_hits[1].get_value(), "hit4");
+        assert_eq!(filtered_hits[0].get_value(SEARCHABLE), "hit2");
+        assert_eq!(filtered_hits[1].get_value(SEARCHABLE), "hit4");
     }
 
     #[test]
-    fn test_apply_frequency_no_frequency() {
+    fn test_apply_frequency_all() {
+        let inputs = get_test_inputs(vec!["test", "--all"]);
+        let filtered_hits = apply_frequency(&inputs, get_test_hits());
+        assert_eq!(filtered_hits.len(), 4);
+    }
+
+    #[test]
+    fn test_apply_frequency_no_frequency_keeps_first_hit() {
         let inputs = get_test_inputs(vec!["test"]);
-        let hits = vec![
-            Hit::new(String::from("hit1"), 0),
-            Hit::new(String::from("hit2"), 1),
-            Hit::new(String::from("hit3"), 2),
+        let filtered_hits = apply_frequency(&inputs, get_test_hits());
+        assert_eq!(filtered_hits.len(), 1);
+        assert_eq!(filtered_hits[0].get_value(SEARCHABLE), "hit1");
+    }
+
+    fn search_streaming(args: &[String], searchable: &str) -> Vec<(usize, usize)> {
+        let inputs = get_test_inputs(args.iter().map(String::as_str).collect());
+        let frequency_strategy = FrequencyStrategyFactory::make(&inputs);
+        ExactSearchStrategy::new(
+            vec![String::from("a")],
+            SearchModifiers::default(),
+            frequency_strategy,
+        )
+        .search(searchable)
+        .iter()
+        .map(|hit| (hit.get_position(), hit.get_length()))
+        .collect()
+    }
+
+    fn search_post_filtered(args: &[String], searchable: &str) -> Vec<(usize, usize)> {
+        let inputs = get_test_inputs(args.iter().map(String::as_str).collect());
+        let all_hits = ExactSearchStrategy::new(
+            vec![String::from("a")],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .search(searchable);
+        apply_frequency(&inputs, all_hits)
+            .iter()
+            .map(|hit| (hit.get_position(), hit.get_length()))
+            .collect()
+    }
+
+    fn frequency_args() -> impl Strategy<Value = Vec<String>> {
+        prop_oneof![
+            Just(vec![String::from("test")]),
+            Just(vec![String::from("test"), String::from("--all")]),
+            (1u64..8).prop_map(|n| vec![
+                String::from("test"),
+                String::from("--nth"),
+                n.to_string()
+            ]),
+            (1u64..8).prop_map(|n| vec![
+                String::from("test"),
+                String::from("--every_nth"),
+                n.to_string()
+            ]),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn streaming_and_post_filter_keep_the_same_hits(
+            args in frequency_args(),
+            searchable in "[ab]{0,40}",
+        ) {
+            prop_assert_eq!(
+                search_streaming(&args, &searchable),
+                search_post_filtered(&args, &searchable)
+            );
+        }
+    }
+
+    fn get_search_inputs(args: Vec<&str>) -> ArgMatches {
+        Command::new("test")
+            .arg(Arg::new("find_string").long("find_string"))
+            .arg(Arg::new("patterns-file").long("patterns-file"))
+            .arg(
+                Arg::new("nth")
+                    .long("nth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(
+                Arg::new("every_nth")
+                    .long("every_nth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(Arg::new("all").long("all").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("ignore-case")
+                    .long("ignore-case")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("smart-case")
+                    .long("smart-case")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("word").long("word").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("multiline")
+                    .long("multiline")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("dotall").long("dotall").action(ArgAction::SetTrue))
+            .arg(Arg::new("lines").long("lines").action(ArgAction::SetTrue))
+            .arg(Arg::new("csv").long("csv").action(ArgAction::SetTrue))
+            .arg(Arg::new("tsv").long("tsv").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("column")
+                    .long("column")
+                    .value_parser(clap::value_parser!(Column)),
+            )
+            .arg(
+                Arg::new("no-header")
+                    .long("no-header")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("invert").long("invert").action(ArgAction::SetTrue))
+            .arg(Arg::new("global").long("global").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("per-source")
+                    .long("per-source")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("threads")
+                    .long("threads")
+                    .value_parser(clap::value_parser!(usize)),
+            )
+            .try_get_matches_from(args)
+            .unwrap()
+    }
+
+    fn get_search_dto<'a>(inputs: ArgMatches) -> DTO<'a> {
+        let mut dto = DTO::new("test", "1.0", "author", "about");
+        dto.set_inputs(inputs);
+        dto.add_source("first", "use a;\nuse b;\n");
+        dto.add_source("second", "use c;\nuse d;\n");
+        dto
+    }
+
+    #[test]
+    fn test_search_handler_global_scope_finds_first_hit_overall() {
+        let inputs = get_search_inputs(vec!["test", "--find_string", "use", "--nth", "1"]);
+        let mut dto = get_search_dto(inputs);
+        SearchHandler::new(io::sink()).handle(&mut dto);
+
+        assert_eq!(dto.get_hit_count(), 1);
+        assert_eq!(dto.get_sources()[0].get_hit_indices(), &vec![0]);
+        assert!(dto.get_sources()[1].get_hit_indices().is_empty());
+    }
+
+    #[test]
+    fn test_search_handler_per_source_scope_finds_first_hit_in_each_source() {
+        let inputs = get_search_inputs(vec![
+            "test",
+            "--find_string",
+            "use",
+            "--nth",
+            "1",
+            "--per-source",
+        ]);
+        let mut dto = get_search_dto(inputs);
+        SearchHandler::new(io::sink()).handle(&mut dto);
+
+        assert_eq!(dto.get_hit_count(), 2);
+        assert_eq!(dto.get_sources()[0].get_hit_indices(), &vec![0]);
+        assert_eq!(dto.get_sources()[1].get_hit_indices(), &vec![1]);
+        assert_eq!(dto.get_hits()[1].get_position(), 0);
+    }
+
+    fn get_hit_positions(dto: &DTO) -> Vec<Vec<usize>> {
+        dto.get_sources()
+            .iter()
+            .map(|source| {
+                source
+                    .get_hit_indices()
+                    .iter()
+                    .map(|hit_index| dto.get_hits()[*hit_index].get_position())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_search_handler_threads_find_the_same_hits_in_source_order() {
+        let frequencies: [&[&str]; 4] = [
+            &["--all"],
+            &["--nth", "3"],
+            &["--every_nth", "2"],
+            &["--nth", "1", "--per-source"],
         ];
-        let filtered_hits = apply_frequency(&inputs, hits);
-        assert_eq!(filtered_hits.len(), 3);
+
+        for frequency in frequencies {
+            let mut results = Vec::new();
+
+            for threads in ["1", "4"] {
+                let mut args = vec!["test", "--find_string", "use", "--threads", threads];
+                args.extend_from_slice(frequency);
+                let mut dto = DTO::new("test", "1.0", "author", "about");
+                dto.set_inputs(get_search_inputs(args));
+                for source_index in 0..20 {
+                    dto.add_source(
+                        &source_index.to_string(),
+                        &"use x; ".repeat(source_index % 4),
+                    );
+                }
+
+                SearchHandler::new(io::sink()).handle(&mut dto);
+                results.push(get_hit_positions(&dto));
+            }
+
+            assert_eq!(results[0], results[1], "{:?}", frequency);
+        }
+    }
+
+    #[test]
+    fn test_search_handler_reports_sources_without_the_named_column() {
+        for threads in ["1", "4"] {
+            let inputs = get_search_inputs(vec![
+                "test",
+                "--find_string",
+                "a",
+                "--all",
+                "--csv",
+                "--column",
+                "email",
+                "--threads",
+                threads,
+            ]);
+            let mut dto = DTO::new("test", "1.0", "author", "about");
+            dto.set_inputs(inputs);
+            dto.add_source("people", "name,email\nann,ann@a.example\n");
+            dto.add_source("places", "name,city\nparis,paris\n");
+
+            let mut err = Vec::new();
+            SearchHandler::new(&mut err).handle(&mut dto);
+
+            assert_eq!(dto.get_hit_count(), 3);
+            assert_eq!(dto.get_unsearched_sources(), &vec![String::from("places")]);
+            assert_eq!(
+                String::from_utf8(err).unwrap(),
+                "Failed to search source: 'places'. The header has no column 'email'\n"
+            );
+        }
     }
 }
@@ -0,0 +1,413 @@
+use super::handler::Handler;
+use super::print_handler::{exit_on_write_error, print_hit};
+use crate::dto::dto::DTO;
+use crate::helpers::binary::{is_binary, BINARY_WINDOW};
+use crate::helpers::encoding::{DecodingReader, SourceEncoding};
+use crate::helpers::stream_search::StreamSearcher;
+use crate::strategies::all_strategy::AllStrategy;
+use crate::strategies::enums::binary_policy::BinaryPolicy;
+use crate::strategies::enums::encoding::Encoding;
+use crate::strategies::enums::frequency_scope::FrequencyScope;
+use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+use crate::strategy_factories::search_strategy_factory::SearchStrategyFactory;
+use crate::strategy_factories::source_strategy_factory::SourceStrategyFactory;
+use std::io::{self, Cursor, Read, Write};
+
+/// Searches every source as a stream and prints each hit as soon as it is found.
+///
+/// This replaces the get sources, search, and print stages when no edits are requested.
+/// Sources are never read into the DTO, so memory use stays bounded however large they are.
+///
+/// Sources are decoded and checked for binary content from their start, with the same `--encoding`
+/// and `--binary` rules as when they are read whole. A source that cannot be read is reported to `err`
+/// and the others are still searched.
+pub struct StreamHandler<W: Write, E: Write> {
+    pipe: bool,
+    out: W,
+    err: E,
+    searcher: StreamSearcher,
+}
+
+impl<W: Write, E: Write> StreamHandler<W, E> {
+    // Constructor for StreamHandler
+    pub fn new(pipe: bool, out: W, err: E) -> Self {
+        Self {
+            pipe,
+            out,
+            err,
+            searcher: StreamSearcher::default(),
+        }
+    }
+
+    // Set the searcher used for each source
+    pub fn set_searcher(&mut self, searcher: StreamSearcher) {
+        self.searcher = searcher;
+    }
+}
+
+impl<'a, W: Write, E: Write> Handler<'a> for StreamHandler<W, E> {
+    /// Streams every source through the search and prints the hits.
+    ///
+    /// Frequencies are applied as hits are found, with the same scope rules as `SearchHandler`.
+    fn handle(&mut self, dto: &mut DTO<'a>) {
+        let inputs = dto
+            .get_inputs()
+            .clone()
+            .expect("The inputs must be set before streaming");
+        let source_strategy = SourceStrategyFactory::make(&inputs, self.pipe);
+        let binary_policy = SourceStrategyFactory::make_binary_policy(&inputs);
+        let encoding = SourceStrategyFactory::make_encoding(&inputs);
+        let scope = FrequencyStrategyFactory::make_scope(&inputs);
+        let mut frequency_strategy = FrequencyStrategyFactory::make(&inputs);
+        // Chunks overlap, so the frequency is only applied to hits once they are final
+        let mut search_strategy =
+            SearchStrategyFactory::make(&inputs, Box::new(AllStrategy::new()));
+
+        let names = source_strategy
+            .get_names()
+            .unwrap_or_else(|e| panic!("Failed to list sources. {}", e));
+        let prefix_names = names.len() > 1;
+        let mut skipped_count = 0;
+
+        for name in names {
+            match scope {
+                FrequencyScope::PerSource => frequency_strategy.reset(),
+                FrequencyScope::Global if frequency_strategy.is_done() => break,
+                FrequencyScope::Global => {}
+            }
+
+            let (reader, binary) = match source_strategy
+                .open(&name)
+                .and_then(|reader| open_text(reader, encoding))
+            {
+                Ok(opened) => opened,
+                Err(e) => {
+                    writeln!(self.err, "Failed to read source: '{}'. {}", name, e)
+                        .expect("Failed to print error");
+                    continue;
+                }
+            };
+
+            if binary && binary_policy == BinaryPolicy::Skip {
+                skipped_count += 1;
+                continue;
+            }
+
+            let report = binary && binary_policy == BinaryPolicy::Report;
+            let mut matched = false;
+            let source_name = Some(name.as_str()).filter(|_| prefix_names);
+            let out = &mut self.out;
+
+            search_strategy.set_source_name(&name);
+            let result = self.searcher.search(
+                reader,
+                search_strategy.as_mut(),
+                frequency_strategy.as_mut(),
+                |_, value| {
+                    matched = true;
+                    if report {
+                        return;
+                    }
+                    print_hit(out, source_name, value).unwrap_or_else(|e| exit_on_write_error(e));
+                    // Hits are printed as they are found, even from an endless pipe
+                    out.flush().unwrap_or_else(|e| exit_on_write_error(e));
+                },
+            );
+
+            if report && matched {
+                writeln!(self.out, "{}: binary file matches", name)
+                    .unwrap_or_else(|e| exit_on_write_error(e));
+            }
+            if let Err(e) = result {
+                writeln!(self.err, "Failed to read source: '{}'. {}", name, e)
+                    .expect("Failed to print error");
+            }
+        }
+
+        self.out.flush().unwrap_or_else(|e| exit_on_write_error(e));
+
+        if skipped_count > 0 {
+            writeln!(
+                self.err,
+                "Skipped {} binary file{}",
+                skipped_count,
+                if skipped_count == 1 { "" } else { "s" }
+            )
+            .expect("Failed to print summary");
+        }
+    }
+}
+
+// A source read as UTF-8, after its start was read to sniff it
+type SourceReader<R> = DecodingReader<io::Chain<Cursor<Vec<u8>>, R>>;
+
+// Reads the start of a source to find its encoding and whether it looks binary, as when it is read whole.
+//
+// A pipe that pauses before the window is full is sniffed from what it has given so far,
+// so its first lines are searched as soon as they arrive.
+//
+// Returns a reader of the whole source as UTF-8, and true if it looks binary.
+fn open_text<R: Read>(
+    mut reader: R,
+    requested: Option<Encoding>,
+) -> io::Result<(SourceReader<R>, bool)> {
+    // Reading past the window tells a character cut off by the window from one cut off by the end
+    let mut start = vec![0; BINARY_WINDOW + 4];
+    let mut filled = 0;
+    let mut is_end = false;
+    while filled < start.len() {
+        let read = match reader.read(&mut start[filled..]) {
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        is_end = read == 0;
+        let is_short = filled + read < start.len();
+        filled += read;
+        if is_end || is_short {
+            break;
+        }
+    }
+    start.truncate(filled);
+
+    // A pause may also cut off a character, which is not a sign of binary content
+    let text_end = match std::str::from_utf8(&start) {
+        Err(e) if !is_end && e.error_len().is_none() => e.valid_up_to(),
+        _ => start.len(),
+    };
+    let source_encoding = SourceEncoding::sniff(&start, requested);
+    let binary = source_encoding.get_encoding() == Encoding::Utf8
+        && is_binary(&start[source_encoding.get_bom_length().min(text_end)..text_end]);
+
+    Ok((
+        source_encoding.decoding_reader(Cursor::new(start).chain(reader)),
+        binary,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction, Command};
+    use tempfile::NamedTempFile;
+
+    fn stream(args: Vec<&str>) -> String {
+        stream_with_errors(args).0
+    }
+
+    fn stream_with_errors(args: Vec<&str>) -> (String, String) {
+        let mut cmd = Command::new("test")
+            .arg(
+                Arg::new("find_string")
+                    .long("find_string")
+                    .action(ArgAction::Append),
+            )
+            .arg(
+                Arg::new("find_regex")
+                    .long("find_regex")
+                    .action(ArgAction::Append),
+            )
+            .arg(
+                Arg::new("nth")
+                    .long("nth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(
+                Arg::new("every_nth")
+                    .long("every_nth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(Arg::new("dir").long("dir"))
+            .arg(Arg::new("file").long("file"))
+            .arg(Arg::new("files").long("files").num_args(1..))
+            .arg(Arg::new("archive").long("archive"))
+            .arg(Arg::new("files-from").long("files-from"))
+            .arg(Arg::new("git-diff").long("git-diff"))
+            .arg(Arg::new("at-rev").long("at-rev"))
+            .arg(Arg::new("text").long("text"))
+            .arg(Arg::new("binary").long("binary"))
+            .arg(Arg::new("encoding").long("encoding"));
+        for flag in [
+            "all",
+            "per-source",
+            "ignore-case",
+            "smart-case",
+            "word",
+            "multiline",
+            "dotall",
+            "lines",
+            "csv",
+            "tsv",
+            "invert",
+            "mmap",
+            "no-mmap",
+            "search-zip",
+            "null",
+            "stdin",
+            "git-changed",
+            "git-staged",
+            "git-hunks",
+            "edit_in_place",
+        ] {
+            cmd = cmd.arg(Arg::new(flag).long(flag).action(ArgAction::SetTrue));
+        }
+
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(cmd.try_get_matches_from(args).unwrap());
+
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let mut handler = StreamHandler::new(false, &mut out, &mut err);
+        handler.set_searcher(StreamSearcher::new(3, 2));
+        handler.handle(&mut dto);
+        (
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_prints_every_hit() {
+        let out = stream(vec![
+            "test",
+            "--find_regex",
+            r"\d+",
+            "--all",
+            "--text",
+            "a1 b22 c333",
+        ]);
+        assert_eq!(out, "1\n22\n333\n");
+    }
+
+    #[test]
+    fn test_applies_the_frequency() {
+        let out = stream(vec![
+            "test",
+            "--find_string",
+            "foo",
+            "--nth",
+            "2",
+            "--text",
+            "foo bar foo baz foo",
+        ]);
+        assert_eq!(out, "foo\n");
+    }
+
+    #[test]
+    fn test_prefixes_hits_with_source_names() {
+        let mut first_file = NamedTempFile::new().unwrap();
+        write!(first_file, "foo bar").unwrap();
+        let mut second_file = NamedTempFile::new().unwrap();
+        write!(second_file, "bar foo").unwrap();
+        let first_path = first_file.path().to_str().unwrap();
+        let second_path = second_file.path().to_str().unwrap();
+
+        let out = stream(vec![
+            "test",
+            "--find_string",
+            "foo",
+            "--per-source",
+            "--files",
+            first_path,
+            second_path,
+        ]);
+        assert_eq!(out, format!("{}:foo\n{}:foo\n", first_path, second_path));
+    }
+
+    #[test]
+    fn test_decodes_sources_that_are_not_utf8() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"caf\xE9 foo").unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let out = stream(vec![
+            "test",
+            "--find_string",
+            "\u{e9}",
+            "--encoding",
+            "latin1",
+            "--file",
+            path,
+        ]);
+        assert_eq!(out, "\u{e9}\n");
+    }
+
+    #[test]
+    fn test_skips_binary_sources() {
+        let mut binary_file = NamedTempFile::new().unwrap();
+        binary_file.write_all(b"foo\0bar").unwrap();
+        let mut text_file = NamedTempFile::new().unwrap();
+        write!(text_file, "bar foo").unwrap();
+        let binary_path = binary_file.path().to_str().unwrap();
+        let text_path = text_file.path().to_str().unwrap();
+
+        let (out, err) = stream_with_errors(vec![
+            "test",
+            "--find_string",
+            "foo",
+            "--all",
+            "--files",
+            binary_path,
+            text_path,
+        ]);
+        assert_eq!(out, format!("{}:foo\n", text_path));
+        assert_eq!(err, "Skipped 1 binary file\n");
+    }
+
+    #[test]
+    fn test_reports_binary_sources_that_match() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"foo\0foo").unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let out = stream(vec![
+            "test",
+            "--find_string",
+            "foo",
+            "--all",
+            "--binary",
+            "report",
+            "--file",
+            path,
+        ]);
+        assert_eq!(out, format!("{}: binary file matches\n", path));
+    }
+
+    #[test]
+    fn test_reports_unreadable_sources_and_continues() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "foo").unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let (out, err) = stream_with_errors(vec![
+            "test",
+            "--find_string",
+            "foo",
+            "--files",
+            "/nonexistent/seer-source",
+            path,
+        ]);
+        assert_eq!(out, format!("{}:foo\n", path));
+        assert!(err.starts_with("Failed to read source: '/nonexistent/seer-source'."));
+    }
+
+    #[test]
+    fn test_sniffs_a_pausing_pipe_from_what_it_has_given() {
+        // The first read ends inside `é`, and the next one would block
+        struct PausingReader(Vec<&'static [u8]>);
+
+        impl Read for PausingReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let chunk = self.0.remove(0);
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Ok(chunk.len())
+            }
+        }
+
+        let (_, binary) = open_text(PausingReader(vec![b"caf\xc3"]), None).unwrap();
+        assert!(!binary);
+
+        let (_, binary) = open_text(PausingReader(vec![b"caf\xff"]), None).unwrap();
+        assert!(binary);
+    }
+}
@@ -0,0 +1,302 @@
+use super::handler::Handler;
+use super::print_handler::exit_on_write_error;
+use crate::dto::dto::DTO;
+use crate::helpers::edits::apply_edits;
+use crate::helpers::lines::with_line_ending;
+use crate::strategies::enums::eol_mode::EolMode;
+use crate::strategy_factories::edit_strategy_factory::EditStrategyFactory;
+use crate::strategy_factories::source_strategy_factory::SourceStrategyFactory;
+use std::io::{self, Write};
+
+/// Writes out every source with its edits applied.
+///
+/// With `--edit_in_place`, each edited source is written back in the encoding it was read in,
+/// keeping its BOM. Sources whose text does not change are left untouched. Otherwise the edited
+/// text of every source is printed.
+///
+/// A source that looks binary, or had invalid bytes replaced when it was decoded, is never written back,
+/// since its other bytes would be lost. It is reported to `err` instead, as is a source that fails to be written,
+/// like an archive member or a compressed file, which are read-only. Each such source is recorded on the DTO.
+///
+/// With `--eol=lf` or `--eol=crlf`, every line ending of the written text is converted.
+/// By default, the bytes outside of the edits are kept as they were.
+pub struct WriteHandler<W: Write, E: Write> {
+    pipe: bool,
+    out: W,
+    err: E,
+}
+
+impl<W: Write, E: Write> WriteHandler<W, E> {
+    // Constructor for WriteHandler
+    pub fn new(pipe: bool, out: W, err: E) -> Self {
+        Self { pipe, out, err }
+    }
+}
+
+impl<'a, W: Write, E: Write> Handler<'a> for WriteHandler<W, E> {
+    fn handle(&mut self, dto: &mut DTO<'a>) {
+        let inputs = dto
+            .get_inputs()
+            .clone()
+            .expect("The inputs must be set before writing");
+        let edit_in_place = inputs.get_flag("edit_in_place");
+        let source_strategy = SourceStrategyFactory::make(&inputs, self.pipe);
+        let line_ending = match EditStrategyFactory::make_eol_mode(&inputs) {
+            EolMode::Preserve => None,
+            EolMode::Lf => Some("\n"),
+            EolMode::Crlf => Some("\r\n"),
+        };
+
+        let mut unwritten_sources = Vec::new();
+
+        for source in dto.get_sources() {
+            let edits = source
+                .get_edit_indices()
+                .iter()
+                .map(|edit_index| &dto.get_edits()[*edit_index]);
+            let mut edited = apply_edits(source.get_text(), edits);
+            if let Some(line_ending) = line_ending {
+                edited = with_line_ending(&edited, line_ending);
+            }
+
+            if !edit_in_place {
+                self.out
+                    .write_all(edited.as_bytes())
+                    .unwrap_or_else(|e| exit_on_write_error(e));
+                continue;
+            }
+
+            if edited == source.get_text() {
+                continue;
+            }
+
+            let result = if source.is_binary() {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "It looks binary, so it is not written back.",
+                ))
+            } else if source.is_lossy() {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "It has bytes that are invalid in its encoding, so it is not written back.",
+                ))
+            } else {
+                source
+                    .get_encoding()
+                    .encode(&edited)
+                    .and_then(|bytes| source_strategy.write(source.get_name(), &bytes))
+            };
+
+            if let Err(e) = result {
+                writeln!(
+                    self.err,
+                    "Failed to edit source: '{}'. {}",
+                    source.get_name(),
+                    e
+                )
+                .expect("Failed to print error");
+                unwritten_sources.push(source.get_name().to_string());
+            }
+        }
+
+        for name in unwritten_sources {
+            dto.add_unwritten_source(&name);
+        }
+
+        self.out.flush().unwrap_or_else(|e| exit_on_write_error(e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use clap::{Arg, ArgAction, Command};
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    fn get_dto(args: Vec<&str>) -> DTO<'static> {
+        let inputs = Command::new("test")
+            .arg(Arg::new("dir").long("dir"))
+            .arg(Arg::new("files-from").long("files-from"))
+            .arg(Arg::new("file").long("file"))
+            .arg(Arg::new("files").long("files"))
+            .arg(Arg::new("archive").long("archive"))
+            .arg(Arg::new("text").long("text"))
+            .arg(Arg::new("null").long("null").action(ArgAction::SetTrue))
+            .arg(Arg::new("stdin").long("stdin").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("git-changed")
+                    .long("git-changed")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("git-staged")
+                    .long("git-staged")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("git-diff").long("git-diff"))
+            .arg(Arg::new("at-rev").long("at-rev"))
+            .arg(
+                Arg::new("git-hunks")
+                    .long("git-hunks")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("mmap").long("mmap").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("no-mmap")
+                    .long("no-mmap")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("search-zip")
+                    .long("search-zip")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("edit_in_place")
+                    .long("edit_in_place")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("eol").long("eol"))
+            .try_get_matches_from(args)
+            .unwrap();
+        let mut dto = DTO::new("test", "1.0", "test", "test");
+        dto.set_inputs(inputs);
+        dto
+    }
+
+    #[test]
+    fn test_prints_edited_sources() {
+        let mut dto = get_dto(vec!["test", "--text", "foo bar"]);
+        dto.add_source("text", "foo bar");
+        dto.add_edit(Edit::replacing(0, 3, String::from("baz")), 0);
+
+        let mut out = Vec::new();
+        WriteHandler::new(false, &mut out, io::sink()).handle(&mut dto);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "baz bar");
+    }
+
+    #[test]
+    fn test_edits_in_place_in_the_original_encoding() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let file_path = temp_file.path().to_str().unwrap();
+        fs::write(file_path, b"\xFF\xFEf\0o\0o\0").unwrap();
+
+        let mut dto = get_dto(vec!["test", "--file", file_path, "--edit_in_place"]);
+        let text = SourceStrategyFactory::make(dto.get_inputs().as_ref().unwrap(), false)
+            .load(file_path, None)
+            .unwrap();
+        dto.add_source_text(file_path, text);
+        dto.add_edit(Edit::replacing(0, 3, String::from("bär")), 0);
+
+        let mut out = Vec::new();
+        WriteHandler::new(false, &mut out, io::sink()).handle(&mut dto);
+
+        assert!(out.is_empty());
+        assert_eq!(fs::read(file_path).unwrap(), b"\xFF\xFEb\0\xE4\0r\0");
+    }
+
+    #[test]
+    fn test_edits_in_place_a_file_given_twice_without_mapping_it() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let file_path = temp_file.path().to_str().unwrap();
+        fs::write(file_path, "foo bar").unwrap();
+
+        let mut dto = get_dto(vec![
+            "test",
+            "--files",
+            file_path,
+            "--mmap",
+            "--edit_in_place",
+        ]);
+        let source_strategy =
+            SourceStrategyFactory::make(dto.get_inputs().as_ref().unwrap(), false);
+        for edit in [
+            Edit::replacing(0, 3, String::from("baz")),
+            Edit::replacing(4, 3, String::from("qux")),
+        ] {
+            let text = source_strategy.load(file_path, None).unwrap();
+            assert!(!text.is_mapped());
+            dto.add_source_text(file_path, text);
+            dto.add_edit(edit, dto.get_source_count() - 1);
+        }
+
+        let mut out = Vec::new();
+        WriteHandler::new(false, &mut out, io::sink()).handle(&mut dto);
+
+        // Each source keeps the text it was read with, so the last write wins
+        assert_eq!(fs::read_to_string(file_path).unwrap(), "foo qux");
+    }
+
+    #[test]
+    fn test_does_not_edit_in_place_sources_with_invalid_bytes() {
+        let cases: [&[u8]; 3] = [
+            b"foo \xFF\xFE bar",
+            b"foo \0 bar",
+            b"\xFF\xFEf\0o\0o\0\0\xD8",
+        ];
+
+        for bytes in cases {
+            let temp_file = NamedTempFile::new().unwrap();
+            let file_path = temp_file.path().to_str().unwrap();
+            fs::write(file_path, bytes).unwrap();
+
+            let mut dto = get_dto(vec!["test", "--file", file_path, "--edit_in_place"]);
+            let text = SourceStrategyFactory::make(dto.get_inputs().as_ref().unwrap(), false)
+                .load(file_path, None)
+                .unwrap();
+            dto.add_source_text(file_path, text);
+            dto.add_edit(Edit::replacing(0, 3, String::from("baz")), 0);
+
+            let mut err = Vec::new();
+            WriteHandler::new(false, io::sink(), &mut err).handle(&mut dto);
+
+            assert_eq!(fs::read(file_path).unwrap(), bytes);
+            assert_eq!(dto.get_unwritten_sources(), &vec![file_path.to_string()]);
+            assert!(String::from_utf8(err)
+                .unwrap()
+                .starts_with(&format!("Failed to edit source: '{}'.", file_path)));
+        }
+    }
+
+    #[test]
+    fn test_records_sources_that_cannot_be_written() {
+        let mut dto = get_dto(vec!["test", "--text", "foo bar", "--edit_in_place"]);
+        dto.add_source("text", "foo bar");
+        dto.add_edit(Edit::replacing(0, 3, String::from("baz")), 0);
+
+        let mut err = Vec::new();
+        WriteHandler::new(false, io::sink(), &mut err).handle(&mut dto);
+
+        assert!(String::from_utf8(err)
+            .unwrap()
+            .starts_with("Failed to edit source: 'text'."));
+        assert_eq!(dto.get_unwritten_sources(), &vec![String::from("text")]);
+    }
+
+    #[test]
+    fn test_converts_line_endings() {
+        let mut dto = get_dto(vec!["test", "--text", "foo\nbar\r\n", "--eol", "crlf"]);
+        dto.add_source("text", "foo\nbar\r\n");
+        dto.add_edit(Edit::replacing(0, 3, String::from("baz\n")), 0);
+
+        let mut out = Vec::new();
+        WriteHandler::new(false, &mut out, io::sink()).handle(&mut dto);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "baz\r\n\r\nbar\r\n");
+    }
+
+    #[test]
+    fn test_keeps_line_endings_outside_of_edits() {
+        let mut dto = get_dto(vec!["test", "--text", "foo\nbar\r\n"]);
+        dto.add_source("text", "foo\nbar\r\n");
+        dto.add_edit(Edit::replacing(4, 3, String::from("baz")), 0);
+
+        let mut out = Vec::new();
+        WriteHandler::new(false, &mut out, io::sink()).handle(&mut dto);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "foo\nbaz\r\n");
+    }
+}
@@ -0,0 +1,376 @@
+use crate::helpers::compression::{decompress, detect_compression};
+use crate::helpers::file_sys::open_file;
+use crate::strategies::enums::archive_format::ArchiveFormat;
+use crate::strategies::enums::compression::Compression;
+use std::fs::File;
+use std::io::{self, Read};
+use zip::ZipArchive;
+
+/// Separates the path of an archive from the path of a member inside it in source names.
+pub const MEMBER_SEPARATOR: &str = "!/";
+
+/// Returns the format of an archive from its extension, or `None` if it is not an archive.
+///
+/// A tar archive may be compressed in any format that `--search-zip` reads.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::archive::detect_archive_format;
+/// use seer::strategies::enums::archive_format::ArchiveFormat;
+///
+/// assert_eq!(detect_archive_format("bundle.tar.gz"), Some(ArchiveFormat::Tar));
+/// assert_eq!(detect_archive_format("bundle.zip"), Some(ArchiveFormat::Zip));
+/// assert_eq!(detect_archive_format("bundle.gz"), None);
+/// ```
+pub fn detect_archive_format(path: &str) -> Option<ArchiveFormat> {
+    if path.ends_with(".zip") {
+        return Some(ArchiveFormat::Zip);
+    }
+
+    let (stem, _) = get_tar_compression(path);
+    if stem.ends_with(".tar") || path.ends_with(".tgz") {
+        return Some(ArchiveFormat::Tar);
+    }
+
+    None
+}
+
+/// A file in an archive.
+///
+/// A file is found again by its position rather than its path, since an archive can hold the same path twice.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ArchiveMember {
+    pub path: String,
+    // The index of the entry in a zip archive, or the offset of its data in an unpacked tar archive
+    position: u64,
+    size: u64,
+}
+
+/// Lists every file in an archive, in the order the archive lists them, without reading their contents.
+///
+/// Directories, links, and other entries that are not regular files are left out.
+///
+/// # Arguments
+///
+/// * `archive_path` - A string slice that holds the path of the archive.
+///
+/// # Errors
+///
+/// This function will return an error if the archive cannot be read or has an unknown format.
+pub fn list_archive(archive_path: &str) -> io::Result<Vec<ArchiveMember>> {
+    let format = get_archive_format(archive_path)?;
+    match format {
+        ArchiveFormat::Tar => list_tar(archive_path),
+        ArchiveFormat::Zip => list_zip(archive_path),
+    }
+    .map_err(|e| add_archive_path(archive_path, e))
+}
+
+/// Reads the files of an archive one at a time.
+///
+/// A zip archive is opened once and its files are read by index. A tar archive is unpacked as a stream,
+/// so reading its files in order unpacks it once, while going back to an earlier file starts over.
+pub struct ArchiveReader {
+    archive_path: String,
+    archive: OpenArchive,
+}
+
+enum OpenArchive {
+    Tar {
+        reader: Box<dyn Read>,
+        position: u64,
+    },
+    Zip(ZipArchive<File>),
+}
+
+impl ArchiveReader {
+    /// Opens an archive to read its files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive cannot be opened or has an unknown format.
+    pub fn new(archive_path: &str) -> io::Result<Self> {
+        let archive = match get_archive_format(archive_path)? {
+            ArchiveFormat::Tar => OpenArchive::Tar {
+                reader: open_tar(archive_path)?,
+                position: 0,
+            },
+            ArchiveFormat::Zip => OpenArchive::Zip(
+                ZipArchive::new(open_file(archive_path)?)
+                    .map_err(|e| add_archive_path(archive_path, e.into()))?,
+            ),
+        };
+
+        Ok(Self {
+            archive_path: String::from(archive_path),
+            archive,
+        })
+    }
+
+    /// Reads the contents of a file listed by `list_archive`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive cannot be read or no longer holds the file.
+    pub fn read_member(&mut self, member: &ArchiveMember) -> io::Result<Vec<u8>> {
+        let archive_path = &self.archive_path;
+        match &mut self.archive {
+            OpenArchive::Tar { reader, position } => {
+                if member.position < *position {
+                    *reader = open_tar(archive_path)?;
+                    *position = 0;
+                }
+
+                let bytes = skip(reader, member.position - *position)
+                    .and_then(|()| read_exactly(reader, member.size));
+                // After a failed read, where the stream is at is unknown, so the next read starts over
+                *position = match bytes {
+                    Ok(_) => member.position + member.size,
+                    Err(_) => u64::MAX,
+                };
+                bytes
+            }
+            OpenArchive::Zip(archive) => archive
+                .by_index(member.position as usize)
+                .map_err(io::Error::from)
+                .and_then(|file| read_exactly(file, member.size)),
+        }
+        .map_err(|e| add_archive_path(archive_path, e))
+    }
+}
+
+// Skips the given number of bytes, failing if the reader ends first.
+fn skip<R: Read>(reader: &mut R, count: u64) -> io::Result<()> {
+    if io::copy(&mut reader.take(count), &mut io::sink())? < count {
+        return Err(unexpected_end());
+    }
+    Ok(())
+}
+
+// Reads the given number of bytes, failing if the reader ends first.
+fn read_exactly<R: Read>(reader: R, size: u64) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.take(size).read_to_end(&mut bytes)?;
+    if (bytes.len() as u64) < size {
+        return Err(unexpected_end());
+    }
+    Ok(bytes)
+}
+
+fn unexpected_end() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "The archive ended before the file did",
+    )
+}
+
+fn get_archive_format(archive_path: &str) -> io::Result<ArchiveFormat> {
+    detect_archive_format(archive_path).ok_or_else(|| {
+        add_archive_path(
+            archive_path,
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Archives must be .tar, .tar.gz, .tgz, or .zip files",
+            ),
+        )
+    })
+}
+
+fn add_archive_path(archive_path: &str, e: io::Error) -> io::Error {
+    io::Error::new(
+        e.kind(),
+        format!("Failed to read archive: '{}'. {}", archive_path, e),
+    )
+}
+
+// Returns the path without its compression extension, and the compression of the tar archive.
+fn get_tar_compression(path: &str) -> (&str, Option<Compression>) {
+    if let Some(stem) = path.strip_suffix(".tgz") {
+        return (stem, Some(Compression::Gzip));
+    }
+
+    match detect_compression(path) {
+        Some(compression) => (
+            &path[..path.rfind('.').unwrap_or(path.len())],
+            Some(compression),
+        ),
+        None => (path, None),
+    }
+}
+
+fn open_tar(archive_path: &str) -> io::Result<Box<dyn Read>> {
+    let file = open_file(archive_path)?;
+    match get_tar_compression(archive_path).1 {
+        Some(compression) => decompress(file, compression),
+        None => Ok(Box::new(file)),
+    }
+}
+
+fn list_tar(archive_path: &str) -> io::Result<Vec<ArchiveMember>> {
+    let mut members = Vec::new();
+    // The data of each entry is skipped over rather than read, as it is not needed yet
+    for entry in tar::Archive::new(open_tar(archive_path)?).entries()? {
+        let entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        members.push(ArchiveMember {
+            path: entry.path()?.to_string_lossy().into_owned(),
+            position: entry.raw_file_position(),
+            size: entry.size(),
+        });
+    }
+
+    Ok(members)
+}
+
+fn list_zip(archive_path: &str) -> io::Result<Vec<ArchiveMember>> {
+    let mut archive = ZipArchive::new(open_file(archive_path)?)?;
+
+    let mut members = Vec::new();
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index)?;
+        if !file.is_file() {
+            continue;
+        }
+
+        members.push(ArchiveMember {
+            path: file.name().to_string(),
+            position: index as u64,
+            size: file.size(),
+        });
+    }
+
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn write_tar<W: Write>(writer: W) -> W {
+        let mut builder = tar::Builder::new(writer);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(6);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header.clone(), "config/.env", &b"SECRET"[..])
+            .unwrap();
+        let mut dir_header = tar::Header::new_gnu();
+        dir_header.set_entry_type(tar::EntryType::Directory);
+        dir_header.set_size(0);
+        dir_header.set_cksum();
+        builder
+            .append_data(&mut dir_header, "config/", io::empty())
+            .unwrap();
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_detects_archive_format_from_the_extension() {
+        assert_eq!(detect_archive_format("a.tar"), Some(ArchiveFormat::Tar));
+        assert_eq!(detect_archive_format("a.tar.gz"), Some(ArchiveFormat::Tar));
+        assert_eq!(detect_archive_format("a.tgz"), Some(ArchiveFormat::Tar));
+        assert_eq!(detect_archive_format("a.tar.zst"), Some(ArchiveFormat::Tar));
+        assert_eq!(detect_archive_format("a.zip"), Some(ArchiveFormat::Zip));
+        assert_eq!(detect_archive_format("a.log.gz"), None);
+        assert_eq!(detect_archive_format("a.txt"), None);
+    }
+
+    // Reads every file in an archive with its path.
+    fn read_archive(archive_path: &str) -> io::Result<Vec<(String, Vec<u8>)>> {
+        let mut reader = ArchiveReader::new(archive_path)?;
+        list_archive(archive_path)?
+            .into_iter()
+            .map(|member| Ok((member.path.clone(), reader.read_member(&member)?)))
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_tar_files() {
+        let mut temp_file = NamedTempFile::with_suffix(".tar").unwrap();
+        write_tar(&mut temp_file);
+
+        let members = read_archive(temp_file.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            members,
+            vec![(String::from("config/.env"), b"SECRET".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_reads_tar_files_in_any_order() {
+        let temp_file = NamedTempFile::with_suffix(".tar.gz").unwrap();
+        let encoder = flate2::write::GzEncoder::new(
+            temp_file.reopen().unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (path, contents) in [("a.txt", "first"), ("b.txt", "second")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, contents.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+        let archive_path = temp_file.path().to_str().unwrap();
+
+        let members = list_archive(archive_path).unwrap();
+        let mut reader = ArchiveReader::new(archive_path).unwrap();
+        assert_eq!(reader.read_member(&members[1]).unwrap(), b"second");
+        assert_eq!(reader.read_member(&members[0]).unwrap(), b"first");
+        assert_eq!(reader.read_member(&members[1]).unwrap(), b"second");
+    }
+
+    #[test]
+    fn test_reads_compressed_tar_files() {
+        let temp_file = NamedTempFile::with_suffix(".tar.gz").unwrap();
+        let encoder = flate2::write::GzEncoder::new(
+            temp_file.reopen().unwrap(),
+            flate2::Compression::default(),
+        );
+        write_tar(encoder).finish().unwrap();
+
+        let members = read_archive(temp_file.path().to_str().unwrap()).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].1, b"SECRET");
+    }
+
+    #[test]
+    fn test_reads_zip_files() {
+        let temp_file = NamedTempFile::with_suffix(".zip").unwrap();
+        let mut writer = zip::ZipWriter::new(temp_file.reopen().unwrap());
+        writer
+            .add_directory("config/", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer
+            .start_file("config/.env", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"SECRET").unwrap();
+        writer.finish().unwrap();
+
+        let members = read_archive(temp_file.path().to_str().unwrap()).unwrap();
+        assert_eq!(
+            members,
+            vec![(String::from("config/.env"), b"SECRET".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_returns_error_with_unknown_format() {
+        let temp_file = NamedTempFile::with_suffix(".txt").unwrap();
+        assert_eq!(
+            list_archive(temp_file.path().to_str().unwrap())
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,66 @@
+/// The number of leading bytes checked to decide whether a source is binary.
+pub const BINARY_WINDOW: usize = 8 * 1024;
+
+/// Returns true if the bytes look like a binary file rather than text.
+///
+/// Like grep, only the first `BINARY_WINDOW` bytes are checked. They are binary if they
+/// contain a NUL byte or are not valid UTF-8. A character cut off by the end of the window is allowed.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::binary::is_binary;
+///
+/// assert!(!is_binary("plain text".as_bytes()));
+/// assert!(is_binary(b"\x7fELF\x02\x01\x01\x00"));
+/// assert!(is_binary(b"caf\xe9"));
+/// ```
+pub fn is_binary(bytes: &[u8]) -> bool {
+    let window = &bytes[..bytes.len().min(BINARY_WINDOW)];
+
+    if window.contains(&0) {
+        return true;
+    }
+
+    match std::str::from_utf8(window) {
+        Ok(_) => false,
+        // Only an incomplete character at the end of a window that doesn't hold the whole source is allowed
+        Err(e) => e.error_len().is_some() || window.len() == bytes.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_is_not_binary() {
+        assert!(!is_binary("straße\r\nline two\n".as_bytes()));
+        assert!(!is_binary(b""));
+    }
+
+    #[test]
+    fn test_nul_byte_is_binary() {
+        assert!(is_binary(b"text\0more text"));
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_binary() {
+        assert!(is_binary(b"text \xff\xfe more text"));
+    }
+
+    #[test]
+    fn test_character_cut_off_by_the_window_is_not_binary() {
+        let mut bytes = vec![b'a'; BINARY_WINDOW - 1];
+        bytes.extend_from_slice("é".as_bytes());
+        assert!(!is_binary(&bytes));
+        assert!(is_binary(&bytes[..BINARY_WINDOW]));
+    }
+
+    #[test]
+    fn test_only_the_window_is_checked() {
+        let mut bytes = vec![b'a'; BINARY_WINDOW];
+        bytes.push(0);
+        assert!(!is_binary(&bytes));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::strategies::enums::compression::Compression;
+use bzip2::read::MultiBzDecoder;
+use flate2::read::MultiGzDecoder;
+use std::io::{self, Read};
+use std::path::Path;
+use xz2::read::XzDecoder;
+
+/// Returns the compression format of a file from its extension, or `None` if it is not compressed.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::compression::detect_compression;
+/// use seer::strategies::enums::compression::Compression;
+///
+/// assert_eq!(detect_compression("app.log.gz"), Some(Compression::Gzip));
+/// assert_eq!(detect_compression("app.log"), None);
+/// ```
+pub fn detect_compression(path: &str) -> Option<Compression> {
+    match Path::new(path).extension()?.to_str()? {
+        "gz" => Some(Compression::Gzip),
+        "zst" => Some(Compression::Zstd),
+        "xz" => Some(Compression::Xz),
+        "bz2" => Some(Compression::Bzip2),
+        _ => None,
+    }
+}
+
+/// Wraps a reader of compressed bytes in a reader of the decompressed bytes.
+///
+/// Files made of several concatenated streams, as `cat a.gz b.gz` gives, are read to the end.
+///
+/// # Errors
+///
+/// Returns an error if the decoder cannot be set up. Corrupt data is only reported while reading.
+pub fn decompress<R: Read + 'static>(
+    reader: R,
+    compression: Compression,
+) -> io::Result<Box<dyn Read>> {
+    Ok(match compression {
+        Compression::Gzip => Box::new(MultiGzDecoder::new(reader)),
+        Compression::Zstd => Box::new(zstd::Decoder::new(reader)?),
+        Compression::Xz => Box::new(XzDecoder::new_multi_decoder(reader)),
+        Compression::Bzip2 => Box::new(MultiBzDecoder::new(reader)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn compress(compression: Compression, text: &str) -> Vec<u8> {
+        match compression {
+            Compression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(text.as_bytes()).unwrap();
+                encoder.finish().unwrap()
+            }
+            Compression::Zstd => zstd::encode_all(text.as_bytes(), 0).unwrap(),
+            Compression::Xz => {
+                let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+                encoder.write_all(text.as_bytes()).unwrap();
+                encoder.finish().unwrap()
+            }
+            Compression::Bzip2 => {
+                let mut encoder =
+                    bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+                encoder.write_all(text.as_bytes()).unwrap();
+                encoder.finish().unwrap()
+            }
+        }
+    }
+
+    #[test]
+    fn test_detects_compression_from_the_extension() {
+        assert_eq!(detect_compression("a/b.log.gz"), Some(Compression::Gzip));
+        assert_eq!(detect_compression("b.zst"), Some(Compression::Zstd));
+        assert_eq!(detect_compression("b.tar.xz"), Some(Compression::Xz));
+        assert_eq!(detect_compression("b.bz2"), Some(Compression::Bzip2));
+        assert_eq!(detect_compression("b.gzip.txt"), None);
+        assert_eq!(detect_compression("gz"), None);
+    }
+
+    #[test]
+    fn test_decompresses_every_format() {
+        for compression in [
+            Compression::Gzip,
+            Compression::Zstd,
+            Compression::Xz,
+            Compression::Bzip2,
+        ] {
+            let bytes = compress(compression, "rotated log");
+            let mut text = String::new();
+            decompress(io::Cursor::new(bytes), compression)
+                .unwrap()
+                .read_to_string(&mut text)
+                .unwrap();
+            assert_eq!(text, "rotated log", "{:?}", compression);
+        }
+    }
+
+    #[test]
+    fn test_reads_concatenated_streams() {
+        let mut bytes = compress(Compression::Gzip, "first ");
+        bytes.extend(compress(Compression::Gzip, "second"));
+
+        let mut text = String::new();
+        decompress(io::Cursor::new(bytes), Compression::Gzip)
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "first second");
+    }
+}
@@ -0,0 +1,402 @@
+use super::markup::{parse_markup, to_spans, Element};
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+
+/// A CSS selector, like `div.note > p` or `a[rel=nofollow]::attr(href)`,
+/// that selects element text or attribute values in an HTML or XML document.
+///
+/// Each selector is a list of compound selectors joined by a space, for a descendant, or `>`, for a child.
+/// A compound selector is a type like `p` or `*`, followed by any of `#id`, `.class`, `[name]`, and `[name=value]`,
+/// where the value may be quoted. Type names match in any case.
+///
+/// The text of each selected element is selected, or with a trailing `::attr(name)`, the value of that attribute.
+/// Several selectors may be given, separated by `,`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::css_selector::CssSelector;
+///
+/// let text = r#"<ul class="links"><li><a href="/a">A</a></li><li><a href="/b">B</a></li></ul>"#;
+/// let css_selector = CssSelector::parse("ul.links > li a::attr(href)").unwrap();
+/// let values: Vec<&str> = css_selector
+///     .select(text)
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+///
+/// assert_eq!(values, vec!["/a", "/b"]);
+/// ```
+#[derive(Debug)]
+pub struct CssSelector {
+    selectors: Vec<Selector>,
+}
+
+// One selector of a comma separated list.
+#[derive(Debug, PartialEq)]
+struct Selector {
+    compounds: Vec<Compound>,
+    // The combinator before each compound after the first
+    combinators: Vec<Combinator>,
+    // The attribute whose value is selected, or `None` for the text
+    attribute: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+// The conditions one element must meet.
+#[derive(Debug, PartialEq)]
+struct Compound {
+    // The type to match, or `None` for `*` or no type
+    name: Option<String>,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Condition {
+    Id(String),
+    Class(String),
+    HasAttribute(String),
+    AttributeEquals(String, String),
+}
+
+impl CssSelector {
+    /// Parses a CSS selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the selector is not in the supported subset of CSS.
+    pub fn parse(selector: &str) -> io::Result<Self> {
+        let invalid = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid CSS selector '{}'. {}", selector, message),
+            )
+        };
+        let mut selectors = Vec::new();
+        let mut rest = selector.trim();
+
+        loop {
+            let (parsed, after) = parse_selector(rest).map_err(invalid)?;
+            selectors.push(parsed);
+            match after.strip_prefix(',') {
+                Some(next) => rest = next.trim_start(),
+                None if after.is_empty() => return Ok(Self { selectors }),
+                None => {
+                    return Err(invalid(
+                        "Expected ' ', '>', ',' or '::attr(name)' after a selector",
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Selects the element text or attribute values that match in the given HTML or XML text.
+    ///
+    /// The text of an element is its content without the whitespace around it, including any child elements.
+    /// An attribute value is selected without its quotes, and attributes without a value are left out.
+    /// Values within another selected value are left out, so the spans never overlap.
+    ///
+    /// # Returns
+    /// The start and end byte positions of each selected value, in the order they appear,
+    /// and how the value is escaped there.
+    pub fn select(&self, text: &str) -> Vec<(usize, usize, Escaping)> {
+        let root = parse_markup(text);
+        let mut spans = Vec::new();
+        for child in root.get_children() {
+            self.select_within(child, &mut Vec::new(), text, &mut spans);
+        }
+        to_spans(spans)
+    }
+
+    // Adds the spans selected from the element and every element within it.
+    fn select_within<'a>(
+        &self,
+        element: &'a Element,
+        ancestors: &mut Vec<&'a Element>,
+        text: &str,
+        spans: &mut Vec<(usize, usize, Escaping)>,
+    ) {
+        for selector in &self.selectors {
+            if !selector.matches(selector.compounds.len() - 1, element, ancestors) {
+                continue;
+            }
+            match &selector.attribute {
+                Some(name) => spans.extend(element.get_attribute(name).and_then(|attribute| {
+                    let (start, end) = attribute.get_span()?;
+                    Some((start, end, attribute.get_escaping()))
+                })),
+                None => spans.push(element.get_text_span(text)),
+            }
+        }
+
+        ancestors.push(element);
+        for child in element.get_children() {
+            self.select_within(child, ancestors, text, spans);
+        }
+        ancestors.pop();
+    }
+}
+
+impl Selector {
+    // Returns true if the element matches the compound at the index, with its ancestors matching those before it.
+    fn matches(&self, index: usize, element: &Element, ancestors: &[&Element]) -> bool {
+        if !self.compounds[index].matches(element) {
+            return false;
+        } else if index == 0 {
+            return true;
+        }
+
+        match self.combinators[index - 1] {
+            Combinator::Child => ancestors
+                .split_last()
+                .is_some_and(|(parent, rest)| self.matches(index - 1, parent, rest)),
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|i| self.matches(index - 1, ancestors[i], &ancestors[..i])),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, element: &Element) -> bool {
+        let attribute_value = |name: &str| element.get_attribute(name).map(|a| a.get_value());
+
+        self.name
+            .as_ref()
+            .is_none_or(|name| element.get_name().eq_ignore_ascii_case(name))
+            && self.conditions.iter().all(|condition| match condition {
+                Condition::Id(id) => attribute_value("id") == Some(id.as_str()),
+                Condition::Class(class) => attribute_value("class")
+                    .is_some_and(|classes| classes.split_whitespace().any(|c| c == class)),
+                Condition::HasAttribute(name) => attribute_value(name).is_some(),
+                Condition::AttributeEquals(name, value) => {
+                    attribute_value(name) == Some(value.as_str())
+                }
+            })
+    }
+}
+
+// Parses one selector, returning it and the rest of the text after it.
+fn parse_selector(text: &str) -> Result<(Selector, &str), &'static str> {
+    let mut selector = Selector {
+        compounds: Vec::new(),
+        combinators: Vec::new(),
+        attribute: None,
+    };
+    let mut rest = text;
+
+    loop {
+        let (compound, after) = parse_compound(rest)?;
+        selector.compounds.push(compound);
+        let trimmed = after.trim_start();
+
+        if let Some(attribute) = trimmed.strip_prefix("::attr(") {
+            let end = attribute
+                .find(')')
+                .ok_or("Expected ')' after the attribute name")?;
+            let name = attribute[..end].trim();
+            if name.is_empty() {
+                return Err("Expected an attribute name in '::attr()'");
+            }
+            selector.attribute = Some(name.to_string());
+            return Ok((selector, attribute[end + 1..].trim_start()));
+        } else if trimmed.is_empty() || trimmed.starts_with(',') {
+            return Ok((selector, trimmed));
+        } else if let Some(after_child) = trimmed.strip_prefix('>') {
+            selector.combinators.push(Combinator::Child);
+            rest = after_child.trim_start();
+        } else if trimmed.len() < after.len() {
+            selector.combinators.push(Combinator::Descendant);
+            rest = trimmed;
+        } else {
+            return Err("Expected ' ', '>', ',' or '::attr(name)' after a selector");
+        }
+    }
+}
+
+// Parses one compound selector, returning it and the rest of the text after it.
+fn parse_compound(text: &str) -> Result<(Compound, &str), &'static str> {
+    let mut compound = Compound {
+        name: None,
+        conditions: Vec::new(),
+    };
+    let mut rest = text;
+
+    if let Some(after_star) = rest.strip_prefix('*') {
+        rest = after_star;
+    } else if let Some((name, after_name)) = split_name(rest) {
+        compound.name = Some(name.to_string());
+        rest = after_name;
+    }
+
+    loop {
+        if let Some(after_hash) = rest.strip_prefix('#') {
+            let (id, after_id) = split_name(after_hash).ok_or("Expected a name after '#'")?;
+            compound.conditions.push(Condition::Id(id.to_string()));
+            rest = after_id;
+        } else if let Some(after_dot) = rest.strip_prefix('.') {
+            let (class, after_class) = split_name(after_dot).ok_or("Expected a name after '.'")?;
+            compound
+                .conditions
+                .push(Condition::Class(class.to_string()));
+            rest = after_class;
+        } else if let Some(bracketed) = rest.strip_prefix('[') {
+            let (condition, after_bracket) = parse_attribute_condition(bracketed)
+                .ok_or("Expected '[name]' or '[name=value]'")?;
+            compound.conditions.push(condition);
+            rest = after_bracket;
+        } else {
+            break;
+        }
+    }
+
+    if rest.len() == text.len() {
+        return Err("Expected a type, '*', '#id', '.class' or '[attribute]'");
+    }
+    Ok((compound, rest))
+}
+
+// Parses the inside of a `[...]` condition, returning it and the rest of the text after the `]`.
+fn parse_attribute_condition(bracketed: &str) -> Option<(Condition, &str)> {
+    let (name, after_name) = split_name(bracketed.trim_start())?;
+    let name = name.to_string();
+    let after_name = after_name.trim_start();
+
+    if let Some(rest) = after_name.strip_prefix(']') {
+        return Some((Condition::HasAttribute(name), rest));
+    }
+
+    let value = after_name.strip_prefix('=')?.trim_start();
+    let (value, after_value) = match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let end = value[1..].find(quote)?;
+            (&value[1..=end], &value[end + 2..])
+        }
+        _ => split_name(value)?,
+    };
+    let rest = after_value.trim_start().strip_prefix(']')?;
+    Some((Condition::AttributeEquals(name, value.to_string()), rest))
+}
+
+// Splits off the name at the start of the text, if it starts with one.
+fn split_name(text: &str) -> Option<(&str, &str)> {
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_')))
+        .unwrap_or(text.len());
+    (end > 0).then(|| text.split_at(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<body>
+  <div id="main" class="note wide">
+    <p>First <a href="/one" rel=nofollow>one</a></p>
+    <section><p>Second</p></section>
+  </div>
+  <P>Third <a href='/three'>three</a></P>
+</body>
+</html>
+"#;
+
+    fn select<'a>(selector: &str, text: &'a str) -> Vec<&'a str> {
+        CssSelector::parse(selector)
+            .unwrap()
+            .select(text)
+            .into_iter()
+            .map(|(start, end, _)| &text[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_selectors() {
+        let css_selector =
+            CssSelector::parse("div#main.note > p [href='/a b']::attr(href), *").unwrap();
+        assert_eq!(
+            css_selector.selectors,
+            vec![
+                Selector {
+                    compounds: vec![
+                        Compound {
+                            name: Some(String::from("div")),
+                            conditions: vec![
+                                Condition::Id(String::from("main")),
+                                Condition::Class(String::from("note")),
+                            ],
+                        },
+                        Compound {
+                            name: Some(String::from("p")),
+                            conditions: vec![],
+                        },
+                        Compound {
+                            name: None,
+                            conditions: vec![Condition::AttributeEquals(
+                                String::from("href"),
+                                String::from("/a b")
+                            )],
+                        },
+                    ],
+                    combinators: vec![Combinator::Child, Combinator::Descendant],
+                    attribute: Some(String::from("href")),
+                },
+                Selector {
+                    compounds: vec![Compound {
+                        name: None,
+                        conditions: vec![],
+                    }],
+                    combinators: vec![],
+                    attribute: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_selectors() {
+        for selector in [
+            "",
+            "p >",
+            "p,",
+            "#",
+            "a:hover",
+            "a[href",
+            "a::attr()",
+            "a + b",
+        ] {
+            let error = CssSelector::parse(selector).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", selector);
+            assert!(error.to_string().starts_with("Invalid CSS selector"));
+        }
+    }
+
+    #[test]
+    fn test_selects_element_text() {
+        assert_eq!(
+            select("div > p", PAGE),
+            vec![r#"First <a href="/one" rel=nofollow>one</a>"#]
+        );
+        assert_eq!(select("#main p", PAGE).len(), 2);
+        assert_eq!(select("div.wide section > p", PAGE), vec!["Second"]);
+        assert_eq!(
+            select("body > p a, section p", PAGE),
+            vec!["Second", "three"]
+        );
+        assert!(select("div.missing p", PAGE).is_empty());
+    }
+
+    #[test]
+    fn test_selects_attribute_values() {
+        assert_eq!(select("a::attr(href)", PAGE), vec!["/one", "/three"]);
+        assert_eq!(select("a[rel=nofollow]::attr(href)", PAGE), vec!["/one"]);
+        assert_eq!(select("div::attr(class)", PAGE), vec!["note wide"]);
+        assert!(select("p::attr(id)", PAGE).is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::strategies::enums::escaping::Escaping;
+use std::borrow::Cow;
+use std::io;
+
+/// A field of a delimited record, like a CSV or TSV row.
+///
+/// A quoted field's value leaves out the quotes around it and has each doubled quote unescaped.
+/// Positions in the value map back to positions in the text, so hits in the value can be edited in place.
+#[derive(Debug, PartialEq)]
+pub struct Field {
+    value: String,
+    // The position in the text where the value starts
+    start: usize,
+    // The positions in the value of the quotes that were doubled in the text
+    unescaped: Vec<usize>,
+    quoted: bool,
+}
+
+impl Field {
+    // Returns the field's value.
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    // Returns whether the field is in quotes.
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the position in the text of the given position in the value.
+    ///
+    /// A position just after an unescaped quote maps to just after both of its quotes in the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seer::helpers::delimited::parse_records;
+    ///
+    /// let records = parse_records("a,\"say \"\"hi\"\"\"", ',');
+    /// let field = &records[0][1];
+    /// assert_eq!(field.get_value(), "say \"hi\"");
+    /// assert_eq!(field.get_position(5), 9);
+    /// ```
+    pub fn get_position(&self, index: usize) -> usize {
+        self.start
+            + index
+            + self
+                .unescaped
+                .iter()
+                .filter(|&&quote| quote < index)
+                .count()
+    }
+}
+
+/// Splits delimited text, like CSV or TSV, into records of fields.
+///
+/// Records end at a `\n` or `\r\n` outside of quotes. A field that starts with `"` is quoted:
+/// it may contain the delimiter, line breaks, and quotes doubled as `""`, and ends at the next lone `"`.
+/// Anything between that quote and the next delimiter is not part of the value.
+/// A final line break does not start an empty last record.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::delimited::parse_records;
+///
+/// let records = parse_records("name,note\r\nweb,\"a, b\"\n", ',');
+/// let values: Vec<Vec<&str>> = records
+///     .iter()
+///     .map(|record| record.iter().map(|field| field.get_value()).collect())
+///     .collect();
+///
+/// assert_eq!(values, vec![vec!["name", "note"], vec!["web", "a, b"]]);
+/// ```
+pub fn parse_records(text: &str, delimiter: char) -> Vec<Vec<Field>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let (field, field_end) = if text[pos..].starts_with('"') {
+            parse_quoted_field(text, pos)
+        } else {
+            let end = text[pos..]
+                .find([delimiter, '\n'])
+                .map_or(text.len(), |found| pos + found);
+            let value_end = if text[pos..end].ends_with('\r') && text[end..].starts_with('\n') {
+                end - 1
+            } else {
+                end
+            };
+            let field = Field {
+                value: text[pos..value_end].to_string(),
+                start: pos,
+                unescaped: Vec::new(),
+                quoted: false,
+            };
+            (field, end)
+        };
+        record.push(field);
+
+        // Skip anything after a closing quote up to the end of the field
+        let end = text[field_end..]
+            .find([delimiter, '\n'])
+            .map_or(text.len(), |found| field_end + found);
+        pos = end;
+        if text[end..].starts_with(delimiter) {
+            pos += delimiter.len_utf8();
+            // A delimiter at the very end leaves an empty last field
+            if pos == text.len() {
+                record.push(Field {
+                    value: String::new(),
+                    start: pos,
+                    unescaped: Vec::new(),
+                    quoted: false,
+                });
+            }
+        } else {
+            records.push(std::mem::take(&mut record));
+            pos += 1;
+        }
+    }
+
+    if !record.is_empty() {
+        records.push(record);
+    }
+    records
+}
+
+// Parses the quoted field starting at `start`, returning it and the position after its closing quote.
+fn parse_quoted_field(text: &str, start: usize) -> (Field, usize) {
+    let mut field = Field {
+        value: String::new(),
+        start: start + 1,
+        unescaped: Vec::new(),
+        quoted: true,
+    };
+    let mut pos = start + 1;
+
+    while let Some(quote) = text[pos..].find('"') {
+        field.value.push_str(&text[pos..pos + quote]);
+        pos += quote + 1;
+        if !text[pos..].starts_with('"') {
+            return (field, pos);
+        }
+        field.unescaped.push(field.value.len());
+        field.value.push('"');
+        pos += 1;
+    }
+
+    // An unclosed quote runs to the end of the text
+    field.value.push_str(&text[pos..]);
+    (field, text.len())
+}
+
+/// Undoes the doubled quotes in the contents of a quoted field.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::delimited::unescape_quoted_field;
+///
+/// assert_eq!(unescape_quoted_field("say \"\"hi\"\""), "say \"hi\"");
+/// ```
+pub fn unescape_quoted_field(raw: &str) -> String {
+    raw.replace("\"\"", "\"")
+}
+
+/// Escapes a value so it can be written where a field, or part of one, with the given escaping was.
+///
+/// Quotes are doubled in a quoted field. A whole field without quotes is put in quotes
+/// if the value holds the delimiter, a quote, or a line break.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error if the value would need quotes but replaces only part of a field
+/// without them, since quotes can only go around a whole field.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::delimited::escape_field;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(escape_field("a,b", Escaping::DelimitedField(',')).unwrap(), "\"a,b\"");
+/// assert_eq!(escape_field("a\tb", Escaping::DelimitedField(',')).unwrap(), "a\tb");
+/// assert_eq!(escape_field("say \"hi\"", Escaping::DelimitedQuoted).unwrap(), "say \"\"hi\"\"");
+/// assert!(escape_field("a,b", Escaping::DelimitedFieldPart(',')).is_err());
+/// ```
+pub fn escape_field(text: &str, escaping: Escaping) -> io::Result<Cow<'_, str>> {
+    match escaping {
+        Escaping::DelimitedQuoted => Ok(Cow::Owned(text.replace('"', "\"\""))),
+        Escaping::DelimitedField(delimiter) if needs_quotes(text, delimiter) => {
+            Ok(Cow::Owned(format!("\"{}\"", text.replace('"', "\"\""))))
+        }
+        Escaping::DelimitedFieldPart(delimiter) if needs_quotes(text, delimiter) => {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "'{}' needs quotes, which can only go around a whole field",
+                    text
+                ),
+            ))
+        }
+        _ => Ok(Cow::Borrowed(text)),
+    }
+}
+
+// Returns whether a field needs quotes to hold the text.
+fn needs_quotes(text: &str, delimiter: char) -> bool {
+    text.contains([delimiter, '"', '\n', '\r'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_values(text: &str, delimiter: char) -> Vec<Vec<String>> {
+        parse_records(text, delimiter)
+            .iter()
+            .map(|record| {
+                record
+                    .iter()
+                    .map(|field| field.get_value().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_splits_records_and_fields() {
+        assert_eq!(
+            get_values("a,b,c\r\n1,,3\n", ','),
+            vec![vec!["a", "b", "c"], vec!["1", "", "3"]]
+        );
+        assert_eq!(get_values("a,b,", ','), vec![vec!["a", "b", ""]]);
+        assert_eq!(
+            get_values("a\n\nb", ','),
+            vec![vec!["a"], vec![""], vec!["b"]]
+        );
+        assert!(get_values("", ',').is_empty());
+    }
+
+    #[test]
+    fn test_handles_quoted_fields() {
+        assert_eq!(
+            get_values("\"a,b\",\"line\r\nbreak\",\"say \"\"hi\"\"\"\nx", ','),
+            vec![vec!["a,b", "line\r\nbreak", "say \"hi\""], vec!["x"]]
+        );
+        assert_eq!(get_values("\"a\"junk,b", ','), vec![vec!["a", "b"]]);
+        assert_eq!(get_values("\"open,end", ','), vec![vec!["open,end"]]);
+    }
+
+    #[test]
+    fn test_splits_tab_separated_values() {
+        assert_eq!(
+            get_values("a\tb,c\n\"d\te\"\tf", '\t'),
+            vec![vec!["a", "b,c"], vec!["d\te", "f"]]
+        );
+    }
+
+    #[test]
+    fn test_maps_value_positions_to_the_text() {
+        let text = "id,\"a \"\"b\"\" c\"\n";
+        let records = parse_records(text, ',');
+        let field = &records[0][1];
+
+        assert_eq!(field.get_value(), "a \"b\" c");
+        assert_eq!(field.get_position(0), 4);
+        assert_eq!(
+            &text[field.get_position(2)..field.get_position(5)],
+            "\"\"b\"\""
+        );
+        assert_eq!(&text[field.get_position(6)..field.get_position(7)], "c");
+    }
+
+    #[test]
+    fn test_escapes_values_for_fields() {
+        assert_eq!(
+            escape_field("line\nbreak", Escaping::DelimitedField(',')).unwrap(),
+            "\"line\nbreak\""
+        );
+        assert_eq!(
+            escape_field("6\" tall", Escaping::DelimitedField('\t')).unwrap(),
+            "\"6\"\" tall\""
+        );
+        assert_eq!(
+            escape_field("a,b", Escaping::DelimitedField('\t')).unwrap(),
+            "a,b"
+        );
+        assert_eq!(
+            escape_field("a,b\n", Escaping::DelimitedQuoted).unwrap(),
+            "a,b\n"
+        );
+        assert_eq!(
+            escape_field("ab", Escaping::DelimitedFieldPart(',')).unwrap(),
+            "ab"
+        );
+        assert!(escape_field("a\"b", Escaping::DelimitedFieldPart(',')).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::dto::edit::Edit;
+
+/// Applies edits to a text and returns the edited text.
+///
+/// Each edit replaces `length` bytes at its position with its new value. Edits are applied
+/// in position order and must not overlap. The text between edits is kept as it is.
+///
+/// # Arguments
+///
+/// * `text` - The text to edit.
+/// * `edits` - The edits to apply, with positions in the original text.
+///
+/// # Example
+///
+/// ```
+/// use seer::dto::edit::Edit;
+/// use seer::helpers::edits::apply_edits;
+///
+/// let edits = vec![Edit::replacing(0, 3, String::from("baz")), Edit::new(7, String::from("!"))];
+/// assert_eq!(apply_edits("foo bar", edits.iter()), "baz bar!");
+/// ```
+pub fn apply_edits<'a, I>(text: &str, edits: I) -> String
+where
+    I: IntoIterator<Item = &'a Edit>,
+{
+    let mut edits: Vec<&Edit> = edits.into_iter().collect();
+    edits.sort_by_key(|edit| edit.get_position());
+
+    let mut edited = String::with_capacity(text.len());
+    let mut kept_from = 0;
+
+    for edit in edits {
+        edited.push_str(&text[kept_from..edit.get_position()]);
+        edited.push_str(edit.get_new_value());
+        kept_from = edit.get_position() + edit.get_length();
+    }
+
+    edited.push_str(&text[kept_from..]);
+    edited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applies_edits_in_position_order() {
+        let edits = [
+            Edit::replacing(8, 3, String::from("qux")),
+            Edit::replacing(0, 3, String::from("baz")),
+        ];
+        assert_eq!(apply_edits("foo bar foo", edits.iter()), "baz bar qux");
+    }
+
+    #[test]
+    fn test_without_edits_keeps_the_text() {
+        assert_eq!(apply_edits("foo\r\nbar", Vec::new()), "foo\r\nbar");
+    }
+}
@@ -0,0 +1,413 @@
+use crate::strategies::enums::encoding::Encoding;
+use encoding_rs::CoderResult;
+use std::io::{self, Read};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16BE_BOM: &[u8] = b"\xFE\xFF";
+
+/// The encoding a source was read in, and whether it started with a byte order mark.
+///
+/// Edited sources are written back with the same encoding and BOM.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::encoding::SourceEncoding;
+/// use seer::strategies::enums::encoding::Encoding;
+///
+/// let bytes = b"\xFF\xFEh\0i\0";
+/// let source_encoding = SourceEncoding::sniff(bytes, None);
+/// assert_eq!(source_encoding.get_encoding(), Encoding::Utf16Le);
+///
+/// let text = source_encoding.decode(bytes);
+/// assert_eq!(text, "hi");
+/// assert_eq!(source_encoding.encode(&text).unwrap(), bytes);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct SourceEncoding {
+    encoding: Encoding,
+    bom: bool,
+}
+
+impl SourceEncoding {
+    // Constructor for SourceEncoding
+    pub fn new(encoding: Encoding, bom: bool) -> Self {
+        Self { encoding, bom }
+    }
+
+    /// Finds the encoding of a source from its byte order mark.
+    ///
+    /// With a requested encoding, a BOM is only recognized if it belongs to that encoding.
+    /// Without one, a source without a BOM is taken to be UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes of the source, or at least its first few bytes.
+    /// * `requested` - The encoding given with `--encoding`, or `None` to detect it.
+    pub fn sniff(bytes: &[u8], requested: Option<Encoding>) -> Self {
+        let bom_encoding = if bytes.starts_with(UTF8_BOM) {
+            Some(Encoding::Utf8)
+        } else if bytes.starts_with(UTF16LE_BOM) {
+            Some(Encoding::Utf16Le)
+        } else if bytes.starts_with(UTF16BE_BOM) {
+            Some(Encoding::Utf16Be)
+        } else {
+            None
+        };
+
+        match (requested, bom_encoding) {
+            (Some(requested), bom_encoding) => {
+                Self::new(requested, bom_encoding == Some(requested))
+            }
+            (None, Some(bom_encoding)) => Self::new(bom_encoding, true),
+            (None, None) => Self::new(Encoding::Utf8, false),
+        }
+    }
+
+    // Returns the encoding.
+    pub fn get_encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    // Returns true if the source started with a byte order mark.
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
+
+    // Returns the length of the byte order mark in bytes, or 0 without one.
+    pub fn get_bom_length(&self) -> usize {
+        if !self.bom {
+            return 0;
+        }
+
+        match self.encoding {
+            Encoding::Utf8 => UTF8_BOM.len(),
+            Encoding::Utf16Le | Encoding::Utf16Be => UTF16LE_BOM.len(),
+            Encoding::Latin1 | Encoding::Windows1252 => 0,
+        }
+    }
+
+    /// Decodes the bytes of a source, skipping its BOM.
+    ///
+    /// Bytes that are invalid in the encoding are replaced with U+FFFD.
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        let bytes = &bytes[self.get_bom_length()..];
+
+        match self.encoding {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Encoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            Encoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+            Encoding::Latin1 => bytes.iter().map(|&byte| char::from(byte)).collect(),
+            Encoding::Windows1252 => encoding_rs::WINDOWS_1252
+                .decode_without_bom_handling(bytes)
+                .0
+                .into_owned(),
+        }
+    }
+
+    /// Wraps a reader of a source's bytes in one that reads its text as UTF-8, skipping its BOM.
+    ///
+    /// Bytes that are invalid in the encoding are replaced with U+FFFD, just as with `decode`.
+    pub fn decoding_reader<R: Read>(&self, reader: R) -> DecodingReader<R> {
+        let encoding = match self.encoding {
+            Encoding::Utf8 => Some(encoding_rs::UTF_8),
+            Encoding::Utf16Le => Some(encoding_rs::UTF_16LE),
+            Encoding::Utf16Be => Some(encoding_rs::UTF_16BE),
+            // The WHATWG decoders treat Latin-1 as Windows-1252
+            Encoding::Latin1 => None,
+            Encoding::Windows1252 => Some(encoding_rs::WINDOWS_1252),
+        };
+
+        DecodingReader {
+            reader,
+            decoder: encoding.map(encoding_rs::Encoding::new_decoder_without_bom_handling),
+            bom_length: self.get_bom_length(),
+            bytes: vec![0; DECODING_CHUNK_SIZE],
+            text: String::new(),
+            position: 0,
+            is_end: false,
+        }
+    }
+
+    /// Encodes a text in this encoding, starting with the BOM if the source had one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text has a character that the encoding cannot represent.
+    pub fn encode(&self, text: &str) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(text.len() + 3);
+
+        match self.encoding {
+            Encoding::Utf8 => {
+                if self.bom {
+                    bytes.extend_from_slice(UTF8_BOM);
+                }
+                bytes.extend_from_slice(text.as_bytes());
+            }
+            Encoding::Utf16Le => {
+                if self.bom {
+                    bytes.extend_from_slice(UTF16LE_BOM);
+                }
+                bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+            }
+            Encoding::Utf16Be => {
+                if self.bom {
+                    bytes.extend_from_slice(UTF16BE_BOM);
+                }
+                bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+            }
+            Encoding::Latin1 => {
+                for character in text.chars() {
+                    let byte = u8::try_from(character)
+                        .map_err(|_| unencodable(character, self.encoding))?;
+                    bytes.push(byte);
+                }
+            }
+            Encoding::Windows1252 => {
+                let (encoded, _, had_errors) = encoding_rs::WINDOWS_1252.encode(text);
+                if had_errors {
+                    let character = text
+                        .chars()
+                        .find(|character| {
+                            encoding_rs::WINDOWS_1252
+                                .encode(character.encode_utf8(&mut [0; 4]))
+                                .2
+                        })
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+                    return Err(unencodable(character, self.encoding));
+                }
+                bytes.extend_from_slice(&encoded);
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl Default for SourceEncoding {
+    fn default() -> Self {
+        Self::new(Encoding::Utf8, false)
+    }
+}
+
+// The number of bytes a decoding reader reads at a time.
+const DECODING_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Reads the text of a source in another encoding as UTF-8, a chunk at a time.
+///
+/// The text read is always valid UTF-8, so a stream in any encoding can be searched without reading it whole.
+pub struct DecodingReader<R> {
+    reader: R,
+    // The decoder for the encoding, or `None` for Latin-1, where each byte is the character with its value
+    decoder: Option<encoding_rs::Decoder>,
+    // The number of bytes of the BOM left to skip
+    bom_length: usize,
+    bytes: Vec<u8>,
+    // The decoded text, with the part before `position` already read
+    text: String,
+    position: usize,
+    is_end: bool,
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.text.len() && !self.is_end {
+            let read = self.reader.read(&mut self.bytes)?;
+            self.is_end = read == 0;
+            self.text.clear();
+            self.position = 0;
+
+            let skipped = self.bom_length.min(read);
+            self.bom_length -= skipped;
+            let mut bytes = &self.bytes[skipped..read];
+
+            match &mut self.decoder {
+                // At the end, the decoder is called once more to replace an incomplete character
+                Some(decoder) => loop {
+                    let capacity = decoder
+                        .max_utf8_buffer_length(bytes.len())
+                        .unwrap_or(bytes.len() * 3 + 4);
+                    self.text.reserve(capacity);
+                    let (result, decoded, _) =
+                        decoder.decode_to_string(bytes, &mut self.text, self.is_end);
+                    bytes = &bytes[decoded..];
+                    if result == CoderResult::InputEmpty {
+                        break;
+                    }
+                },
+                None => self.text.extend(bytes.iter().map(|&byte| char::from(byte))),
+            }
+        }
+
+        let read = buf.len().min(self.text.len() - self.position);
+        buf[..read].copy_from_slice(&self.text.as_bytes()[self.position..self.position + read]);
+        self.position += read;
+        Ok(read)
+    }
+}
+
+/// Parses an encoding name given with `--encoding`.
+///
+/// # Returns
+///
+/// The encoding, or `None` for `auto`, which detects the encoding of each source.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::encoding::parse_encoding;
+/// use seer::strategies::enums::encoding::Encoding;
+///
+/// assert_eq!(parse_encoding("utf-16le"), Some(Encoding::Utf16Le));
+/// assert_eq!(parse_encoding("auto"), None);
+/// ```
+pub fn parse_encoding(name: &str) -> Option<Encoding> {
+    match name {
+        "utf-8" => Some(Encoding::Utf8),
+        "utf-16le" => Some(Encoding::Utf16Le),
+        "utf-16be" => Some(Encoding::Utf16Be),
+        "latin1" => Some(Encoding::Latin1),
+        "windows-1252" => Some(Encoding::Windows1252),
+        _ => None,
+    }
+}
+
+// Decodes UTF-16 code units read with the given byte order, replacing invalid ones.
+fn decode_utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> String {
+    let units = bytes.chunks(2).map(|pair| match pair {
+        [first, second] => from_bytes([*first, *second]),
+        // A lone trailing byte can't be a code unit
+        _ => 0xFFFD,
+    });
+
+    char::decode_utf16(units)
+        .map(|character| character.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+// Returns the error for a character that the encoding cannot represent.
+fn unencodable(character: char, encoding: Encoding) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("'{}' cannot be written in {:?}", character, encoding),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniffs_boms() {
+        let cases: [(&[u8], Encoding, bool); 4] = [
+            (b"\xEF\xBB\xBFabc", Encoding::Utf8, true),
+            (b"\xFF\xFEa\0", Encoding::Utf16Le, true),
+            (b"\xFE\xFF\0a", Encoding::Utf16Be, true),
+            (b"abc", Encoding::Utf8, false),
+        ];
+
+        for (bytes, encoding, bom) in cases {
+            assert_eq!(
+                SourceEncoding::sniff(bytes, None),
+                SourceEncoding::new(encoding, bom)
+            );
+        }
+    }
+
+    #[test]
+    fn test_requested_encoding_ignores_other_boms() {
+        let source_encoding = SourceEncoding::sniff(b"\xFF\xFEa", Some(Encoding::Latin1));
+        assert_eq!(
+            source_encoding,
+            SourceEncoding::new(Encoding::Latin1, false)
+        );
+        assert_eq!(source_encoding.decode(b"\xFF\xFEa"), "ÿþa");
+    }
+
+    #[test]
+    fn test_round_trips_every_encoding() {
+        let text = "café\r\n";
+        for encoding in [
+            Encoding::Utf8,
+            Encoding::Utf16Le,
+            Encoding::Utf16Be,
+            Encoding::Latin1,
+            Encoding::Windows1252,
+        ] {
+            for bom in [false, true] {
+                let source_encoding = SourceEncoding::new(encoding, bom);
+                let bytes = source_encoding.encode(text).unwrap();
+                // Only Unicode encodings have a BOM
+                let bom = source_encoding.get_bom_length() > 0;
+                assert_eq!(
+                    SourceEncoding::sniff(&bytes, Some(encoding)),
+                    SourceEncoding::new(encoding, bom)
+                );
+                assert_eq!(source_encoding.decode(&bytes), text, "{:?}", encoding);
+            }
+        }
+    }
+
+    #[test]
+    fn test_windows_1252_differs_from_latin1() {
+        let bytes = b"\x80";
+        assert_eq!(
+            SourceEncoding::new(Encoding::Windows1252, false).decode(bytes),
+            "€"
+        );
+        assert_eq!(
+            SourceEncoding::new(Encoding::Latin1, false).decode(bytes),
+            "\u{80}"
+        );
+    }
+
+    #[test]
+    fn test_rejects_unencodable_characters() {
+        assert!(SourceEncoding::new(Encoding::Latin1, false)
+            .encode("€")
+            .is_err());
+        assert!(SourceEncoding::new(Encoding::Windows1252, false)
+            .encode("→")
+            .is_err());
+    }
+
+    // Reads one byte at a time, so every character is split across reads.
+    struct OneByteReader<'a>(&'a [u8]);
+
+    impl Read for OneByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let read = self.0.len().min(buf.len()).min(1);
+            buf[..read].copy_from_slice(&self.0[..read]);
+            self.0 = &self.0[read..];
+            Ok(read)
+        }
+    }
+
+    #[test]
+    fn test_decoding_reader_reads_the_same_text_as_decode() {
+        let cases: [(&[u8], Encoding); 5] = [
+            (b"\xEF\xBB\xBFcaf\xC3\xA9 \xFF!", Encoding::Utf8),
+            (b"\xFF\xFEa\0\0\xD8b\0", Encoding::Utf16Le),
+            (b"\xFE\xFF\0a\0\xE9", Encoding::Utf16Be),
+            (b"caf\xE9 \x80", Encoding::Latin1),
+            (b"caf\xE9 \x80", Encoding::Windows1252),
+        ];
+
+        for (bytes, encoding) in cases {
+            let source_encoding = SourceEncoding::sniff(bytes, Some(encoding));
+            let mut text = String::new();
+            source_encoding
+                .decoding_reader(OneByteReader(bytes))
+                .read_to_string(&mut text)
+                .unwrap();
+            assert_eq!(text, source_encoding.decode(bytes), "{:?}", encoding);
+        }
+    }
+
+    #[test]
+    fn test_replaces_invalid_utf16() {
+        let source_encoding = SourceEncoding::new(Encoding::Utf16Le, false);
+        assert_eq!(source_encoding.decode(b"a\0\0\xD8b"), "a\u{FFFD}\u{FFFD}");
+    }
+}
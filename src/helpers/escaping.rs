@@ -0,0 +1,90 @@
+use super::delimited::{escape_field, unescape_quoted_field};
+use super::json_path::{escape_json_string, unescape_json_string};
+use super::key_value::{escape_kv_value, unescape_kv_value};
+use super::markup::{escape_markup_value, unescape_markup_value};
+use super::toml_key::{escape_toml_string, unescape_toml_string};
+use super::yaml_path::{escape_yaml_scalar, unescape_yaml_scalar};
+use crate::strategies::enums::escaping::Escaping;
+use std::borrow::Cow;
+use std::io;
+
+/// Undoes the escaping of a value as it is written in its source.
+///
+/// A value that is not validly escaped is returned as it is written.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::escaping::unescape;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(unescape(r#"say \"hi\""#, Escaping::JsonString), r#"say "hi""#);
+/// assert_eq!(unescape(r#"say \"hi\""#, Escaping::None), r#"say \"hi\""#);
+/// ```
+pub fn unescape(raw: &str, escaping: Escaping) -> Cow<'_, str> {
+    match escaping {
+        Escaping::None
+        | Escaping::DelimitedField(_)
+        | Escaping::DelimitedFieldPart(_)
+        | Escaping::XmlCData
+        | Escaping::KvPlain
+        | Escaping::KvSingleQuoted => Cow::Borrowed(raw),
+        Escaping::DelimitedQuoted => Cow::Owned(unescape_quoted_field(raw)),
+        markup @ (Escaping::XmlText | Escaping::XmlAttribute(_)) => {
+            Cow::Owned(unescape_markup_value(raw, markup))
+        }
+        kv @ (Escaping::KvDoubleQuoted | Escaping::KvProperties) => {
+            Cow::Owned(unescape_kv_value(raw, kv))
+        }
+        toml @ (Escaping::TomlBasic | Escaping::TomlLiteral) => {
+            unescape_toml_string(raw, toml).map_or(Cow::Borrowed(raw), Cow::Owned)
+        }
+        Escaping::JsonString => unescape_json_string(raw).map_or(Cow::Borrowed(raw), Cow::Owned),
+        yaml @ (Escaping::YamlPlain
+        | Escaping::YamlSingleQuoted
+        | Escaping::YamlDoubleQuoted
+        | Escaping::YamlLiteral(_)
+        | Escaping::YamlFolded(_)) => {
+            unescape_yaml_scalar(raw, yaml).map_or(Cow::Borrowed(raw), Cow::Owned)
+        }
+    }
+}
+
+/// Escapes a value so it can be written where a value with the given escaping was.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error if the value cannot be written there.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::escaping::escape;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(escape("a\\b", Escaping::JsonString).unwrap(), r"a\\b");
+/// ```
+pub fn escape(text: &str, escaping: Escaping) -> io::Result<Cow<'_, str>> {
+    Ok(match escaping {
+        Escaping::None => Cow::Borrowed(text),
+        Escaping::JsonString => Cow::Owned(escape_json_string(text)),
+        yaml @ (Escaping::YamlPlain
+        | Escaping::YamlSingleQuoted
+        | Escaping::YamlDoubleQuoted
+        | Escaping::YamlLiteral(_)
+        | Escaping::YamlFolded(_)) => Cow::Owned(escape_yaml_scalar(text, yaml)?),
+        toml @ (Escaping::TomlBasic | Escaping::TomlLiteral) => {
+            Cow::Owned(escape_toml_string(text, toml)?)
+        }
+        delimited @ (Escaping::DelimitedField(_)
+        | Escaping::DelimitedFieldPart(_)
+        | Escaping::DelimitedQuoted) => escape_field(text, delimited)?,
+        markup @ (Escaping::XmlText | Escaping::XmlAttribute(_) | Escaping::XmlCData) => {
+            Cow::Owned(escape_markup_value(text, markup)?)
+        }
+        kv @ (Escaping::KvPlain
+        | Escaping::KvSingleQuoted
+        | Escaping::KvDoubleQuoted
+        | Escaping::KvProperties) => Cow::Owned(escape_kv_value(text, kv)?),
+    })
+}
@@ -1,7 +1,16 @@
+use crate::dto::source_text::SourceText;
+use crate::helpers::compression::decompress;
+use crate::strategies::enums::compression::Compression;
+use crate::strategies::enums::encoding::Encoding;
+use crate::strategies::enums::mmap_mode::MmapMode;
+use memmap2::Mmap;
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::PathBuf;
 
+/// Files of at least this many bytes are memory-mapped with `MmapMode::Auto`.
+pub const MMAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
 /// Returns a vector of file paths from the specified directory.
 ///
 /// # Arguments
@@ -83,6 +92,105 @@ mod get_file_paths_from_dir_tests {
     }
 }
 
+/// Returns the file paths under the specified directory, sorted by path.
+///
+/// Subdirectories are searched recursively, down to `max_depth` levels below `dir` if given.
+/// A `max_depth` of 0 only returns the files directly in `dir`.
+///
+/// # Arguments
+///
+/// * `dir` - A string slice that holds the path of the directory
+/// * `max_depth` - The number of subdirectory levels to descend into, or `None` for no limit
+///
+/// # Example
+///
+/// ```
+/// use std::fs;
+/// use tempfile::tempdir;
+/// use seer::helpers::file_sys::walk_dir;
+///
+/// let dir = tempdir().unwrap();
+/// fs::create_dir(dir.path().join("sub")).unwrap();
+/// fs::File::create(dir.path().join("sub").join("b.txt")).unwrap();
+/// fs::File::create(dir.path().join("a.txt")).unwrap();
+///
+/// let file_paths = walk_dir(dir.path().to_str().unwrap(), None).unwrap();
+/// assert_eq!(file_paths, vec![dir.path().join("a.txt"), dir.path().join("sub").join("b.txt")]);
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if the directory or one of its subdirectories cannot be read.
+pub fn walk_dir(dir: &str, max_depth: Option<u64>) -> io::Result<Vec<PathBuf>> {
+    let mut file_paths = Vec::new();
+    let mut dirs = vec![(PathBuf::from(dir), 0)];
+
+    while let Some((dir_path, depth)) = dirs.pop() {
+        for entry in fs::read_dir(&dir_path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read directory: '{}'. {}", dir_path.display(), e),
+            )
+        })? {
+            let path = entry?.path();
+            if path.is_file() {
+                file_paths.push(path);
+            } else if path.is_dir() && max_depth.is_none_or(|max_depth| depth < max_depth) {
+                dirs.push((path, depth + 1));
+            }
+        }
+    }
+
+    file_paths.sort();
+    Ok(file_paths)
+}
+
+#[cfg(test)]
+mod walk_dir_tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_walks_subdirectories_in_path_order() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+        fs::create_dir_all(dir_path.join("b").join("c")).unwrap();
+        fs::File::create(dir_path.join("b").join("c").join("3.txt")).unwrap();
+        fs::File::create(dir_path.join("b").join("2.txt")).unwrap();
+        fs::File::create(dir_path.join("a.txt")).unwrap();
+
+        let file_paths = walk_dir(dir_path.to_str().unwrap(), None).unwrap();
+        assert_eq!(
+            file_paths,
+            vec![
+                dir_path.join("a.txt"),
+                dir_path.join("b").join("2.txt"),
+                dir_path.join("b").join("c").join("3.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stops_at_max_depth() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+        fs::create_dir_all(dir_path.join("b").join("c")).unwrap();
+        fs::File::create(dir_path.join("b").join("c").join("3.txt")).unwrap();
+        fs::File::create(dir_path.join("b").join("2.txt")).unwrap();
+        fs::File::create(dir_path.join("a.txt")).unwrap();
+
+        let dir_str = dir_path.to_str().unwrap();
+        assert_eq!(walk_dir(dir_str, Some(0)).unwrap().len(), 1);
+        assert_eq!(walk_dir(dir_str, Some(1)).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_walk_invalid_dir() {
+        assert!(walk_dir("invalid_dir", None).is_err());
+    }
+}
+
 /// Reads the contents of a file given its path.
 ///
 /// # Arguments
@@ -146,3 +254,394 @@ mod read_file_tests {
         );
     }
 }
+
+/// Opens a file for reading given its path.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path of the file.
+///
+/// # Errors
+///
+/// This function will return an error if the file path is invalid or the file does not exist.
+pub fn open_file(file_path: &str) -> io::Result<fs::File> {
+    fs::File::open(file_path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Failed to read file: '{}'. {}", file_path, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod open_file_tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_can_open_valid_path() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "file content").unwrap();
+
+        let mut contents = String::new();
+        open_file(temp_file.path().to_str().unwrap())
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "file content\n");
+    }
+
+    #[test]
+    fn test_returns_error_with_invalid_path() {
+        assert_eq!(
+            open_file("invalid_file").unwrap_err().to_string(),
+            "Failed to read file: 'invalid_file'. No such file or directory (os error 2)"
+        );
+    }
+}
+
+/// Loads the text of a file, memory-mapping it when the mmap mode allows.
+///
+/// A mapped file is searched in place instead of being copied into a `String`.
+/// With `MmapMode::Auto`, only files of at least `MMAP_THRESHOLD` bytes are mapped.
+/// Empty files are always read, since they cannot be mapped.
+/// The file is decoded in the requested encoding, or the one its BOM gives.
+/// A file that looks binary is loaded and marked as binary rather than failing.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path of the file.
+/// * `mmap_mode` - When to map the file.
+/// * `encoding` - The encoding given with `--encoding`, or `None` to detect it.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+///
+/// # Examples
+///
+/// ```
+/// use seer::helpers::file_sys::load_file;
+/// use seer::strategies::enums::mmap_mode::MmapMode;
+/// use tempfile::NamedTempFile;
+/// use std::io::Write;
+///
+/// let mut temp_file = NamedTempFile::new().unwrap();
+/// write!(temp_file, "file content").unwrap();
+/// let file_path = temp_file.path().to_str().unwrap();
+///
+/// let text = load_file(file_path, MmapMode::Always, None).unwrap();
+/// assert_eq!(&*text, "file content");
+/// ```
+pub fn load_file(
+    file_path: &str,
+    mmap_mode: MmapMode,
+    encoding: Option<Encoding>,
+) -> io::Result<SourceText> {
+    let add_path = |e: io::Error| {
+        io::Error::new(
+            e.kind(),
+            format!("Failed to read file: '{}'. {}", file_path, e),
+        )
+    };
+    let file = open_file(file_path)?;
+    let len = file.metadata().map_err(add_path)?.len();
+    let map = match mmap_mode {
+        MmapMode::Always => len > 0,
+        MmapMode::Auto => len >= MMAP_THRESHOLD,
+        MmapMode::Never => false,
+    };
+
+    if !map {
+        return fs::read(file_path)
+            .map(|bytes| SourceText::from_bytes(bytes, encoding))
+            .map_err(add_path);
+    }
+
+    // SAFETY: Mapping is only sound while the file does not change, which no OS guarantees.
+    // Seer never writes a file it mapped, as edited files are read instead.
+    // If another process truncates or rewrites the file meanwhile, reads of the map may fault or see
+    // the new bytes, a risk taken on for large files and avoided with `--no-mmap`
+    let mmap = unsafe { Mmap::map(&file) }.map_err(add_path)?;
+
+    Ok(SourceText::from_mmap(mmap, encoding))
+}
+
+#[cfg(test)]
+mod load_file_tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn get_temp_file(contents: &[u8]) -> NamedTempFile {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(contents).unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_maps_file_when_always() {
+        let temp_file = get_temp_file(b"file content");
+        let text = load_file(temp_file.path().to_str().unwrap(), MmapMode::Always, None).unwrap();
+
+        assert!(text.is_mapped());
+        assert_eq!(&*text, "file content");
+    }
+
+    #[test]
+    fn test_reads_small_file_when_auto() {
+        let temp_file = get_temp_file(b"file content");
+        let text = load_file(temp_file.path().to_str().unwrap(), MmapMode::Auto, None).unwrap();
+
+        assert!(!text.is_mapped());
+        assert_eq!(&*text, "file content");
+    }
+
+    #[test]
+    fn test_reads_file_when_never() {
+        let temp_file = get_temp_file(b"file content");
+        let text = load_file(temp_file.path().to_str().unwrap(), MmapMode::Never, None).unwrap();
+
+        assert!(!text.is_mapped());
+    }
+
+    #[test]
+    fn test_reads_empty_file_when_always() {
+        let temp_file = get_temp_file(b"");
+        let text = load_file(temp_file.path().to_str().unwrap(), MmapMode::Always, None).unwrap();
+
+        assert_eq!(&*text, "");
+    }
+
+    #[test]
+    fn test_loads_binary_file_as_binary() {
+        for mmap_mode in [MmapMode::Always, MmapMode::Never] {
+            let temp_file = get_temp_file(b"abc\xff\0def");
+            let text = load_file(temp_file.path().to_str().unwrap(), mmap_mode, None).unwrap();
+
+            assert!(text.is_binary());
+            assert_eq!(&*text, "abc\u{FFFD}\0def");
+        }
+    }
+}
+
+/// Opens a compressed file for reading its decompressed bytes.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path of the file.
+/// * `compression` - The compression format of the file.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be opened.
+pub fn open_decompressed(file_path: &str, compression: Compression) -> io::Result<Box<dyn Read>> {
+    decompress(open_file(file_path)?, compression).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Failed to decompress file: '{}'. {}", file_path, e),
+        )
+    })
+}
+
+/// Loads the decompressed text of a compressed file.
+///
+/// The decompressed bytes are decoded and checked for binary content like those of any other file.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path of the file.
+/// * `compression` - The compression format of the file.
+/// * `encoding` - The encoding given with `--encoding`, or `None` to detect it.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read or is not validly compressed.
+pub fn load_decompressed(
+    file_path: &str,
+    compression: Compression,
+    encoding: Option<Encoding>,
+) -> io::Result<SourceText> {
+    let mut bytes = Vec::new();
+    open_decompressed(file_path, compression)?
+        .read_to_end(&mut bytes)
+        .map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to decompress file: '{}'. {}", file_path, e),
+            )
+        })?;
+    Ok(SourceText::from_bytes(bytes, encoding))
+}
+
+#[cfg(test)]
+mod load_decompressed_tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_loads_decompressed_text() {
+        let temp_file = NamedTempFile::with_suffix(".gz").unwrap();
+        let mut encoder =
+            GzEncoder::new(temp_file.reopen().unwrap(), flate2::Compression::default());
+        encoder.write_all(b"file content").unwrap();
+        encoder.finish().unwrap();
+
+        let text =
+            load_decompressed(temp_file.path().to_str().unwrap(), Compression::Gzip, None).unwrap();
+        assert_eq!(&*text, "file content");
+    }
+
+    #[test]
+    fn test_returns_error_with_corrupt_data() {
+        let mut temp_file = NamedTempFile::with_suffix(".gz").unwrap();
+        temp_file.write_all(b"not gzip").unwrap();
+
+        assert!(
+            load_decompressed(temp_file.path().to_str().unwrap(), Compression::Gzip, None,)
+                .is_err()
+        );
+    }
+}
+
+/// Replaces the contents of a file.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path of the file.
+/// * `bytes` - The new contents of the file.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be written.
+///
+/// # Examples
+///
+/// ```
+/// use seer::helpers::file_sys::write_file;
+/// use tempfile::NamedTempFile;
+///
+/// let temp_file = NamedTempFile::new().unwrap();
+/// let file_path = temp_file.path().to_str().unwrap();
+///
+/// write_file(file_path, b"file content").unwrap();
+/// assert_eq!(std::fs::read(file_path).unwrap(), b"file content");
+/// ```
+pub fn write_file(file_path: &str, bytes: &[u8]) -> io::Result<()> {
+    fs::write(file_path, bytes).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("Failed to write file: '{}'. {}", file_path, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod write_file_tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_can_write_to_valid_path() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let file_path = temp_file.path().to_str().unwrap();
+
+        write_file(file_path, b"\xFF\xFEa\0").unwrap();
+        assert_eq!(fs::read(file_path).unwrap(), b"\xFF\xFEa\0");
+    }
+
+    #[test]
+    fn test_returns_error_with_invalid_path() {
+        assert!(write_file("invalid_dir/invalid_file", b"").is_err());
+    }
+}
+
+/// Reads a list of file paths from a file, or from stdin if the path is `-`.
+///
+/// Paths are separated by newlines, or by NUL bytes if `null` is set, as `find -print0`,
+/// `git ls-files -z`, and `fd -0` write them. Empty entries are left out.
+///
+/// # Arguments
+///
+/// * `list_path` - The path of the file holding the list, or `-` for stdin.
+/// * `null` - Whether the paths are separated by NUL bytes.
+///
+/// # Errors
+///
+/// This function will return an error if the list cannot be read or is not valid UTF-8.
+pub fn read_file_list(list_path: &str, null: bool) -> io::Result<Vec<String>> {
+    let mut bytes = Vec::new();
+    let read = if list_path == "-" {
+        io::stdin().lock().read_to_end(&mut bytes)
+    } else {
+        open_file(list_path)?.read_to_end(&mut bytes)
+    };
+    let text = read
+        .and_then(|_| {
+            String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read file list: '{}'. {}", list_path, e),
+            )
+        })?;
+
+    Ok(split_file_list(&text, null))
+}
+
+// Splits a file list into its paths, without line endings or empty entries.
+fn split_file_list(text: &str, null: bool) -> Vec<String> {
+    let separator = if null { '\0' } else { '\n' };
+    text.split(separator)
+        .map(|path| {
+            if null {
+                path
+            } else {
+                path.strip_suffix('\r').unwrap_or(path)
+            }
+        })
+        .filter(|path| !path.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod read_file_list_tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_splits_lines() {
+        assert_eq!(
+            split_file_list("a.txt\r\nb, c.txt\n\n", false),
+            vec!["a.txt", "b, c.txt"]
+        );
+    }
+
+    #[test]
+    fn test_splits_nul_separated_paths() {
+        assert_eq!(
+            split_file_list("a\nb.txt\0c.txt\0", true),
+            vec!["a\nb.txt", "c.txt"]
+        );
+    }
+
+    #[test]
+    fn test_can_read_from_valid_path() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "a.txt\0b.txt\0").unwrap();
+
+        let file_paths = read_file_list(temp_file.path().to_str().unwrap(), true).unwrap();
+        assert_eq!(file_paths, vec!["a.txt", "b.txt"]);
+    }
+
+    #[test]
+    fn test_returns_error_with_invalid_path() {
+        assert!(read_file_list("invalid_file", false).is_err());
+    }
+}
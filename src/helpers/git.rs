@@ -0,0 +1,442 @@
+use crate::strategies::enums::git_selection::GitSelection;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// A run of consecutive lines added by a change.
+#[derive(PartialEq, Debug)]
+pub struct AddedLines {
+    path: String,
+    start_line: usize,
+    text: String,
+}
+
+impl AddedLines {
+    // Constructor for AddedLines
+    pub fn new(path: String, start_line: usize, text: String) -> Self {
+        Self {
+            path,
+            start_line,
+            text,
+        }
+    }
+
+    // Getter for the path of the file the lines were added to
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    // Getter for the line number of the first added line, counting from 1
+    pub fn get_start_line(&self) -> usize {
+        self.start_line
+    }
+
+    // Getter for the added lines, with their line endings
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Runs `git` with the given arguments and returns what it prints.
+///
+/// # Arguments
+///
+/// * `dir` - The directory to run git in, or an empty path for the current directory.
+/// * `args` - The arguments to git.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or fails, with what git printed to stderr.
+pub fn run_git(dir: &Path, args: &[&str]) -> io::Result<Vec<u8>> {
+    let mut command = Command::new("git");
+    if !dir.as_os_str().is_empty() {
+        command.current_dir(dir);
+    }
+
+    let output = command
+        .args(args)
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("Failed to run git. {}", e)))?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "'git {}' failed. {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Returns the paths of the files with changes, sorted by path.
+///
+/// Deleted files are left out, since they cannot be searched.
+/// Paths are relative to `dir`, even when `dir` is below the top of the repository.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not in a git repository, the range starts with `-`, or git fails.
+pub fn list_changed_files(dir: &Path, selection: &GitSelection) -> io::Result<Vec<String>> {
+    let top_level = get_top_level(dir)?;
+    let mut args = vec!["diff", "--name-only", "-z", "--diff-filter=d"];
+    args.extend(get_diff_args(selection)?);
+
+    let mut paths = split_nul(&run_git(dir, &args)?);
+    if *selection == GitSelection::Changed {
+        paths.extend(list_untracked_files(dir)?);
+    }
+
+    paths.sort();
+    paths.dedup();
+    Ok(paths
+        .into_iter()
+        .map(|path| top_level.join(path).to_string_lossy().into_owned())
+        .collect())
+}
+
+/// Returns the runs of lines added by the changes, in path and line order.
+///
+/// Every line of an untracked file counts as added.
+/// Paths are relative to `dir`, even when `dir` is below the top of the repository.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not in a git repository, the range starts with `-`, or git fails.
+pub fn list_added_lines(dir: &Path, selection: &GitSelection) -> io::Result<Vec<AddedLines>> {
+    let top_level = get_top_level(dir)?;
+    let mut args = vec![
+        "-c",
+        "core.quotepath=off",
+        "diff",
+        "--unified=0",
+        "--no-color",
+        "--no-ext-diff",
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+        "--diff-filter=d",
+    ];
+    args.extend(get_diff_args(selection)?);
+
+    let diff = run_git(dir, &args)?;
+    let mut added_lines = parse_added_lines(&String::from_utf8_lossy(&diff));
+
+    if *selection == GitSelection::Changed {
+        for path in list_untracked_files(dir)? {
+            let bytes = fs::read(top_level.join(&path))?;
+            if !bytes.is_empty() {
+                let text = String::from_utf8_lossy(&bytes).into_owned();
+                added_lines.push(AddedLines::new(path, 1, text));
+            }
+        }
+        added_lines.sort_by(|a, b| (&a.path, a.start_line).cmp(&(&b.path, b.start_line)));
+    }
+
+    Ok(added_lines
+        .into_iter()
+        .map(|lines| AddedLines {
+            path: top_level.join(lines.path).to_string_lossy().into_owned(),
+            ..lines
+        })
+        .collect())
+}
+
+/// Returns the paths of the files in the given paths at a revision, sorted by path.
+///
+/// Directories are listed recursively. Paths are relative to `dir`, like the given paths.
+/// Given paths that did not exist at the revision are left out.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not in a git repository, or the revision starts with `-` or does not exist.
+pub fn list_files_at_rev(dir: &Path, rev: &str, paths: &[String]) -> io::Result<Vec<String>> {
+    let mut args = vec!["ls-tree", "-r", "-z", "--name-only", check_rev(rev)?, "--"];
+    args.extend(paths.iter().map(String::as_str));
+    Ok(split_nul(&run_git(dir, &args)?))
+}
+
+/// Reads a file as it was at a revision.
+///
+/// # Arguments
+///
+/// * `dir` - The directory to run git in, or an empty path for the current directory.
+/// * `rev` - The revision, like a commit, tag, or branch.
+/// * `path` - The path of the file, relative to `dir`.
+///
+/// # Errors
+///
+/// Returns an error if the revision starts with `-` or the file did not exist at the revision.
+pub fn read_file_at_rev(dir: &Path, rev: &str, path: &str) -> io::Result<Vec<u8>> {
+    run_git(
+        dir,
+        &[
+            "cat-file",
+            "blob",
+            &format!("{}:./{}", check_rev(rev)?, path),
+        ],
+    )
+}
+
+// Returns the arguments to `git diff` that select the changes.
+fn get_diff_args(selection: &GitSelection) -> io::Result<Vec<&str>> {
+    Ok(match selection {
+        GitSelection::Changed => vec!["HEAD"],
+        GitSelection::Staged => vec!["--cached"],
+        GitSelection::Diff(range) => vec![check_rev(range)?, "--"],
+    })
+}
+
+// Returns the revision or range, or an error if git would take it for an option.
+fn check_rev(rev: &str) -> io::Result<&str> {
+    if rev.starts_with('-') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Invalid revision: '{}'. Revisions cannot start with '-'",
+                rev
+            ),
+        ));
+    }
+    Ok(rev)
+}
+
+// Returns the top of the repository, relative to `dir`.
+fn get_top_level(dir: &Path) -> io::Result<std::path::PathBuf> {
+    let cdup = run_git(dir, &["rev-parse", "--show-cdup"])?;
+    Ok(dir.join(String::from_utf8_lossy(&cdup).trim_end()))
+}
+
+// Returns the paths of the untracked files that are not ignored, relative to the top of the repository.
+fn list_untracked_files(dir: &Path) -> io::Result<Vec<String>> {
+    let untracked = run_git(
+        dir,
+        &[
+            "ls-files",
+            "-z",
+            "--others",
+            "--exclude-standard",
+            "--full-name",
+            ":/",
+        ],
+    )?;
+    Ok(split_nul(&untracked))
+}
+
+// Splits NUL-separated git output into its entries.
+fn split_nul(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|byte| *byte == 0)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| String::from_utf8_lossy(entry).into_owned())
+        .collect()
+}
+
+// Parses the added lines out of a `git diff --unified=0` patch.
+fn parse_added_lines(diff: &str) -> Vec<AddedLines> {
+    let mut added_lines: Vec<AddedLines> = Vec::new();
+    let mut path: Option<String> = None;
+    // Lines before the first hunk of a file are headers, which may look like added lines
+    let mut in_header = false;
+    let mut line_number = 0;
+    let mut starts_run = false;
+    let mut last_was_added = false;
+
+    for line in diff.split_inclusive('\n') {
+        if line.starts_with("diff --git ") {
+            in_header = true;
+            path = None;
+        } else if in_header {
+            if let Some(new_path) = line.strip_prefix("+++ ") {
+                path = new_path.trim_end().strip_prefix("b/").map(String::from);
+            } else if line.starts_with("@@ ") {
+                in_header = false;
+            }
+        }
+
+        if line.starts_with("@@ ") {
+            // The header is `@@ -old_start[,count] +new_start[,count] @@`
+            line_number = line
+                .split_whitespace()
+                .nth(2)
+                .and_then(|range| range.trim_start_matches('+').split(',').next())
+                .and_then(|start| start.parse().ok())
+                .unwrap_or(0);
+            starts_run = true;
+            last_was_added = false;
+            continue;
+        }
+
+        if in_header {
+            continue;
+        }
+
+        if let (Some(added), Some(path)) = (line.strip_prefix('+'), &path) {
+            if starts_run {
+                added_lines.push(AddedLines::new(path.clone(), line_number, String::new()));
+                starts_run = false;
+            }
+            if let Some(run) = added_lines.last_mut() {
+                run.text.push_str(added);
+            }
+            line_number += 1;
+            last_was_added = true;
+        } else if line.starts_with('\\') {
+            // `\ No newline at end of file` applies to the line before it
+            if last_was_added {
+                if let Some(run) = added_lines.last_mut() {
+                    if run.text.ends_with('\n') {
+                        run.text.pop();
+                    }
+                }
+            }
+        } else {
+            last_was_added = false;
+        }
+    }
+
+    added_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let mut all_args = vec!["-c", "user.name=test", "-c", "user.email=test@test"];
+        all_args.extend(args);
+        run_git(dir, &all_args).unwrap();
+    }
+
+    fn get_repo() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub").join("a.txt"), "one\ntwo\n").unwrap();
+        fs::write(dir.path().join("b.txt"), "b\n").unwrap();
+        git(dir.path(), &["add", "."]);
+        git(dir.path(), &["commit", "-q", "-m", "first"]);
+        dir
+    }
+
+    #[test]
+    fn test_parses_added_lines() {
+        let diff = "diff --git a/x.rs b/x.rs\n\
+                    --- a/x.rs\n\
+                    +++ b/x.rs\n\
+                    @@ -1 +1,2 @@\n\
+                    -old\n\
+                    +new\n\
+                    ++++ not a header\n\
+                    @@ -9,0 +11 @@ fn main() {\n\
+                    +last\n\
+                    \\ No newline at end of file\n\
+                    diff --git a/gone.rs b/gone.rs\n\
+                    --- a/gone.rs\n\
+                    +++ /dev/null\n\
+                    @@ -1 +0,0 @@\n\
+                    -gone\n";
+
+        assert_eq!(
+            parse_added_lines(diff),
+            vec![
+                AddedLines::new(
+                    String::from("x.rs"),
+                    1,
+                    String::from("new\n+++ not a header\n")
+                ),
+                AddedLines::new(String::from("x.rs"), 11, String::from("last")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lists_changed_files() {
+        let repo = get_repo();
+        fs::write(repo.path().join("sub").join("a.txt"), "one\nthree\n").unwrap();
+        fs::write(repo.path().join("c.txt"), "c\n").unwrap();
+        fs::remove_file(repo.path().join("b.txt")).unwrap();
+
+        let sub = repo.path().join("sub");
+        assert_eq!(
+            list_changed_files(&sub, &GitSelection::Changed).unwrap(),
+            vec![
+                sub.join("../c.txt").to_string_lossy(),
+                sub.join("../sub/a.txt").to_string_lossy(),
+            ]
+        );
+        assert!(list_changed_files(&sub, &GitSelection::Staged)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_lists_added_lines_in_a_range() {
+        let repo = get_repo();
+        fs::write(repo.path().join("b.txt"), "b\nadded\n").unwrap();
+        git(repo.path(), &["commit", "-q", "-a", "-m", "second"]);
+
+        let added_lines = list_added_lines(
+            repo.path(),
+            &GitSelection::Diff(String::from("HEAD~1..HEAD")),
+        )
+        .unwrap();
+
+        assert_eq!(added_lines.len(), 1);
+        assert!(added_lines[0].get_path().ends_with("b.txt"));
+        assert_eq!(added_lines[0].get_start_line(), 2);
+        assert_eq!(added_lines[0].get_text(), "added\n");
+    }
+
+    #[test]
+    fn test_does_not_take_a_range_for_an_option() {
+        let repo = get_repo();
+        let output = repo.path().join("output.txt");
+        let range = format!("--output={}", output.display());
+
+        let error = list_changed_files(repo.path(), &GitSelection::Diff(range)).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn test_reads_files_at_a_revision() {
+        let repo = get_repo();
+        fs::write(repo.path().join("sub").join("a.txt"), "changed\n").unwrap();
+        fs::write(repo.path().join("sub").join("new.txt"), "new\n").unwrap();
+
+        let sub = repo.path().join("sub");
+        let paths = list_files_at_rev(&sub, "HEAD", &[String::from(".")]).unwrap();
+        assert_eq!(paths, vec!["a.txt"]);
+        assert_eq!(
+            read_file_at_rev(&sub, "HEAD", &paths[0]).unwrap(),
+            b"one\ntwo\n"
+        );
+        assert!(read_file_at_rev(&sub, "HEAD", "new.txt").is_err());
+    }
+
+    #[test]
+    fn test_does_not_take_a_revision_for_an_option() {
+        let repo = get_repo();
+        let output = repo.path().join("output.txt");
+        let rev = format!("--output={}", output.display());
+
+        let error = list_files_at_rev(repo.path(), &rev, &[String::from(".")]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            read_file_at_rev(repo.path(), "--textconv", "b.txt")
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn test_returns_error_outside_of_a_repository() {
+        let dir = tempdir().unwrap();
+        assert!(list_changed_files(dir.path(), &GitSelection::Staged).is_err());
+    }
+}
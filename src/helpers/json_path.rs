@@ -0,0 +1,394 @@
+use super::key_path::{KeyPath, Node, NodeKind};
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+
+/// A JSONPath expression, like `$.services[*].image`, that selects values in a JSON document.
+///
+/// The path starts with `$`, the document itself, followed by segments as described for `KeyPath`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::json_path::JsonPath;
+///
+/// let json_path = JsonPath::parse("$.services[*].image").unwrap();
+/// let text = r#"{"services": [{"image": "nginx"}, {"image": "redis", "port": 6379}]}"#;
+/// let values: Vec<&str> = json_path
+///     .select(text)
+///     .unwrap()
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+///
+/// assert_eq!(values, vec!["nginx", "redis"]);
+/// ```
+#[derive(Debug)]
+pub struct JsonPath {
+    key_path: KeyPath,
+}
+
+impl JsonPath {
+    /// Parses a JSONPath expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the expression is not a supported JSONPath.
+    pub fn parse(path: &str) -> io::Result<Self> {
+        if !path.trim().starts_with('$') {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid JSON path '{}'. A JSON path must start with '$'",
+                    path
+                ),
+            ));
+        }
+
+        Ok(Self {
+            key_path: KeyPath::parse(path, "JSON")?,
+        })
+    }
+
+    /// Selects the values at this path in the given JSON text.
+    ///
+    /// A string value is selected without its quotes, so that an edit keeps the value a string.
+    /// Its contents are still escaped as written, which `Escaping::JsonString` undoes.
+    /// Any other value is selected as written, from its first to its last byte.
+    /// Values within another selected value are left out, so the spans never overlap.
+    ///
+    /// # Returns
+    /// The start and end byte positions of each selected value, in the order they appear, and how it is escaped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the text is not valid JSON.
+    pub fn select(&self, text: &str) -> io::Result<Vec<(usize, usize, Escaping)>> {
+        let roots = [JsonParser::new(text).parse_document()?];
+        Ok(KeyPath::to_escaped_spans(&self.key_path.select(&roots)))
+    }
+}
+
+/// Decodes the escapes in the contents of a JSON string, written without its quotes.
+///
+/// # Errors
+///
+/// Returns an `InvalidData` error if the contents are not validly escaped.
+pub fn unescape_json_string(raw: &str) -> io::Result<String> {
+    let quoted = format!("\"{}\"", raw);
+    let mut parser = JsonParser::new(&quoted);
+    let value = parser.parse_string()?;
+    if parser.pos < quoted.len() {
+        return Err(parser.error("Unescaped quote"));
+    }
+    Ok(value)
+}
+
+/// Escapes a text to be the contents of a JSON string, written without its quotes.
+pub fn escape_json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            control if control < ' ' => escaped.push_str(&format!("\\u{:04x}", u32::from(control))),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+// Parses JSON text while keeping the byte span of every value.
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str) -> Self {
+        // A byte order mark is not part of the document
+        let pos = if text.starts_with('\u{feff}') { 3 } else { 0 };
+        Self { text, pos }
+    }
+
+    fn parse_document(&mut self) -> io::Result<Node> {
+        let root = self.parse_value()?;
+        self.skip_whitespace();
+        if self.pos < self.text.len() {
+            return Err(self.error("Expected the end of the document"));
+        }
+        Ok(root)
+    }
+
+    fn parse_value(&mut self) -> io::Result<Node> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some(b'{') => NodeKind::Mapping(self.parse_object()?),
+            Some(b'[') => NodeKind::Sequence(self.parse_array()?),
+            Some(b'"') => {
+                self.parse_string()?;
+                // The span of a string leaves out its quotes
+                let mut node = Node::new(start + 1, self.pos - 1, NodeKind::Scalar);
+                node.set_escaping(Escaping::JsonString);
+                return Ok(node);
+            }
+            Some(_) => {
+                self.parse_scalar()?;
+                NodeKind::Scalar
+            }
+            None => return Err(self.error("Expected a value")),
+        };
+        Ok(Node::new(start, self.pos, kind))
+    }
+
+    fn parse_object(&mut self) -> io::Result<Vec<(String, Node)>> {
+        let mut members = Vec::new();
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.eat(b'}') {
+            return Ok(members);
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("Expected a member name"));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            if !self.eat(b':') {
+                return Err(self.error("Expected ':'"));
+            }
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            if self.eat(b'}') {
+                return Ok(members);
+            }
+            if !self.eat(b',') {
+                return Err(self.error("Expected ',' or '}'"));
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> io::Result<Vec<Node>> {
+        let mut elements = Vec::new();
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.eat(b']') {
+            return Ok(elements);
+        }
+
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            if self.eat(b']') {
+                return Ok(elements);
+            }
+            if !self.eat(b',') {
+                return Err(self.error("Expected ',' or ']'"));
+            }
+        }
+    }
+
+    // Parses a string and returns its decoded value.
+    fn parse_string(&mut self) -> io::Result<String> {
+        let mut value = String::new();
+        self.pos += 1;
+
+        loop {
+            let rest = &self.text[self.pos..];
+            let Some(end) = rest.find(['"', '\\']) else {
+                return Err(self.error("Unterminated string"));
+            };
+            value.push_str(&rest[..end]);
+            self.pos += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(value);
+            }
+
+            let escaped = self
+                .peek()
+                .ok_or_else(|| self.error("Unterminated string"))?;
+            self.pos += 1;
+            match escaped {
+                b'"' => value.push('"'),
+                b'\\' => value.push('\\'),
+                b'/' => value.push('/'),
+                b'b' => value.push('\u{8}'),
+                b'f' => value.push('\u{c}'),
+                b'n' => value.push('\n'),
+                b'r' => value.push('\r'),
+                b't' => value.push('\t'),
+                b'u' => value.push(self.parse_unicode_escape()?),
+                _ => return Err(self.error("Invalid escape")),
+            }
+        }
+    }
+
+    // Parses the digits of a `\u` escape, joining a surrogate pair into one character.
+    fn parse_unicode_escape(&mut self) -> io::Result<char> {
+        let high = self.parse_hex()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error("Invalid escape"));
+        }
+
+        if !self.text[self.pos..].starts_with("\\u") {
+            return Err(self.error("Unpaired surrogate"));
+        }
+        self.pos += 2;
+        let low = self.parse_hex()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error("Unpaired surrogate"));
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or_else(|| self.error("Invalid escape"))
+    }
+
+    fn parse_hex(&mut self) -> io::Result<u32> {
+        let digits = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("Invalid escape"))?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn parse_scalar(&mut self) -> io::Result<()> {
+        let rest = &self.text[self.pos..];
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+            .unwrap_or(rest.len());
+        let token = &rest[..end];
+        let is_number = token.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+            && token.parse::<f64>().is_ok();
+        if !is_number && !matches!(token, "true" | "false" | "null") {
+            return Err(self.error("Expected a value"));
+        }
+        self.pos += end;
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    // Moves past the given byte if it is next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let is_next = self.peek() == Some(byte);
+        if is_next {
+            self.pos += 1;
+        }
+        is_next
+    }
+
+    fn error(&self, message: &str) -> io::Error {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid JSON at byte {}. {}", self.pos, message),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select<'a>(path: &str, text: &'a str) -> Vec<&'a str> {
+        JsonPath::parse(path)
+            .unwrap()
+            .select(text)
+            .unwrap()
+            .into_iter()
+            .map(|(start, end, _)| &text[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_rejects_invalid_paths() {
+        for path in ["a.b", "$.", "$[x]", "$a"] {
+            let error = JsonPath::parse(path).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_selects_strings_without_quotes_and_other_values_as_written() {
+        let text = "{\"name\": \"web\", \"port\": 8080, \"tags\": [ \"a\" ], \"on\": true}";
+        assert_eq!(select("$.name", text), vec!["web"]);
+        assert_eq!(select("$.port", text), vec!["8080"]);
+        assert_eq!(select("$.tags", text), vec!["[ \"a\" ]"]);
+        assert_eq!(
+            select("$.*", text),
+            vec!["web", "8080", "[ \"a\" ]", "true"]
+        );
+    }
+
+    #[test]
+    fn test_selects_array_elements() {
+        let text = "[1, 2, 3]";
+        assert_eq!(select("$[0]", text), vec!["1"]);
+        assert_eq!(select("$[-1]", text), vec!["3"]);
+        assert!(select("$[3]", text).is_empty());
+        assert_eq!(select("$[*]", text), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_selects_descendants_without_overlaps() {
+        let text = r#"{"a": {"id": 1, "b": {"id": 2}}, "id": {"id": 3}}"#;
+        assert_eq!(select("$..id", text), vec!["1", "2", r#"{"id": 3}"#]);
+    }
+
+    #[test]
+    fn test_decodes_escaped_member_names() {
+        let text = r#"{"caf\u00e9": "\"x\"", "\ud83d\ude00": 1}"#;
+        assert_eq!(select("$['café']", text), vec![r#"\"x\""#]);
+        assert_eq!(select("$['😀']", text), vec!["1"]);
+    }
+
+    #[test]
+    fn test_marks_only_strings_as_escaped() {
+        let json_path = JsonPath::parse("$.*").unwrap();
+        let escapings: Vec<Escaping> = json_path
+            .select(r#"{"a": "x", "b": 1, "c": ["y"]}"#)
+            .unwrap()
+            .into_iter()
+            .map(|(_, _, escaping)| escaping)
+            .collect();
+        assert_eq!(
+            escapings,
+            vec![Escaping::JsonString, Escaping::None, Escaping::None]
+        );
+    }
+
+    #[test]
+    fn test_unescapes_and_escapes_strings() {
+        let raw = r#"say \"hi\" \\ C:\\dir\u00e9\/"#;
+        let text = "say \"hi\" \\ C:\\dir\u{e9}/";
+        assert_eq!(unescape_json_string(raw).unwrap(), text);
+        assert_eq!(escape_json_string(text), r#"say \"hi\" \\ C:\\diré/"#);
+        assert_eq!(escape_json_string("a\nb\u{1}"), r"a\nb\u0001");
+        assert!(unescape_json_string(r#"a"b"#).is_err());
+        assert!(unescape_json_string(r"a\").is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_json() {
+        let json_path = JsonPath::parse("$").unwrap();
+        for text in ["", "{", "{\"a\" 1}", "[1,]", "\"abc", "nope", "1 2"] {
+            let error = json_path.select(text).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{}", text);
+        }
+    }
+}
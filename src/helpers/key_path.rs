@@ -0,0 +1,366 @@
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+
+/// A path of keys and indices, like `$.services[*].image`, that selects values in a parsed document.
+///
+/// The path may start with `$`, the document itself, and is followed by any of these segments:
+///
+/// * `.name` or `['name']` selects the member with that name.
+/// * `[0]` selects a sequence element. A negative index counts from the end.
+/// * `.*` or `[*]` selects every member or element.
+/// * `..name`, `..[0]` or `..*` selects the same, at any depth below.
+///
+/// Without the `$`, the first name needs no `.`, as in `spec.containers[0].image`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::key_path::{KeyPath, Node, NodeKind};
+///
+/// // The document `{"a": [1, 2]}`
+/// let roots = [Node::new(0, 13, NodeKind::Mapping(vec![(
+///     String::from("a"),
+///     Node::new(6, 12, NodeKind::Sequence(vec![
+///         Node::new(7, 8, NodeKind::Scalar),
+///         Node::new(10, 11, NodeKind::Scalar),
+///     ])),
+/// )]))];
+///
+/// let key_path = KeyPath::parse("a[-1]", "key").unwrap();
+/// let nodes = key_path.select(&roots);
+/// assert_eq!(KeyPath::to_spans(&nodes), vec![(10, 11)]);
+/// ```
+#[derive(Debug)]
+pub struct KeyPath {
+    segments: Vec<Segment>,
+}
+
+// A step of a path, applied to every value selected so far.
+#[derive(Debug, PartialEq)]
+enum Segment {
+    // Selects from the value itself
+    Child(Selector),
+    // Selects from the value and every value nested within it
+    Descendant(Selector),
+}
+
+// What a segment selects from a mapping or sequence.
+#[derive(Debug, PartialEq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+}
+
+/// A parsed value and the byte span it covers in the document's text.
+#[derive(Clone)]
+pub struct Node {
+    start: usize,
+    end: usize,
+    kind: NodeKind,
+    escaping: Escaping,
+}
+
+/// The shape of a parsed value.
+#[derive(Clone)]
+pub enum NodeKind {
+    // Members in the order they were written
+    Mapping(Vec<(String, Node)>),
+    Sequence(Vec<Node>),
+    // A value without members, like a string or a number
+    Scalar,
+}
+
+impl Node {
+    // Constructor for Node
+    pub fn new(start: usize, end: usize, kind: NodeKind) -> Self {
+        Self {
+            start,
+            end,
+            kind,
+            escaping: Escaping::None,
+        }
+    }
+
+    // Sets how the text of the node is escaped in the document.
+    pub fn set_escaping(&mut self, escaping: Escaping) {
+        self.escaping = escaping;
+    }
+
+    // Returns true if the node has no members or elements.
+    pub fn is_scalar(&self) -> bool {
+        matches!(self.kind, NodeKind::Scalar)
+    }
+}
+
+impl KeyPath {
+    /// Parses a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to parse.
+    /// * `format` - The name of the document format, used in error messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the path is not valid.
+    pub fn parse(path: &str, format: &str) -> io::Result<Self> {
+        let invalid = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid {} path '{}'. {}", format, path, message),
+            )
+        };
+        let trimmed = path.trim();
+        let (mut rest, mut needs_separator) = match trimmed.strip_prefix('$') {
+            Some(after_root) => (after_root, true),
+            None => (trimmed, false),
+        };
+        let mut segments = Vec::new();
+
+        while !rest.is_empty() {
+            let descendant = rest.starts_with("..");
+            if descendant {
+                rest = &rest[2..];
+            } else if let Some(after_dot) = rest.strip_prefix('.') {
+                rest = after_dot;
+            } else if needs_separator && !rest.starts_with('[') {
+                return Err(invalid("Expected '.' or '[' before each selector"));
+            }
+            needs_separator = true;
+
+            let (selector, after_selector) = if let Some(bracketed) = rest.strip_prefix('[') {
+                parse_bracketed(bracketed)
+                    .ok_or_else(|| invalid("Expected '[*]', '[0]' or ['name']"))?
+            } else {
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                match &rest[..end] {
+                    "" => return Err(invalid("Expected a name or '*' after '.'")),
+                    "*" => (Selector::Wildcard, &rest[end..]),
+                    name => (Selector::Name(name.to_string()), &rest[end..]),
+                }
+            };
+
+            segments.push(if descendant {
+                Segment::Descendant(selector)
+            } else {
+                Segment::Child(selector)
+            });
+            rest = after_selector;
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Selects the values at this path in each of the given documents.
+    ///
+    /// # Returns
+    /// The selected nodes, document by document.
+    pub fn select<'a>(&self, roots: &'a [Node]) -> Vec<&'a Node> {
+        let mut nodes: Vec<&Node> = roots.iter().collect();
+
+        for segment in &self.segments {
+            let mut selected = Vec::new();
+            for node in nodes {
+                match segment {
+                    Segment::Child(selector) => select_children(node, selector, &mut selected),
+                    Segment::Descendant(selector) => {
+                        select_descendants(node, selector, &mut selected)
+                    }
+                }
+            }
+            nodes = selected;
+        }
+
+        nodes
+    }
+
+    /// Returns the spans of the given nodes in the order they appear.
+    ///
+    /// Nodes within another given node are left out, so the spans never overlap.
+    pub fn to_spans(nodes: &[&Node]) -> Vec<(usize, usize)> {
+        Self::to_escaped_spans(nodes)
+            .into_iter()
+            .map(|(start, end, _)| (start, end))
+            .collect()
+    }
+
+    /// Returns the spans of the given nodes in the order they appear, with how each is escaped.
+    ///
+    /// Nodes within another given node are left out, as with `to_spans`.
+    pub fn to_escaped_spans(nodes: &[&Node]) -> Vec<(usize, usize, Escaping)> {
+        let mut spans: Vec<(usize, usize, Escaping)> = nodes
+            .iter()
+            .map(|node| (node.start, node.end, node.escaping))
+            .collect();
+        spans.sort_unstable_by_key(|&(start, end, _)| (start, end));
+        let mut covered_to = 0;
+        spans.retain(|&(start, end, _)| {
+            let is_outside = start >= covered_to;
+            if is_outside {
+                covered_to = end.max(start + 1);
+            }
+            is_outside
+        });
+        spans
+    }
+}
+
+// Parses the inside of a `[...]` selector, returning it and the rest of the path after the `]`.
+fn parse_bracketed(bracketed: &str) -> Option<(Selector, &str)> {
+    if let Some(quote) = bracketed.chars().next().filter(|c| *c == '\'' || *c == '"') {
+        let quoted = &bracketed[1..];
+        let end = quoted.find(quote)?;
+        let rest = quoted[end + 1..].strip_prefix(']')?;
+        return Some((Selector::Name(quoted[..end].to_string()), rest));
+    }
+
+    let end = bracketed.find(']')?;
+    let rest = &bracketed[end + 1..];
+    match bracketed[..end].trim() {
+        "*" => Some((Selector::Wildcard, rest)),
+        index => Some((Selector::Index(index.parse().ok()?), rest)),
+    }
+}
+
+// Adds the members or elements of the node that the selector selects.
+fn select_children<'a>(node: &'a Node, selector: &Selector, selected: &mut Vec<&'a Node>) {
+    match (&node.kind, selector) {
+        (NodeKind::Mapping(members), Selector::Name(name)) => selected.extend(
+            members
+                .iter()
+                .filter(|(key, _)| key == name)
+                .map(|(_, value)| value),
+        ),
+        (NodeKind::Mapping(members), Selector::Wildcard) => {
+            selected.extend(members.iter().map(|(_, value)| value))
+        }
+        (NodeKind::Sequence(elements), Selector::Index(index)) => {
+            let index = if *index < 0 {
+                elements.len() as i64 + index
+            } else {
+                *index
+            };
+            if let Some(element) = usize::try_from(index).ok().and_then(|i| elements.get(i)) {
+                selected.push(element);
+            }
+        }
+        (NodeKind::Sequence(elements), Selector::Wildcard) => selected.extend(elements.iter()),
+        _ => {}
+    }
+}
+
+// Applies the selector to the node and to every node nested within it.
+fn select_descendants<'a>(node: &'a Node, selector: &Selector, selected: &mut Vec<&'a Node>) {
+    select_children(node, selector, selected);
+    match &node.kind {
+        NodeKind::Mapping(members) => {
+            for (_, value) in members {
+                select_descendants(value, selector, selected);
+            }
+        }
+        NodeKind::Sequence(elements) => {
+            for element in elements {
+                select_descendants(element, selector, selected);
+            }
+        }
+        NodeKind::Scalar => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The document `{"a": {"id": 1}, "id": [2, 3]}`, with made up spans.
+    fn get_document() -> Node {
+        Node::new(
+            0,
+            100,
+            NodeKind::Mapping(vec![
+                (
+                    String::from("a"),
+                    Node::new(
+                        10,
+                        20,
+                        NodeKind::Mapping(vec![(
+                            String::from("id"),
+                            Node::new(15, 16, NodeKind::Scalar),
+                        )]),
+                    ),
+                ),
+                (
+                    String::from("id"),
+                    Node::new(
+                        30,
+                        40,
+                        NodeKind::Sequence(vec![
+                            Node::new(31, 32, NodeKind::Scalar),
+                            Node::new(34, 35, NodeKind::Scalar),
+                        ]),
+                    ),
+                ),
+            ]),
+        )
+    }
+
+    fn select(path: &str) -> Vec<(usize, usize)> {
+        let roots = [get_document()];
+        KeyPath::to_spans(&KeyPath::parse(path, "key").unwrap().select(&roots))
+    }
+
+    #[test]
+    fn test_parses_segments() {
+        let key_path = KeyPath::parse("$.a['b.c'][*]..d[-1].*", "key").unwrap();
+        assert_eq!(
+            key_path.segments,
+            vec![
+                Segment::Child(Selector::Name(String::from("a"))),
+                Segment::Child(Selector::Name(String::from("b.c"))),
+                Segment::Child(Selector::Wildcard),
+                Segment::Descendant(Selector::Name(String::from("d"))),
+                Segment::Child(Selector::Index(-1)),
+                Segment::Child(Selector::Wildcard),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_first_name_needs_no_dot_without_the_root() {
+        let key_path = KeyPath::parse("a.b[0]", "key").unwrap();
+        assert_eq!(
+            key_path.segments,
+            vec![
+                Segment::Child(Selector::Name(String::from("a"))),
+                Segment::Child(Selector::Name(String::from("b"))),
+                Segment::Child(Selector::Index(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_paths() {
+        for path in ["$a", "$.", "a..", "$[", "$[x]", "$['a'"] {
+            let error = KeyPath::parse(path, "key").unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", path);
+            assert!(error.to_string().starts_with("Invalid key path"));
+        }
+    }
+
+    #[test]
+    fn test_selects_members_and_elements() {
+        assert_eq!(select("$.a.id"), vec![(15, 16)]);
+        assert_eq!(select("id[0]"), vec![(31, 32)]);
+        assert_eq!(select("id[-1]"), vec![(34, 35)]);
+        assert!(select("id[2]").is_empty());
+        assert_eq!(select("id[*]"), vec![(31, 32), (34, 35)]);
+        assert_eq!(select("*"), vec![(10, 20), (30, 40)]);
+        assert_eq!(select("$"), vec![(0, 100)]);
+    }
+
+    #[test]
+    fn test_selects_descendants_without_overlaps() {
+        assert_eq!(select("$..id"), vec![(15, 16), (30, 40)]);
+        assert_eq!(select("$..[0]"), vec![(31, 32)]);
+    }
+}
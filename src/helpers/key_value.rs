@@ -0,0 +1,560 @@
+use crate::strategies::enums::escaping::Escaping;
+use crate::strategies::enums::kv_dialect::KvDialect;
+use std::io;
+
+/// Finds the values of a key in `.env`, INI, or `.properties` text, read as the given dialect.
+///
+/// In `.env` and INI text, each line holds a `key=value` or `key: value` pair, a `[section]` header,
+/// or a comment starting with `#`, `;`, or `!`. A pair in a `.env` file may start with `export`.
+/// Keys match exactly, and pairs in comments never match.
+///
+/// A value in single or double quotes spans the text inside the quotes, where `\"` does not end a double quoted value.
+/// Any other value ends before a `#` or `;` that follows whitespace, which starts a comment,
+/// and leaves out the whitespace around it. An empty value spans nothing, just after the `=` or `:`.
+///
+/// With a section, only the pairs under that section's header match.
+/// Without one, only the pairs before the first header match, as in `.env` files, which have no sections.
+///
+/// `.properties` text is read as Java reads it, as described for `find_properties_values`.
+/// It has no sections, so nothing matches within one.
+///
+/// # Returns
+/// The start and end byte positions of each value, in the order they appear, and how the value is quoted.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::key_value::find_values;
+/// use seer::strategies::enums::kv_dialect::KvDialect;
+///
+/// let text = "# PORT=80\nexport PORT=\"8080\" # web\n[db]\nPORT = 5432\n";
+/// let values: Vec<&str> = find_values(text, "PORT", None, KvDialect::Ini)
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+/// assert_eq!(values, vec!["8080"]);
+///
+/// let (start, end, _) = find_values(text, "PORT", Some("db"), KvDialect::Ini)[0];
+/// assert_eq!(&text[start..end], "5432");
+/// ```
+pub fn find_values(
+    text: &str,
+    key: &str,
+    section: Option<&str>,
+    dialect: KvDialect,
+) -> Vec<(usize, usize, Escaping)> {
+    if dialect == KvDialect::Properties {
+        return match section {
+            Some(_) => Vec::new(),
+            None => find_properties_values(text, key),
+        };
+    }
+
+    let mut spans = Vec::new();
+    let mut current_section = None;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let start = line_start;
+        line_start += line.len();
+
+        let content = line.trim_end_matches(['\n', '\r']);
+        let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+        if trimmed.is_empty() || trimmed.starts_with(['#', ';', '!']) {
+            continue;
+        } else if let Some(header) = trimmed.strip_prefix('[') {
+            if let Some(end) = header.find(']') {
+                current_section = Some(header[..end].trim());
+            }
+            continue;
+        }
+
+        let Some(separator) = content.find(['=', ':']) else {
+            continue;
+        };
+        let name = content[..separator].trim_start_matches('\u{feff}').trim();
+        let name = name
+            .strip_prefix("export")
+            .filter(|rest| rest.starts_with([' ', '\t']))
+            .map_or(name, str::trim_start);
+        if name != key || current_section != section {
+            continue;
+        }
+
+        let value_start = start + separator + 1;
+        let (value_offset, value_end, escaping) = find_value(&content[separator + 1..]);
+        spans.push((
+            value_start + value_offset,
+            value_start + value_end,
+            escaping,
+        ));
+    }
+
+    spans
+}
+
+/// Finds the values of a key in `.properties` text, as Java reads it.
+///
+/// Each logical line holds a pair or a comment starting with `#` or `!`. A line ending in an odd number
+/// of backslashes goes on over the next line, without that line's leading whitespace, unless it is a comment.
+/// The key ends at the first `=`, `:`, or whitespace not escaped with a backslash, so `a\:b` is the key `a:b`.
+/// Whitespace and one `=` or `:` after it separate the key from the value.
+///
+/// A value spans the rest of its logical line, with any whitespace at its end. A `#` or `!` in it
+/// is part of it, since comments only start lines, and its escapes are undone as `KvProperties`.
+///
+/// # Returns
+/// The start and end byte positions of each value, in the order they appear, and `Escaping::KvProperties`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::key_value::find_properties_values;
+///
+/// let text = "# url=old\ndb.url jdbc:h2:mem#main \\\n    ;DB_CLOSE_DELAY=-1\n";
+/// let (start, end, _) = find_properties_values(text, "db.url")[0];
+/// assert_eq!(&text[start..end], "jdbc:h2:mem#main \\\n    ;DB_CLOSE_DELAY=-1");
+/// ```
+pub fn find_properties_values(text: &str, key: &str) -> Vec<(usize, usize, Escaping)> {
+    let mut spans = Vec::new();
+    let mut line_start = 0;
+
+    while line_start < text.len() {
+        let start = line_start;
+        let physical_end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+        let trimmed = text[start..physical_end]
+            .trim_start_matches('\u{feff}')
+            .trim_start_matches(PROPERTIES_WHITESPACE);
+        // A blank line or comment does not go on over the next line, even if it ends in a backslash
+        if trimmed.trim_end_matches('\r').is_empty() || trimmed.starts_with(['#', '!']) {
+            line_start = physical_end + 1;
+            continue;
+        }
+
+        let (end, next_start) = find_logical_line_end(text, start);
+        line_start = next_start;
+        let content = &text[physical_end - trimmed.len()..end];
+
+        let key_end = find_properties_key_end(content);
+        let name = &content[..key_end];
+        let after_key = &content[key_end..];
+        let mut value = after_key.trim_start_matches(PROPERTIES_WHITESPACE);
+        if let Some(rest) = value.strip_prefix(['=', ':']) {
+            value = rest.trim_start_matches(PROPERTIES_WHITESPACE);
+        }
+        if unescape_properties(name) != key {
+            continue;
+        }
+
+        let value_start = end - value.len();
+        spans.push((value_start, end, Escaping::KvProperties));
+    }
+
+    spans
+}
+
+/// Undoes the escaping of a value as it is written in its quotes.
+///
+/// In double quotes, `\"`, `\\`, and `\n` are a quote, a backslash, and a line break.
+/// A `.properties` value has its backslash escapes undone and its continued lines joined.
+/// Any other value is its own text.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::key_value::unescape_kv_value;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(unescape_kv_value(r#"say \"hi\""#, Escaping::KvDoubleQuoted), r#"say "hi""#);
+/// assert_eq!(unescape_kv_value(r#"say \"hi\""#, Escaping::KvPlain), r#"say \"hi\""#);
+/// ```
+pub fn unescape_kv_value(raw: &str, escaping: Escaping) -> String {
+    if escaping == Escaping::KvProperties {
+        return unescape_properties(raw);
+    } else if escaping != Escaping::KvDoubleQuoted {
+        return raw.to_string();
+    }
+
+    let mut unescaped = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(next @ ('"' | '\\'))) => {
+                unescaped.push(next);
+                chars.next();
+            }
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Escapes a value so it can be written where a value with the given quoting was.
+///
+/// A value without quotes is put in double quotes if it holds whitespace, `#`, or `;`,
+/// or starts with a quote, so that none of it is taken for a comment or lost.
+/// In double quotes, quotes, backslashes, and line breaks are escaped.
+/// A `.properties` value is written without quotes, with backslash escapes for backslashes,
+/// line breaks, tabs, other control characters, and leading whitespace.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error for a quote or line break in a single quoted value,
+/// since single quotes have no escapes.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::key_value::escape_kv_value;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(escape_kv_value("8080", Escaping::KvPlain).unwrap(), "8080");
+/// assert_eq!(escape_kv_value("a #b", Escaping::KvPlain).unwrap(), "\"a #b\"");
+/// assert!(escape_kv_value("it's", Escaping::KvSingleQuoted).is_err());
+/// assert_eq!(escape_kv_value(" a #b", Escaping::KvProperties).unwrap(), r"\ a #b");
+/// ```
+pub fn escape_kv_value(text: &str, escaping: Escaping) -> io::Result<String> {
+    match escaping {
+        Escaping::KvSingleQuoted if text.contains(['\'', '\n', '\r']) => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{}' cannot be written in a single quoted value", text),
+        )),
+        Escaping::KvPlain
+            if text.contains(|c: char| c.is_whitespace() || matches!(c, '#' | ';'))
+                || text.starts_with(['"', '\'']) =>
+        {
+            Ok(format!(
+                "\"{}\"",
+                escape_kv_value(text, Escaping::KvDoubleQuoted)?
+            ))
+        }
+        Escaping::KvDoubleQuoted => Ok(text
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")),
+        Escaping::KvProperties => Ok(escape_properties(text)),
+        _ => Ok(text.to_string()),
+    }
+}
+
+// The whitespace that separates the parts of a `.properties` line.
+const PROPERTIES_WHITESPACE: [char; 3] = [' ', '\t', '\u{c}'];
+
+// Returns where the logical `.properties` line starting at `start` ends, before its line break,
+// and where the next one starts.
+fn find_logical_line_end(text: &str, start: usize) -> (usize, usize) {
+    let mut line_start = start;
+    loop {
+        let Some(newline) = text[line_start..].find('\n').map(|i| line_start + i) else {
+            return (text.len(), text.len());
+        };
+        let line = text[line_start..newline].trim_end_matches('\r');
+        let backslashes = line.len() - line.trim_end_matches('\\').len();
+        if backslashes.is_multiple_of(2) {
+            return (line_start + line.len(), newline + 1);
+        }
+        line_start = newline + 1;
+    }
+}
+
+// Returns where a `.properties` key ends, at the first `=`, `:`, or whitespace not escaped with a backslash.
+fn find_properties_key_end(content: &str) -> usize {
+    let mut is_escaped = false;
+    for (i, c) in content.char_indices() {
+        if is_escaped {
+            is_escaped = false;
+        } else if c == '\\' {
+            is_escaped = true;
+        } else if matches!(c, '=' | ':' | '\r' | '\n') || PROPERTIES_WHITESPACE.contains(&c) {
+            return i;
+        }
+    }
+    content.len()
+}
+
+// Undoes the backslash escapes in a `.properties` key or value, and joins its continued lines.
+fn unescape_properties(raw: &str) -> String {
+    let mut unescaped = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('f') => unescaped.push('\u{c}'),
+            Some('u') => {
+                let hex: String = chars.clone().take(4).collect();
+                let decoded = Some(&hex)
+                    .filter(|hex| hex.len() == 4 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                    .and_then(|hex| char::from_u32(u32::from_str_radix(hex, 16).ok()?));
+                match decoded {
+                    Some(decoded) => {
+                        unescaped.push(decoded);
+                        chars.nth(3);
+                    }
+                    // Java refuses a malformed escape, so it is kept as written
+                    _ => unescaped.push_str("\\u"),
+                }
+            }
+            Some(line_break @ ('\r' | '\n')) => {
+                if line_break == '\r' {
+                    chars.next_if_eq(&'\n');
+                }
+                while chars
+                    .next_if(|c| PROPERTIES_WHITESPACE.contains(c))
+                    .is_some()
+                {}
+            }
+            Some(next) => unescaped.push(next),
+            None => {}
+        }
+    }
+    unescaped
+}
+
+// Escapes a `.properties` value, so it is read back as the same text.
+fn escape_properties(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{c}' => escaped.push_str("\\f"),
+            // Whitespace at the start would be taken for the separator
+            ' ' if i == 0 => escaped.push_str("\\ "),
+            control if control.is_control() => {
+                escaped.push_str(&format!("\\u{:04X}", u32::from(control)))
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Returns the span of the value in the text after a separator, without its quotes, comment, or whitespace,
+// and how it is quoted.
+fn find_value(text: &str) -> (usize, usize, Escaping) {
+    let leading = text.len() - text.trim_start().len();
+    let value = &text[leading..];
+
+    if let Some(quote @ ('"' | '\'')) = value.chars().next() {
+        if let Some(end) = find_closing_quote(&value[1..], quote) {
+            let escaping = if quote == '"' {
+                Escaping::KvDoubleQuoted
+            } else {
+                Escaping::KvSingleQuoted
+            };
+            return (leading + 1, leading + 1 + end, escaping);
+        }
+    }
+
+    let end = text
+        .char_indices()
+        .find(|&(i, c)| matches!(c, '#' | ';') && text[..i].ends_with([' ', '\t']))
+        .map_or(text.len(), |(i, _)| i);
+    if leading >= end {
+        // An edit just after the separator keeps the whitespace before any comment
+        return (0, 0, Escaping::KvPlain);
+    }
+    (leading, text[..end].trim_end().len(), Escaping::KvPlain)
+}
+
+// Returns the position of the quote that closes a quoted value.
+fn find_closing_quote(text: &str, quote: char) -> Option<usize> {
+    let mut is_escaped = false;
+    for (i, c) in text.char_indices() {
+        if is_escaped {
+            is_escaped = false;
+        } else if c == '\\' && quote == '"' {
+            is_escaped = true;
+        } else if c == quote {
+            return Some(i);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_values<'a>(text: &'a str, key: &str, section: Option<&str>) -> Vec<&'a str> {
+        find_values(text, key, section, KvDialect::Ini)
+            .into_iter()
+            .map(|(start, end, _)| &text[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_finds_env_values() {
+        let text = "\u{feff}API_URL=https://old.example#v1\r\n# API_URL=commented\r\n  export API_URL = 'https://a.example' # note\r\nAPI_URL_2=x\r\n";
+        assert_eq!(
+            get_values(text, "API_URL", None),
+            vec!["https://old.example#v1", "https://a.example"]
+        );
+    }
+
+    #[test]
+    fn test_handles_quotes_and_inline_comments() {
+        let text = "A=\"say \\\"hi\\\"\" # c\nB=unclosed \"quote ; c\nC='it''s'\nD=x;y z\n";
+        assert_eq!(get_values(text, "A", None), vec!["say \\\"hi\\\""]);
+        assert_eq!(get_values(text, "B", None), vec!["unclosed \"quote"]);
+        assert_eq!(get_values(text, "C", None), vec!["it"]);
+        assert_eq!(get_values(text, "D", None), vec!["x;y z"]);
+    }
+
+    #[test]
+    fn test_finds_values_within_a_section() {
+        let text = "port=1\n[server]\nport = 8080\n[ database ] ; main\nport=5432\n";
+        assert_eq!(get_values(text, "port", None), vec!["1"]);
+        assert_eq!(get_values(text, "port", Some("database")), vec!["5432"]);
+        assert!(get_values(text, "port", Some("cache")).is_empty());
+    }
+
+    #[test]
+    fn test_empty_values_span_nothing_after_the_separator() {
+        let text = "A=\nB=   # unset\n";
+        assert_eq!(
+            find_values(text, "A", None, KvDialect::Ini),
+            vec![(2, 2, Escaping::KvPlain)]
+        );
+        assert_eq!(
+            find_values(text, "B", None, KvDialect::Ini),
+            vec![(5, 5, Escaping::KvPlain)]
+        );
+    }
+
+    #[test]
+    fn test_finds_how_values_are_quoted() {
+        let text = "A=1\nB='2'\nC=\"3\"\n";
+        let escapings: Vec<Escaping> = ["A", "B", "C"]
+            .iter()
+            .flat_map(|key| find_values(text, key, None, KvDialect::Ini))
+            .map(|(_, _, escaping)| escaping)
+            .collect();
+        assert_eq!(
+            escapings,
+            vec![
+                Escaping::KvPlain,
+                Escaping::KvSingleQuoted,
+                Escaping::KvDoubleQuoted
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escapes_values_where_they_are_written() {
+        assert_eq!(
+            escape_kv_value("a;b", Escaping::KvPlain).unwrap(),
+            "\"a;b\""
+        );
+        assert_eq!(
+            escape_kv_value("x\"y\"", Escaping::KvPlain).unwrap(),
+            "x\"y\""
+        );
+        assert_eq!(
+            escape_kv_value("'x' y", Escaping::KvPlain).unwrap(),
+            "\"'x' y\""
+        );
+        assert_eq!(
+            escape_kv_value("a\\\"b\"\nc", Escaping::KvDoubleQuoted).unwrap(),
+            "a\\\\\\\"b\\\"\\nc"
+        );
+        assert_eq!(
+            escape_kv_value("a #b", Escaping::KvSingleQuoted).unwrap(),
+            "a #b"
+        );
+
+        let value = "a\\\"b\"\nc";
+        let escaped = escape_kv_value(value, Escaping::KvDoubleQuoted).unwrap();
+        assert_eq!(unescape_kv_value(&escaped, Escaping::KvDoubleQuoted), value);
+    }
+
+    fn get_properties_values(text: &str, key: &str) -> Vec<String> {
+        find_properties_values(text, key)
+            .into_iter()
+            .map(|(start, end, escaping)| unescape_kv_value(&text[start..end], escaping))
+            .collect()
+    }
+
+    #[test]
+    fn test_properties_values_have_no_inline_comments() {
+        let text = "! comment\n# url=old\nurl=jdbc:h2:mem # main ; db \nchannel=#general\n";
+        assert_eq!(
+            get_properties_values(text, "url"),
+            vec!["jdbc:h2:mem # main ; db "]
+        );
+        assert_eq!(get_properties_values(text, "channel"), vec!["#general"]);
+    }
+
+    #[test]
+    fn test_joins_continued_properties_lines() {
+        let text = "fruits = apple, \\\r\n    banana,\\\n\tcherry\npath=C:\\\\dir\\\\\nnext=1\n# a \\\nhidden=2\n";
+        assert_eq!(
+            get_properties_values(text, "fruits"),
+            vec!["apple, banana,cherry"]
+        );
+        assert_eq!(get_properties_values(text, "path"), vec!["C:\\dir\\"]);
+        assert_eq!(get_properties_values(text, "next"), vec!["1"]);
+        assert_eq!(get_properties_values(text, "hidden"), vec!["2"]);
+    }
+
+    #[test]
+    fn test_separates_properties_keys_with_whitespace() {
+        let text = "a 1\nb\t: 2\nc  =  3\nd= =4\ne\nf:\n";
+        assert_eq!(get_properties_values(text, "a"), vec!["1"]);
+        assert_eq!(get_properties_values(text, "b"), vec!["2"]);
+        assert_eq!(get_properties_values(text, "c"), vec!["3"]);
+        assert_eq!(get_properties_values(text, "d"), vec!["=4"]);
+        assert_eq!(get_properties_values(text, "e"), vec![""]);
+        assert_eq!(get_properties_values(text, "f"), vec![""]);
+    }
+
+    #[test]
+    fn test_finds_values_of_escaped_properties_keys() {
+        let text = "K\\:ey=1\nK=2\nmy\\ key\\=x : 3\ncaf\\u00e9=4\n";
+        assert_eq!(get_properties_values(text, "K:ey"), vec!["1"]);
+        assert_eq!(get_properties_values(text, "K"), vec!["2"]);
+        assert_eq!(get_properties_values(text, "my key=x"), vec!["3"]);
+        assert_eq!(get_properties_values(text, "café"), vec!["4"]);
+    }
+
+    #[test]
+    fn test_properties_have_no_sections() {
+        let text = "[db]\nport=1\n";
+        assert_eq!(get_properties_values(text, "port"), vec!["1"]);
+        assert_eq!(get_properties_values(text, "[db]"), vec![""]);
+        assert!(find_values(text, "port", Some("db"), KvDialect::Properties).is_empty());
+    }
+
+    #[test]
+    fn test_unescapes_properties_values() {
+        let unescape = |raw| unescape_kv_value(raw, Escaping::KvProperties);
+        assert_eq!(unescape(r"a\tb\nc\\d\:e\u00e9"), "a\tb\nc\\d:eé");
+        assert_eq!(unescape(r"\u00zz"), r"\u00zz");
+        assert_eq!(unescape("x\"y'"), "x\"y'");
+    }
+
+    #[test]
+    fn test_escapes_properties_values_without_quotes() {
+        let escape = |text| escape_kv_value(text, Escaping::KvProperties).unwrap();
+        assert_eq!(escape("a #b; \"c\""), "a #b; \"c\"");
+        assert_eq!(escape("C:\\dir\nnext\t"), r"C:\\dir\nnext\t");
+        assert_eq!(escape("  lead\u{1}"), r"\  lead\u0001");
+
+        for value in ["  a\\b\r\n#c ", "é=:!", ""] {
+            let text = format!("k={}\n", escape(value));
+            assert_eq!(get_properties_values(&text, "k"), vec![value]);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::dto::hit::Hit;
+
+/// Returns the start and end byte positions of each line in the text.
+///
+/// A line ends before its `\n` or `\r\n` terminator, so the spans never include it.
+/// A final terminator does not start an empty last line, as with `str::lines`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::lines::line_spans;
+///
+/// assert_eq!(line_spans("one\r\ntwo\n"), vec![(0, 3), (5, 8)]);
+/// ```
+pub fn line_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line
+            .strip_suffix('\n')
+            .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line));
+        spans.push((start, start + content.len()));
+        start += line.len();
+    }
+
+    spans
+}
+
+/// Returns the spans of the lines that no hit touches.
+///
+/// A hit touches a line if it covers any of the line's bytes, including its terminator.
+/// An empty hit touches the line it is positioned in.
+///
+/// # Example
+///
+/// ```
+/// use seer::dto::hit::Hit;
+/// use seer::helpers::lines::lines_without_hits;
+///
+/// let hits = vec![Hit::new(4, 1)];
+/// assert_eq!(lines_without_hits("one\ntwo\nsix", &hits), vec![(0, 3), (8, 11)]);
+/// ```
+pub fn lines_without_hits(text: &str, hits: &[Hit]) -> Vec<(usize, usize)> {
+    let spans = line_spans(text);
+    let mut touched = vec![false; spans.len()];
+
+    for hit in hits {
+        let hit_start = hit.get_position();
+        let hit_end = hit_start + hit.get_length().max(1);
+
+        // The first line whose extent ends after the hit starts
+        let first = spans.partition_point(|&(start, _)| start <= hit_start);
+        let mut line = first.saturating_sub(1);
+
+        while line < spans.len() && spans[line].0 < hit_end {
+            touched[line] = true;
+            line += 1;
+        }
+    }
+
+    spans
+        .into_iter()
+        .zip(touched)
+        .filter(|(_, touched)| !touched)
+        .map(|(span, _)| span)
+        .collect()
+}
+
+/// Returns the line ending used by most lines of the text.
+///
+/// Ties, and texts without any line ending, use `\n`.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::lines::dominant_line_ending;
+///
+/// assert_eq!(dominant_line_ending("one\r\ntwo\r\nthree\n"), "\r\n");
+/// assert_eq!(dominant_line_ending("one"), "\n");
+/// ```
+pub fn dominant_line_ending(text: &str) -> &'static str {
+    let line_feeds = text.matches('\n').count();
+    let crlfs = text.matches("\r\n").count();
+
+    if crlfs > line_feeds - crlfs {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+/// Returns the text with every `\n` or `\r\n` line ending replaced by the given one.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::lines::with_line_ending;
+///
+/// assert_eq!(with_line_ending("one\ntwo\r\n", "\r\n"), "one\r\ntwo\r\n");
+/// assert_eq!(with_line_ending("one\ntwo\r\n", "\n"), "one\ntwo\n");
+/// ```
+pub fn with_line_ending(text: &str, line_ending: &str) -> String {
+    let mut converted = String::with_capacity(text.len());
+
+    for line in text.split_inclusive('\n') {
+        match line.strip_suffix('\n') {
+            Some(content) => {
+                converted.push_str(content.strip_suffix('\r').unwrap_or(content));
+                converted.push_str(line_ending);
+            }
+            None => converted.push_str(line),
+        }
+    }
+
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_spans_exclude_terminators() {
+        let text = "one\ntwo\r\nthree";
+        let spans = line_spans(text);
+
+        assert_eq!(spans, vec![(0, 3), (4, 7), (9, 14)]);
+        assert_eq!(&text[spans[2].0..spans[2].1], "three");
+    }
+
+    #[test]
+    fn test_line_spans_keep_empty_lines() {
+        assert_eq!(line_spans("\n\nx\n"), vec![(0, 0), (1, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_line_spans_of_empty_text() {
+        assert!(line_spans("").is_empty());
+    }
+
+    #[test]
+    fn test_lines_without_hits_skips_every_touched_line() {
+        let text = "a\nb\nc\nd\ne";
+        let hits = vec![Hit::new(0, 1), Hit::new(3, 3)];
+
+        assert_eq!(lines_without_hits(text, &hits), vec![(6, 7), (8, 9)]);
+    }
+
+    #[test]
+    fn test_lines_without_hits_counts_terminators_and_empty_hits() {
+        let text = "a\nb\nc";
+        let hits = vec![Hit::new(1, 1), Hit::new(2, 0)];
+
+        assert_eq!(lines_without_hits(text, &hits), vec![(4, 5)]);
+    }
+
+    #[test]
+    fn test_lines_without_hits_keeps_every_line_without_hits() {
+        assert_eq!(
+            lines_without_hits("a\n\nb", &[]),
+            vec![(0, 1), (2, 2), (3, 4)]
+        );
+    }
+
+    #[test]
+    fn test_dominant_line_ending_counts_lines() {
+        assert_eq!(dominant_line_ending("a\r\nb\nc\r\n"), "\r\n");
+        assert_eq!(dominant_line_ending("a\r\nb\nc\n"), "\n");
+        assert_eq!(dominant_line_ending("a\r\nb\n"), "\n");
+    }
+
+    #[test]
+    fn test_with_line_ending_keeps_lone_carriage_returns() {
+        assert_eq!(with_line_ending("a\rb\nc", "\r\n"), "a\rb\r\nc");
+    }
+}
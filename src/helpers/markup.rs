@@ -0,0 +1,639 @@
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+
+// Elements whose start tag is their whole element in an HTML document.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+// Elements whose content is text, never tags, in an HTML document.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// An element of an XML or HTML document, with the byte spans needed to edit it in place.
+#[derive(Debug)]
+pub struct Element {
+    name: String,
+    // The position of the `<` that opens the start tag
+    start: usize,
+    attributes: Vec<Attribute>,
+    // The span between the end of the start tag and the start of the end tag
+    content: (usize, usize),
+    // Whether the content is text that is never decoded, as in an HTML `<script>`
+    raw_text: bool,
+    children: Vec<Element>,
+}
+
+/// An attribute of an element.
+#[derive(Debug)]
+pub struct Attribute {
+    name: String,
+    // The value with character references decoded
+    value: String,
+    // The span of the value as written, without its quotes, or `None` for an attribute without a value
+    span: Option<(usize, usize)>,
+    // The quote around the value, if any
+    quote: Option<char>,
+}
+
+impl Element {
+    // Returns the element's name, in lowercase in an HTML document.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // Returns the position of the start tag.
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    // Returns the attribute with the given name, if any.
+    pub fn get_attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    // Returns the element's attributes in the order they were written.
+    pub fn get_attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    // Returns the elements directly within this one.
+    pub fn get_children(&self) -> &[Element] {
+        &self.children
+    }
+
+    /// Returns this element and every element within it, in document order.
+    pub fn get_descendants(&self) -> Vec<&Element> {
+        let mut descendants = vec![self];
+        for child in &self.children {
+            descendants.extend(child.get_descendants());
+        }
+        descendants
+    }
+
+    /// Returns the span of the element's content without the whitespace around it, and how the content is escaped.
+    ///
+    /// Content that is only a CDATA section spans the text inside it.
+    /// Content with child elements, or the raw text of an HTML `<script>` or `<style>`, is not escaped.
+    pub fn get_text_span(&self, text: &str) -> (usize, usize, Escaping) {
+        let (start, end, is_cdata) = self.get_trimmed_content(text);
+        let escaping = if is_cdata {
+            Escaping::XmlCData
+        } else if self.raw_text || !self.children.is_empty() {
+            Escaping::None
+        } else {
+            Escaping::XmlText
+        };
+        (start, end, escaping)
+    }
+
+    /// Returns the element's text with character references decoded, or `None` if it has child elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seer::helpers::markup::parse_markup;
+    ///
+    /// let text = "<a><b> x &amp; y </b></a>";
+    /// let root = parse_markup(text);
+    /// let a = &root.get_children()[0];
+    ///
+    /// assert_eq!(a.get_text(text), None);
+    /// assert_eq!(a.get_children()[0].get_text(text).unwrap(), "x & y");
+    /// ```
+    pub fn get_text(&self, text: &str) -> Option<String> {
+        if !self.children.is_empty() {
+            return None;
+        }
+
+        let (start, end, is_cdata) = self.get_trimmed_content(text);
+        if is_cdata || self.raw_text {
+            Some(text[start..end].to_string())
+        } else {
+            Some(decode_references(&text[start..end]))
+        }
+    }
+
+    // Returns the span of the trimmed content and whether it is the inside of a CDATA section.
+    fn get_trimmed_content(&self, text: &str) -> (usize, usize, bool) {
+        let content = &text[self.content.0..self.content.1];
+        let start = self.content.0 + content.len() - content.trim_start().len();
+        let end = start + content.trim().len();
+        let trimmed = &text[start..end];
+
+        let is_cdata = self.children.is_empty()
+            && trimmed.len() >= "<![CDATA[]]>".len()
+            && trimmed.starts_with("<![CDATA[")
+            && trimmed.ends_with("]]>");
+        if is_cdata {
+            (start + "<![CDATA[".len(), end - "]]>".len(), true)
+        } else {
+            (start, end, false)
+        }
+    }
+}
+
+impl Attribute {
+    // Returns the attribute's name, in lowercase in an HTML document.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // Returns the attribute's value with character references decoded.
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
+    // Returns the span of the value as written, or `None` if the attribute has no value.
+    pub fn get_span(&self) -> Option<(usize, usize)> {
+        self.span
+    }
+
+    // Returns how the value is escaped where it is written.
+    pub fn get_escaping(&self) -> Escaping {
+        Escaping::XmlAttribute(self.quote)
+    }
+}
+
+/// Parses an XML or HTML document into a tree of elements.
+///
+/// The returned element stands for the document itself. It has no name and its children are the top level elements.
+///
+/// Parsing never fails, so that a hand edited file or an HTML page can be searched:
+/// an end tag closes every element opened since the element it names, an end tag that names no open element is skipped,
+/// and elements still open at the end of the text end there.
+/// Comments, processing instructions, and declarations are skipped.
+///
+/// A document that starts with `<!DOCTYPE html` or `<html` is HTML.
+/// Its element and attribute names are lowercased, void elements like `<br>` need no end tag,
+/// and the content of `<script>` and `<style>` is text.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::markup::parse_markup;
+///
+/// let text = r#"<?xml version="1.0"?><project><version>1.2</version></project>"#;
+/// let root = parse_markup(text);
+/// let version = &root.get_children()[0].get_children()[0];
+///
+/// assert_eq!(version.get_name(), "version");
+/// let (start, end, _) = version.get_text_span(text);
+/// assert_eq!(&text[start..end], "1.2");
+/// ```
+pub fn parse_markup(text: &str) -> Element {
+    let is_html = is_html(text);
+    let mut stack = vec![Element {
+        name: String::new(),
+        start: 0,
+        attributes: Vec::new(),
+        content: (0, text.len()),
+        raw_text: false,
+        children: Vec::new(),
+    }];
+    let mut pos = 0;
+
+    while let Some(found) = text[pos..].find('<') {
+        let tag_start = pos + found;
+        let rest = &text[tag_start..];
+
+        if rest.starts_with("<!--") {
+            pos = find_end(text, tag_start, "-->");
+        } else if rest.starts_with("<![CDATA[") {
+            pos = find_end(text, tag_start, "]]>");
+        } else if rest.starts_with("<?") {
+            pos = find_end(text, tag_start, "?>");
+        } else if rest.starts_with("<!") {
+            pos = find_end(text, tag_start, ">");
+        } else if let Some(after_slash) = rest.strip_prefix("</") {
+            let name = to_name(&after_slash[..get_name_len(after_slash)], is_html);
+            close_element(&mut stack, &name, tag_start);
+            pos = find_end(text, tag_start, ">");
+        } else {
+            let name_len = get_name_len(&rest[1..]);
+            if name_len == 0 {
+                // A `<` that starts no tag is text
+                pos = tag_start + 1;
+                continue;
+            }
+
+            let name = to_name(&rest[1..=name_len], is_html);
+            let (attributes, tag_end, is_self_closing) =
+                parse_attributes(text, tag_start + 1 + name_len, is_html);
+            let mut element = Element {
+                name,
+                start: tag_start,
+                attributes,
+                content: (tag_end, tag_end),
+                raw_text: false,
+                children: Vec::new(),
+            };
+            pos = tag_end;
+
+            if is_html && RAW_TEXT_ELEMENTS.contains(&element.name.as_str()) && !is_self_closing {
+                let end_tag = format!("</{}", element.name);
+                let content_end = text[tag_end..]
+                    .to_ascii_lowercase()
+                    .find(&end_tag)
+                    .map_or(text.len(), |found| tag_end + found);
+                element.content.1 = content_end;
+                element.raw_text = true;
+                pos = find_end(text, content_end, ">");
+                stack.last_mut().unwrap().children.push(element);
+            } else if is_self_closing || (is_html && VOID_ELEMENTS.contains(&element.name.as_str()))
+            {
+                stack.last_mut().unwrap().children.push(element);
+            } else {
+                stack.push(element);
+            }
+        }
+    }
+
+    while stack.len() > 1 {
+        let mut element = stack.pop().unwrap();
+        element.content.1 = text.len();
+        stack.last_mut().unwrap().children.push(element);
+    }
+    stack.pop().unwrap()
+}
+
+/// Sorts the spans of selected elements or attributes and drops those within another.
+pub fn to_spans(mut spans: Vec<(usize, usize, Escaping)>) -> Vec<(usize, usize, Escaping)> {
+    spans.sort_unstable_by_key(|&(start, end, _)| (start, end));
+    let mut covered_to = 0;
+    spans.retain(|&(start, end, _)| {
+        let is_outside = start >= covered_to;
+        if is_outside {
+            covered_to = end.max(start + 1);
+        }
+        is_outside
+    });
+    spans
+}
+
+/// Decodes the character references in element text or an attribute value as it is written.
+///
+/// The inside of a CDATA section is its own value.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::markup::unescape_markup_value;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(unescape_markup_value("a &lt; b", Escaping::XmlText), "a < b");
+/// assert_eq!(unescape_markup_value("a &lt; b", Escaping::XmlCData), "a &lt; b");
+/// ```
+pub fn unescape_markup_value(raw: &str, escaping: Escaping) -> String {
+    match escaping {
+        Escaping::XmlText | Escaping::XmlAttribute(_) => decode_references(raw),
+        _ => raw.to_string(),
+    }
+}
+
+/// Escapes a value so it can be written as element text or an attribute value.
+///
+/// In text, `&`, `<`, and `>` are escaped. In an attribute value, `&`, `<`, and the quote around it are escaped,
+/// and a value without quotes also has whitespace and anything else that would end it escaped.
+/// Nothing is escaped inside a CDATA section.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error for a value with `]]>` inside a CDATA section, since it would end the section.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::markup::escape_markup_value;
+/// use seer::strategies::enums::escaping::Escaping;
+///
+/// assert_eq!(escape_markup_value("a < b & c", Escaping::XmlText).unwrap(), "a &lt; b &amp; c");
+/// assert_eq!(escape_markup_value("say \"hi\"", Escaping::XmlAttribute(Some('"'))).unwrap(), "say &quot;hi&quot;");
+/// assert_eq!(escape_markup_value("a < b", Escaping::XmlCData).unwrap(), "a < b");
+/// assert!(escape_markup_value("a ]]> b", Escaping::XmlCData).is_err());
+/// ```
+pub fn escape_markup_value(text: &str, escaping: Escaping) -> io::Result<String> {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match (escaping, c) {
+            (Escaping::XmlCData, _) => escaped.push(c),
+            (_, '&') => escaped.push_str("&amp;"),
+            (_, '<') => escaped.push_str("&lt;"),
+            (Escaping::XmlText | Escaping::XmlAttribute(None), '>') => escaped.push_str("&gt;"),
+            (Escaping::XmlAttribute(Some('"') | None), '"') => escaped.push_str("&quot;"),
+            (Escaping::XmlAttribute(Some('\'') | None), '\'') => escaped.push_str("&apos;"),
+            (Escaping::XmlAttribute(None), c) if c.is_whitespace() || c == '=' || c == '`' => {
+                escaped.push_str(&format!("&#{};", c as u32))
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    if escaping == Escaping::XmlCData && text.contains("]]>") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("'{}' cannot be written in a CDATA section", text),
+        ));
+    } else if escaping == Escaping::XmlAttribute(None) && text.is_empty() {
+        // An empty value without quotes would take whatever follows it
+        escaped.push_str("\"\"");
+    }
+    Ok(escaped)
+}
+
+// Returns true if the text starts with an HTML doctype or `<html` tag.
+fn is_html(text: &str) -> bool {
+    let mut rest = text.trim_start_matches('\u{feff}').trim_start();
+    while rest.starts_with("<!--") {
+        rest = rest
+            .find("-->")
+            .map_or("", |end| rest[end + 3..].trim_start());
+    }
+    let start = rest.get(..14).unwrap_or(rest).to_ascii_lowercase();
+    start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+// Ends the element with the given name, and every element opened within it, at the end tag.
+fn close_element(stack: &mut Vec<Element>, name: &str, end_tag_start: usize) {
+    // The document itself, at the bottom of the stack, is never closed
+    let Some(index) = stack
+        .iter()
+        .rposition(|element| element.name == name)
+        .filter(|&index| index > 0)
+    else {
+        return;
+    };
+
+    while stack.len() > index {
+        let mut element = stack.pop().unwrap();
+        element.content.1 = end_tag_start;
+        stack.last_mut().unwrap().children.push(element);
+    }
+}
+
+// Parses the attributes of a start tag, returning them, the position after the tag, and whether it ends with `/>`.
+fn parse_attributes(text: &str, mut pos: usize, is_html: bool) -> (Vec<Attribute>, usize, bool) {
+    let mut attributes = Vec::new();
+
+    loop {
+        pos += text[pos..].len() - text[pos..].trim_start().len();
+        let rest = &text[pos..];
+        if rest.is_empty() {
+            return (attributes, pos, false);
+        } else if rest.starts_with('>') {
+            return (attributes, pos + 1, false);
+        } else if rest.starts_with("/>") {
+            return (attributes, pos + 2, true);
+        }
+
+        let name_len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if name_len == 0 {
+            // A `/` or `=` out of place
+            pos += 1;
+            continue;
+        }
+        let name = to_name(&rest[..name_len], is_html);
+        pos += name_len;
+
+        let after_name = text[pos..].trim_start();
+        let Some(after_equals) = after_name.strip_prefix('=') else {
+            attributes.push(Attribute {
+                name,
+                value: String::new(),
+                span: None,
+                quote: None,
+            });
+            continue;
+        };
+        let value_start = text.len() - after_equals.trim_start().len();
+        let value = &text[value_start..];
+
+        let quote = value.chars().next().filter(|&c| c == '"' || c == '\'');
+        let span = match quote {
+            Some(quote) => {
+                let end = value[1..]
+                    .find(quote)
+                    .map_or(text.len(), |found| value_start + 1 + found);
+                pos = (end + 1).min(text.len());
+                (value_start + 1, end)
+            }
+            None => {
+                let end = value
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .map_or(text.len(), |found| value_start + found);
+                pos = end;
+                (value_start, end)
+            }
+        };
+        attributes.push(Attribute {
+            name,
+            value: decode_references(&text[span.0..span.1]),
+            span: Some(span),
+            quote,
+        });
+    }
+}
+
+// Returns the length of the element name at the start of the text, or 0 if it does not start with one.
+fn get_name_len(text: &str) -> usize {
+    if !text.starts_with(|c: char| c.is_alphabetic() || c == '_' || c == ':') {
+        return 0;
+    }
+    text.find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '<'))
+        .unwrap_or(text.len())
+}
+
+fn to_name(name: &str, is_html: bool) -> String {
+    if is_html {
+        name.to_ascii_lowercase()
+    } else {
+        name.to_string()
+    }
+}
+
+// Returns the position after the next `end` from `start`, or the end of the text if there is none.
+fn find_end(text: &str, start: usize, end: &str) -> usize {
+    text[start..]
+        .find(end)
+        .map_or(text.len(), |found| start + found + end.len())
+}
+
+// Decodes the predefined entities and numeric character references, leaving anything else as written.
+fn decode_references(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let reference = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_reference(&rest[1..end])?, end)));
+        match reference {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_names(element: &Element) -> Vec<&str> {
+        element
+            .get_descendants()
+            .iter()
+            .skip(1)
+            .map(|element| element.get_name())
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_nested_elements_and_attributes() {
+        let text = "<?xml version=\"1.0\"?>\n<!-- <skipped/> -->\n<a x=\"1 &lt; 2\" y='b' z=c flag><b/><c>\n  text\n</c></a>";
+        let root = parse_markup(text);
+        assert_eq!(get_names(&root), vec!["a", "b", "c"]);
+
+        let a = &root.get_children()[0];
+        let values: Vec<&str> = a
+            .get_attributes()
+            .iter()
+            .map(|attribute| attribute.get_value())
+            .collect();
+        assert_eq!(values, vec!["1 < 2", "b", "c", ""]);
+        let (start, end) = a.get_attribute("x").unwrap().get_span().unwrap();
+        assert_eq!(&text[start..end], "1 &lt; 2");
+        assert_eq!(a.get_attribute("flag").unwrap().get_span(), None);
+        assert_eq!(
+            a.get_attribute("y").unwrap().get_escaping(),
+            Escaping::XmlAttribute(Some('\''))
+        );
+        assert_eq!(
+            a.get_attribute("z").unwrap().get_escaping(),
+            Escaping::XmlAttribute(None)
+        );
+
+        let (start, end, escaping) = a.get_children()[1].get_text_span(text);
+        assert_eq!(&text[start..end], "text");
+        assert_eq!(escaping, Escaping::XmlText);
+    }
+
+    #[test]
+    fn test_selects_the_inside_of_cdata() {
+        let text = "<a> <![CDATA[x < y]]> </a>";
+        let root = parse_markup(text);
+        let a = &root.get_children()[0];
+        let (start, end, escaping) = a.get_text_span(text);
+        assert_eq!(&text[start..end], "x < y");
+        assert_eq!(escaping, Escaping::XmlCData);
+        assert_eq!(a.get_text(text).unwrap(), "x < y");
+    }
+
+    #[test]
+    fn test_recovers_from_unbalanced_tags() {
+        let text = "<a><b><c>1</a><d>2</x></d><e>";
+        let root = parse_markup(text);
+        assert_eq!(get_names(&root), vec!["a", "b", "c", "d", "e"]);
+
+        let b = &root.get_children()[0].get_children()[0];
+        let (start, end, escaping) = b.get_text_span(text);
+        assert_eq!(&text[start..end], "<c>1");
+        assert_eq!(escaping, Escaping::None);
+        assert_eq!(root.get_children()[1].get_text(text).unwrap(), "2</x>");
+    }
+
+    #[test]
+    fn test_parses_html() {
+        let text =
+            "<!DOCTYPE html><HTML><p>a<br>b</p><script>if (a<b) {}</script><img src=x.png></HTML>";
+        let root = parse_markup(text);
+        assert_eq!(get_names(&root), vec!["html", "p", "br", "script", "img"]);
+
+        let script = &root.get_children()[0].get_children()[1];
+        assert_eq!(script.get_text(text).unwrap(), "if (a<b) {}");
+        assert_eq!(script.get_text_span(text).2, Escaping::None);
+    }
+
+    #[test]
+    fn test_to_spans_drops_nested_spans() {
+        let spans = [(5, 6), (0, 10), (12, 12), (12, 12), (11, 15)]
+            .into_iter()
+            .map(|(start, end)| (start, end, Escaping::None))
+            .collect();
+        assert_eq!(
+            to_spans(spans),
+            vec![(0, 10, Escaping::None), (11, 15, Escaping::None)]
+        );
+    }
+
+    #[test]
+    fn test_escapes_values_where_they_are_written() {
+        assert_eq!(
+            escape_markup_value("<b> & 'c'", Escaping::XmlText).unwrap(),
+            "&lt;b&gt; &amp; 'c'"
+        );
+        assert_eq!(
+            escape_markup_value("it's \"x\"", Escaping::XmlAttribute(Some('\''))).unwrap(),
+            "it&apos;s \"x\""
+        );
+        assert_eq!(
+            escape_markup_value("a b=c>", Escaping::XmlAttribute(None)).unwrap(),
+            "a&#32;b&#61;c&gt;"
+        );
+        assert_eq!(
+            escape_markup_value("", Escaping::XmlAttribute(None)).unwrap(),
+            "\"\""
+        );
+        assert_eq!(escape_markup_value("]]", Escaping::XmlCData).unwrap(), "]]");
+
+        let error = escape_markup_value("]]>", Escaping::XmlCData).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_escaped_values_decode_to_themselves() {
+        let value = "x < y & \"z\" 'w'\t";
+        for escaping in [
+            Escaping::XmlText,
+            Escaping::XmlAttribute(Some('"')),
+            Escaping::XmlAttribute(Some('\'')),
+            Escaping::XmlAttribute(None),
+        ] {
+            let escaped = escape_markup_value(value, escaping).unwrap();
+            assert_eq!(unescape_markup_value(&escaped, escaping), value);
+        }
+    }
+}
@@ -1 +1,22 @@
+pub mod archive;
+pub mod binary;
+pub mod compression;
+pub mod css_selector;
+pub mod delimited;
+pub mod edits;
+pub mod encoding;
+pub mod escaping;
 pub mod file_sys;
+pub mod git;
+pub mod json_path;
+pub mod key_path;
+pub mod key_value;
+pub mod lines;
+pub mod markup;
+pub mod pattern_set;
+pub mod search_modifiers;
+pub mod stream_search;
+pub mod toml_key;
+pub mod worker_pool;
+pub mod xml_path;
+pub mod yaml_path;
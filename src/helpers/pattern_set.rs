@@ -0,0 +1,231 @@
+use crate::helpers::search_modifiers::SearchModifiers;
+use regex::Regex;
+
+// Prefix of the capture group that wraps each pattern in the combined regex.
+const GROUP_PREFIX: &str = "seer_pattern_";
+
+/// Several regex patterns that are searched for in a single scan.
+///
+/// The patterns are compiled once into one alternation, with a capture group around each pattern
+/// to tell which one matched. Hundreds of patterns then cost one scan of each searchable.
+/// Named groups within the patterns become non-capturing, so patterns may reuse group names.
+///
+/// When several patterns match at the same position, the one given first wins.
+/// With whole words, only the patterns that match a whole word there take part.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::pattern_set::PatternSet;
+/// use seer::helpers::search_modifiers::SearchModifiers;
+///
+/// let patterns = vec![String::from(r"\d+"), String::from("[a-z]+")];
+/// let pattern_set = PatternSet::new(&patterns, SearchModifiers::default()).unwrap();
+/// assert_eq!(pattern_set.find_at("abc 123", 0), Some((0, 3, 1)));
+/// assert_eq!(pattern_set.find_at("abc 123", 3), Some((4, 7, 0)));
+/// ```
+pub struct PatternSet {
+    regex: Regex,
+    // The pattern index for each capture group, if the group wraps a pattern.
+    group_patterns: Vec<Option<usize>>,
+}
+
+impl PatternSet {
+    /// Compiles the patterns with the search modifiers applied to each of them.
+    ///
+    /// With smart case, each pattern decides its own case sensitivity.
+    /// The regex flags apply to every pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the patterns is not a valid regex.
+    pub fn new(
+        patterns: &[String],
+        search_modifiers: SearchModifiers,
+    ) -> Result<Self, regex::Error> {
+        // Compiling each pattern alone reports the error for the pattern that is invalid.
+        for pattern in patterns {
+            Regex::new(pattern)?;
+        }
+        let alternation = patterns
+            .iter()
+            .enumerate()
+            .map(|(index, pattern)| {
+                format!(
+                    "(?P<{}{}>{})",
+                    GROUP_PREFIX,
+                    index,
+                    search_modifiers.apply_inline_flags(&without_group_names(pattern))
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("|");
+        let regex = Regex::new(&alternation)?;
+        let group_patterns = regex
+            .capture_names()
+            .map(|name| name.and_then(|name| name.strip_prefix(GROUP_PREFIX)?.parse().ok()))
+            .collect();
+
+        Ok(Self {
+            regex,
+            group_patterns,
+        })
+    }
+
+    /// Finds the first match at or after `start`.
+    ///
+    /// The search modifiers were applied to each pattern when it was compiled.
+    ///
+    /// # Returns
+    /// The start and end byte positions of the match and the index of the pattern that matched,
+    /// or `None` if there is no such match.
+    pub fn find_at(&self, searchable: &str, start: usize) -> Option<(usize, usize, usize)> {
+        let captures = self.regex.captures_at(searchable, start)?;
+        let mat = captures.get(0)?;
+        let pattern_index = self
+            .group_patterns
+            .iter()
+            .enumerate()
+            .find_map(|(group, pattern)| pattern.filter(|_| captures.get(group).is_some()))?;
+
+        Some((mat.start(), mat.end(), pattern_index))
+    }
+}
+
+// Rewrites the named groups of a valid pattern as non-capturing groups.
+//
+// Only the capture groups around whole patterns are read, and a name given twice in the
+// alternation would not compile.
+fn without_group_names(pattern: &str) -> String {
+    let mut rewritten = String::with_capacity(pattern.len());
+    let mut chars = pattern.char_indices().peekable();
+    let mut class_depth = 0;
+
+    while let Some((index, c)) = chars.next() {
+        rewritten.push(c);
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    rewritten.push(escaped);
+                }
+            }
+            '[' => {
+                class_depth += 1;
+                // A `]` right after the opening bracket is a literal.
+                if chars.next_if(|&(_, next)| next == '^').is_some() {
+                    rewritten.push('^');
+                }
+                if chars.next_if(|&(_, next)| next == ']').is_some() {
+                    rewritten.push(']');
+                }
+            }
+            ']' if class_depth > 0 => class_depth -= 1,
+            '(' if class_depth == 0 => {
+                let rest = &pattern[index + 1..];
+                if rest.starts_with("?P<") || rest.starts_with("?<") {
+                    // Group names may not contain `>`, so the first one closes the name.
+                    let group_start = index + 1 + rest.find('>').map_or(0, |end| end + 1);
+                    while chars.next_if(|&(next, _)| next < group_start).is_some() {}
+                    rewritten.push_str("?:");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    rewritten
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+
+    fn patterns(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|pattern| pattern.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reports_the_pattern_that_matched() {
+        let pattern_set = PatternSet::new(
+            &patterns(&["foo", "ba(r|z)", "q+"]),
+            SearchModifiers::default(),
+        )
+        .unwrap();
+        let searchable = "baz foo qq";
+
+        assert_eq!(pattern_set.find_at(searchable, 0), Some((0, 3, 1)));
+        assert_eq!(pattern_set.find_at(searchable, 3), Some((4, 7, 0)));
+        assert_eq!(pattern_set.find_at(searchable, 7), Some((8, 10, 2)));
+        assert_eq!(pattern_set.find_at(searchable, 10), None);
+    }
+
+    #[test]
+    fn test_no_match_without_any_occurring_pattern() {
+        let pattern_set =
+            PatternSet::new(&patterns(&["foo", "bar"]), SearchModifiers::default()).unwrap();
+        assert!(pattern_set.find_at("baz", 0).is_none());
+    }
+
+    #[test]
+    fn test_first_pattern_wins_at_the_same_position() {
+        let pattern_set =
+            PatternSet::new(&patterns(&["fo", "foo"]), SearchModifiers::default()).unwrap();
+        assert_eq!(pattern_set.find_at("foo", 0), Some((0, 2, 0)));
+    }
+
+    #[test]
+    fn test_whole_words_apply_to_each_pattern() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Sensitive, true);
+        let pattern_set = PatternSet::new(&patterns(&["foo", "foobar"]), modifiers).unwrap();
+        let searchable = "foobar (foo)";
+
+        assert_eq!(pattern_set.find_at(searchable, 0), Some((0, 6, 1)));
+        assert_eq!(pattern_set.find_at(searchable, 6), Some((8, 11, 0)));
+        assert_eq!(pattern_set.find_at(searchable, 11), None);
+    }
+
+    #[test]
+    fn test_smart_case_applies_to_each_pattern() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Smart, false);
+        let pattern_set = PatternSet::new(&patterns(&["foo", "Bar"]), modifiers).unwrap();
+        let searchable = "FOO bar Bar";
+
+        assert_eq!(pattern_set.find_at(searchable, 0), Some((0, 3, 0)));
+        assert_eq!(pattern_set.find_at(searchable, 3), Some((8, 11, 1)));
+    }
+
+    #[test]
+    fn test_patterns_may_reuse_group_names() {
+        let pattern_set = PatternSet::new(
+            &patterns(&[r"a(?P<v>\d)", r"b(?<v>\d)", r"[(?P<v>]c"]),
+            SearchModifiers::default(),
+        )
+        .unwrap();
+        let searchable = "b2 a1 <c";
+
+        assert_eq!(pattern_set.find_at(searchable, 0), Some((0, 2, 1)));
+        assert_eq!(pattern_set.find_at(searchable, 2), Some((3, 5, 0)));
+        assert_eq!(pattern_set.find_at(searchable, 5), Some((6, 8, 2)));
+    }
+
+    #[test]
+    fn test_group_names_are_rewritten_outside_classes_and_escapes() {
+        assert_eq!(without_group_names(r"(?P<a>x)(?<b>y)(z)"), "(?:x)(?:y)(z)");
+        assert_eq!(without_group_names(r"\(?P<a>x\)"), r"\(?P<a>x\)");
+        assert_eq!(without_group_names(r"[](?P<a>]x"), r"[](?P<a>]x");
+        assert_eq!(without_group_names(r"[^]](?<a>x)"), r"[^]](?:x)");
+        assert_eq!(
+            without_group_names(r"[[:alpha:]](?<a>x)"),
+            r"[[:alpha:]](?:x)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        let error = PatternSet::new(&patterns(&["foo", "("]), SearchModifiers::default())
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("unclosed group"));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+use regex::{Regex, RegexBuilder};
+
+/// Modifiers that change how every search pattern is matched.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::search_modifiers::SearchModifiers;
+/// use seer::strategies::enums::case_sensitivity::CaseSensitivity;
+///
+/// let modifiers = SearchModifiers::new(CaseSensitivity::Insensitive, true);
+/// let regex = modifiers.build_regex("foo").unwrap();
+/// assert_eq!(modifiers.find_at(&regex, "food FOO", 0), Some((5, 8)));
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct SearchModifiers {
+    case_sensitivity: CaseSensitivity,
+    whole_word: bool,
+    multi_line: bool,
+    dot_all: bool,
+}
+
+impl SearchModifiers {
+    // Constructor for SearchModifiers
+    pub fn new(case_sensitivity: CaseSensitivity, whole_word: bool) -> Self {
+        Self {
+            case_sensitivity,
+            whole_word,
+            multi_line: false,
+            dot_all: false,
+        }
+    }
+
+    // Returns the case sensitivity.
+    pub fn get_case_sensitivity(&self) -> CaseSensitivity {
+        self.case_sensitivity
+    }
+
+    // Returns true if matches must be whole words.
+    pub fn is_whole_word(&self) -> bool {
+        self.whole_word
+    }
+
+    // Returns true if `^` and `$` match at the start and end of each line.
+    pub fn is_multi_line(&self) -> bool {
+        self.multi_line
+    }
+
+    // Returns true if `.` also matches line terminators.
+    pub fn is_dot_all(&self) -> bool {
+        self.dot_all
+    }
+
+    // Returns true if regexes may match across lines. Without a regex flag they match within each line.
+    pub fn matches_across_lines(&self) -> bool {
+        self.multi_line || self.dot_all
+    }
+
+    // Sets whether `^` and `$` match at the start and end of each line.
+    pub fn set_multi_line(&mut self, multi_line: bool) {
+        self.multi_line = multi_line;
+    }
+
+    // Sets whether `.` also matches line terminators.
+    pub fn set_dot_all(&mut self, dot_all: bool) {
+        self.dot_all = dot_all;
+    }
+
+    // Returns true if exact strings are matched as given. The regex flags never change an exact string.
+    pub fn is_default(&self) -> bool {
+        self.case_sensitivity == CaseSensitivity::Sensitive && !self.whole_word
+    }
+
+    /// Returns true if the given regex pattern should be matched without regard to case.
+    ///
+    /// Smart case ignores case unless the pattern contains an uppercase letter.
+    /// A character following a backslash is skipped, so escapes like `\W` or `\D` don't count.
+    pub fn ignores_case_for(&self, pattern: &str) -> bool {
+        match self.case_sensitivity {
+            CaseSensitivity::Sensitive => false,
+            CaseSensitivity::Insensitive => true,
+            CaseSensitivity::Smart => {
+                let mut chars = pattern.chars();
+                while let Some(c) = chars.next() {
+                    if c == '\\' {
+                        chars.next();
+                    } else if c.is_uppercase() {
+                        return false;
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    /// Compiles a regex pattern with the case sensitivity and regex flags applied.
+    ///
+    /// Ignoring case uses Unicode simple case folding, so `É` matches `é` and `K` matches the Kelvin sign.
+    pub fn build_regex(&self, pattern: &str) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(self.ignores_case_for(pattern))
+            .multi_line(self.multi_line)
+            .dot_matches_new_line(self.dot_all)
+            .build()
+    }
+
+    /// Returns the pattern wrapped in a group that applies the case sensitivity and regex flags inline.
+    ///
+    /// For whole words, the group must also not be preceded or followed by a word character,
+    /// so each pattern is checked on its own. This lets patterns with different flags share one regex.
+    pub fn apply_inline_flags(&self, pattern: &str) -> String {
+        let mut flags = String::new();
+        if self.ignores_case_for(pattern) {
+            flags.push('i');
+        }
+        if self.multi_line {
+            flags.push('m');
+        }
+        if self.dot_all {
+            flags.push('s');
+        }
+        if self.whole_word {
+            format!(r"\b{{start-half}}(?{}:{})\b{{end-half}}", flags, pattern)
+        } else {
+            format!("(?{}:{})", flags, pattern)
+        }
+    }
+
+    /// Finds the first match of the regex at or after `start` that satisfies the modifiers.
+    ///
+    /// # Returns
+    /// The start and end byte positions of the match, or `None` if there is no such match.
+    pub fn find_at(&self, regex: &Regex, searchable: &str, start: usize) -> Option<(usize, usize)> {
+        let mut pos = start;
+
+        while pos <= searchable.len() {
+            let mat = regex.find_at(searchable, pos)?;
+
+            if !self.whole_word || is_whole_word(searchable, mat.start(), mat.end()) {
+                return Some((mat.start(), mat.end()));
+            }
+
+            // Retry from the next character so that overlapping candidates are still considered.
+            pos = mat.start()
+                + searchable[mat.start()..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+        }
+
+        None
+    }
+}
+
+impl Default for SearchModifiers {
+    fn default() -> Self {
+        Self::new(CaseSensitivity::Sensitive, false)
+    }
+}
+
+/// Returns true if the span is not directly preceded or followed by a word character.
+///
+/// Word characters are Unicode letters, digits, and underscores, as with `grep -w`.
+pub fn is_whole_word(searchable: &str, start: usize, end: usize) -> bool {
+    let before = searchable[..start].chars().next_back();
+    let after = searchable[end..].chars().next();
+
+    !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensitive_never_ignores_case() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Sensitive, false);
+        assert!(!modifiers.ignores_case_for("foo"));
+    }
+
+    #[test]
+    fn test_insensitive_always_ignores_case() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Insensitive, false);
+        assert!(modifiers.ignores_case_for("Foo"));
+    }
+
+    #[test]
+    fn test_smart_case_ignores_case_for_lowercase_patterns() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Smart, false);
+        assert!(modifiers.ignores_case_for("foo"));
+        assert!(modifiers.ignores_case_for(r"\W+foo\D"));
+        assert!(!modifiers.ignores_case_for("Foo"));
+        assert!(!modifiers.ignores_case_for("émile Éclair"));
+    }
+
+    #[test]
+    fn test_build_regex_folds_unicode_case() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Insensitive, false);
+        let regex = modifiers.build_regex("émile").unwrap();
+        assert!(regex.is_match("ÉMILE"));
+    }
+
+    #[test]
+    fn test_build_regex_applies_regex_flags() {
+        let mut modifiers = SearchModifiers::default();
+        let searchable = "one\ntwo";
+        assert!(!modifiers.build_regex("^two").unwrap().is_match(searchable));
+        assert!(!modifiers
+            .build_regex("one.two")
+            .unwrap()
+            .is_match(searchable));
+
+        modifiers.set_multi_line(true);
+        modifiers.set_dot_all(true);
+        assert!(modifiers.build_regex("^two").unwrap().is_match(searchable));
+        assert!(modifiers
+            .build_regex("one.two")
+            .unwrap()
+            .is_match(searchable));
+    }
+
+    #[test]
+    fn test_apply_inline_flags() {
+        let mut modifiers = SearchModifiers::new(CaseSensitivity::Smart, false);
+        assert_eq!(modifiers.apply_inline_flags("foo"), "(?i:foo)");
+        assert_eq!(modifiers.apply_inline_flags("Foo"), "(?:Foo)");
+
+        modifiers.set_multi_line(true);
+        modifiers.set_dot_all(true);
+        assert_eq!(modifiers.apply_inline_flags("foo"), "(?ims:foo)");
+
+        let modifiers = SearchModifiers::new(CaseSensitivity::Sensitive, true);
+        assert_eq!(
+            modifiers.apply_inline_flags("foo"),
+            r"\b{start-half}(?:foo)\b{end-half}"
+        );
+    }
+
+    #[test]
+    fn test_find_at_skips_partial_words() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Sensitive, true);
+        let regex = modifiers.build_regex("foo").unwrap();
+        assert_eq!(
+            modifiers.find_at(&regex, "food foo_bar (foo)", 0),
+            Some((14, 17))
+        );
+    }
+
+    #[test]
+    fn test_find_at_retries_overlapping_candidates() {
+        let modifiers = SearchModifiers::new(CaseSensitivity::Sensitive, true);
+        let regex = modifiers.build_regex("aa").unwrap();
+        assert_eq!(modifiers.find_at(&regex, "aaa aa", 0), Some((4, 6)));
+    }
+
+    #[test]
+    fn test_is_whole_word_allows_punctuation_delimiters() {
+        assert!(is_whole_word("a {b} c", 2, 5));
+        assert!(!is_whole_word("a{b}c", 1, 4));
+        assert!(is_whole_word("é", 0, 2));
+    }
+}
@@ -0,0 +1,480 @@
+use crate::dto::hit::Hit;
+use crate::strategies::traits::frequency_strategy::FrequencyStrategy;
+use crate::strategies::traits::search_strategy::SearchStrategy;
+use std::io::{self, Read};
+
+/// The number of bytes read from a stream at a time.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The number of bytes kept from the end of each chunk, so that matches crossing into the next chunk are found.
+pub const DEFAULT_OVERLAP: usize = 4 * 1024;
+
+/// Searches a stream in chunks, reporting hits as soon as they are final.
+///
+/// Only the unsearched part of the stream is kept in memory. After each chunk is searched,
+/// everything before the last `overlap` bytes is dropped, unless a hit that may still grow starts there.
+/// The cut is moved back to the start of its line, so patterns see whole lines, but never by more than
+/// another `overlap` bytes. Memory use is therefore bounded by the chunk size and twice the overlap,
+/// even for a stream without line breaks.
+///
+/// A hit is final once it ends before the overlap. Every match no longer than the overlap is found
+/// just as in a search of the whole text. A longer match may be cut short.
+/// A read that returns less than a chunk, as from a pipe with nothing more to give yet, also makes
+/// the hits that end by its last line break final, so they are reported without waiting for more.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::search_modifiers::SearchModifiers;
+/// use seer::helpers::stream_search::StreamSearcher;
+/// use seer::strategies::find_string_strategy::ExactSearchStrategy;
+/// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+///
+/// let mut search_strategy = ExactSearchStrategy::new(
+///     vec![String::from("needle")],
+///     SearchModifiers::default(),
+///     FrequencyStrategyFactory::make_for_testing(),
+/// );
+/// let mut frequency_strategy = FrequencyStrategyFactory::make_for_testing();
+/// let mut values = Vec::new();
+///
+/// StreamSearcher::new(4, 8)
+///     .search(
+///         "hay needle hay needle".as_bytes(),
+///         &mut search_strategy,
+///         frequency_strategy.as_mut(),
+///         |hit, value| values.push((hit.get_position(), value.to_string())),
+///     )
+///     .unwrap();
+///
+/// assert_eq!(values, vec![(4, String::from("needle")), (15, String::from("needle"))]);
+/// ```
+pub struct StreamSearcher {
+    chunk_size: usize,
+    overlap: usize,
+}
+
+impl StreamSearcher {
+    // Constructor for StreamSearcher
+    pub fn new(chunk_size: usize, overlap: usize) -> Self {
+        assert!(chunk_size > 0, "The chunk size must be greater than 0");
+        Self {
+            chunk_size,
+            overlap,
+        }
+    }
+
+    /// Searches the stream and calls `on_hit` with each kept hit and its value, in order.
+    ///
+    /// The search strategy should keep every hit. Chunks overlap, so it may see the same match twice,
+    /// and the frequency strategy is only applied once a hit is final.
+    /// Hit positions are byte offsets from the start of the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream cannot be read or is not valid UTF-8.
+    pub fn search<R, F>(
+        &self,
+        mut reader: R,
+        search_strategy: &mut dyn SearchStrategy,
+        frequency_strategy: &mut dyn FrequencyStrategy,
+        mut on_hit: F,
+    ) -> io::Result<()>
+    where
+        R: Read,
+        F: FnMut(Hit, &str),
+    {
+        let mut chunk = vec![0; self.chunk_size];
+        // The unsearched text, starting at `offset` bytes into the stream
+        let mut buffer = String::new();
+        let mut offset = 0;
+        // The bytes of a character split across chunks
+        let mut pending = Vec::new();
+        // Hits starting before this position were already reported or overlap one that was
+        let mut next_start = 0;
+
+        loop {
+            let read = match reader.read(&mut chunk) {
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            let is_end = read == 0;
+            pending.extend_from_slice(&chunk[..read]);
+            decode_into(&mut pending, &mut buffer, is_end)?;
+
+            let safe_end = if is_end {
+                buffer.len()
+            } else {
+                floor_char_boundary(&buffer, buffer.len().saturating_sub(self.overlap))
+            };
+            // After a short read, the hits in complete lines are final too. The cut still keeps
+            // the overlap, so a match that continues past the last line break is found later.
+            let final_end = if read < self.chunk_size {
+                buffer
+                    .rfind('\n')
+                    .map_or(0, |newline| newline + 1)
+                    .max(safe_end)
+            } else {
+                safe_end
+            };
+            let mut cut = safe_end;
+
+            for hit in search_strategy.search(&buffer) {
+                let start = hit.get_position();
+                let end = start + hit.get_length();
+
+                if offset + start < next_start {
+                    continue;
+                }
+
+                // The hit may still grow with more of the stream, so search it again next time
+                if end > final_end {
+                    cut = cut.min(start);
+                    break;
+                }
+
+                next_start = offset + if end > start { end } else { end + 1 };
+
+                if frequency_strategy.matches_frequency() {
+                    on_hit(
+                        Hit::for_pattern(offset + start, hit.get_length(), hit.get_pattern_index()),
+                        &hit.get_unescaped_value(&buffer),
+                    );
+                }
+
+                if frequency_strategy.is_done() {
+                    return Ok(());
+                }
+            }
+
+            if is_end {
+                return Ok(());
+            }
+
+            // Keep the whole line the cut falls in, unless it starts too far back
+            let limit = floor_char_boundary(&buffer, safe_end.saturating_sub(self.overlap));
+            let cut = buffer[..cut]
+                .rfind('\n')
+                .map_or(0, |newline| newline + 1)
+                .max(limit);
+            buffer.drain(..cut);
+            offset += cut;
+        }
+    }
+}
+
+impl Default for StreamSearcher {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHUNK_SIZE, DEFAULT_OVERLAP)
+    }
+}
+
+// Moves the complete characters from `pending` to the end of `buffer`.
+fn decode_into(pending: &mut Vec<u8>, buffer: &mut String, is_end: bool) -> io::Result<()> {
+    let valid_up_to = match std::str::from_utf8(pending) {
+        Ok(text) => text.len(),
+        // Only an incomplete character at the end may wait for the next chunk
+        Err(e) if e.error_len().is_none() && !is_end => e.valid_up_to(),
+        Err(e) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Stream is not valid UTF-8. {}", e),
+            ))
+        }
+    };
+
+    // The prefix was just validated
+    buffer.push_str(std::str::from_utf8(&pending[..valid_up_to]).unwrap());
+    pending.drain(..valid_up_to);
+    Ok(())
+}
+
+// Returns the largest character boundary in `text` that is not after `index`.
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::search_modifiers::SearchModifiers;
+    use crate::strategies::enums::strategy_type::StrategyType;
+    use crate::strategies::find_between_strategy::BetweenSearchStrategy;
+    use crate::strategies::find_regex_strategy::RegexSearchStrategy;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    use std::cell::Cell;
+
+    // Reads at most `limit` bytes at a time, like a pipe.
+    struct SlowReader<'a> {
+        bytes: &'a [u8],
+        limit: usize,
+    }
+
+    impl Read for SlowReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let read = self.bytes.len().min(self.limit).min(buf.len());
+            buf[..read].copy_from_slice(&self.bytes[..read]);
+            self.bytes = &self.bytes[read..];
+            Ok(read)
+        }
+    }
+
+    // Returns one chunk per read, like a pipe whose writer pauses between chunks.
+    struct PausingReader<'a> {
+        chunks: Vec<&'a str>,
+        reads: &'a Cell<usize>,
+    }
+
+    impl Read for PausingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads.set(self.reads.get() + 1);
+            if self.chunks.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.chunks.remove(0);
+            buf[..chunk.len()].copy_from_slice(chunk.as_bytes());
+            Ok(chunk.len())
+        }
+    }
+
+    fn stream_search(
+        searcher: &StreamSearcher,
+        searchable: &str,
+        search_strategy: &mut dyn SearchStrategy,
+        frequency_strategy: &mut dyn FrequencyStrategy,
+    ) -> Vec<(usize, String)> {
+        let mut hits = Vec::new();
+        searcher
+            .search(
+                SlowReader {
+                    bytes: searchable.as_bytes(),
+                    limit: 3,
+                },
+                search_strategy,
+                frequency_strategy,
+                |hit, value| {
+                    assert_eq!(hit.get_value(searchable), value);
+                    hits.push((hit.get_position(), value.to_string()));
+                },
+            )
+            .unwrap();
+        hits
+    }
+
+    fn get_regex_strategy(regex: &str) -> RegexSearchStrategy {
+        RegexSearchStrategy::new(
+            vec![String::from(regex)],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_finds_the_same_hits_as_a_whole_search() {
+        let searchable = "ab12 cd345\nef6 7890 gh\n1 23";
+        let mut whole_strategy = get_regex_strategy(r"\d+");
+        let expected: Vec<(usize, String)> = whole_strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| (hit.get_position(), hit.get_value(searchable).to_string()))
+            .collect();
+
+        for chunk_size in 1..8 {
+            let hits = stream_search(
+                &StreamSearcher::new(chunk_size, 4),
+                searchable,
+                &mut get_regex_strategy(r"\d+"),
+                FrequencyStrategyFactory::make_for_testing().as_mut(),
+            );
+            assert_eq!(hits, expected, "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_finds_hits_as_long_as_the_overlap_within_a_long_line() {
+        let searchable = "x <a long tag> y <b> z";
+        let mut search_strategy = BetweenSearchStrategy::new(
+            String::from("<"),
+            String::from(">"),
+            false,
+            false,
+            Vec::new(),
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let hits = stream_search(
+            &StreamSearcher::new(2, 12),
+            searchable,
+            &mut search_strategy,
+            FrequencyStrategyFactory::make_for_testing().as_mut(),
+        );
+
+        assert_eq!(
+            hits,
+            vec![(2, String::from("<a long tag>")), (17, String::from("<b>"))]
+        );
+    }
+
+    // Records the length of the longest text it was asked to search.
+    struct LongestSearchStrategy {
+        search_strategy: RegexSearchStrategy,
+        longest: usize,
+    }
+
+    impl SearchStrategy for LongestSearchStrategy {
+        fn strategy_type(&self) -> StrategyType {
+            self.search_strategy.strategy_type()
+        }
+
+        fn search(&mut self, searchable: &str) -> Vec<Hit> {
+            self.longest = self.longest.max(searchable.len());
+            self.search_strategy.search(searchable)
+        }
+
+        fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+            self.search_strategy.get_frequency_strategy()
+        }
+    }
+
+    #[test]
+    fn test_keeps_the_buffer_bounded_without_line_breaks() {
+        let searchable = "<".repeat(10_000) + "x";
+        let mut search_strategy = LongestSearchStrategy {
+            search_strategy: get_regex_strategy("<+x"),
+            longest: 0,
+        };
+
+        let hits = stream_search(
+            &StreamSearcher::new(16, 8),
+            &searchable,
+            &mut search_strategy,
+            FrequencyStrategyFactory::make_for_testing().as_mut(),
+        );
+
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].1.ends_with("<<x"));
+        assert!(search_strategy.longest <= 16 + 2 * 8);
+    }
+
+    #[test]
+    fn test_keeps_characters_split_across_chunks() {
+        let searchable = "ééé straße ééé";
+        let hits = stream_search(
+            &StreamSearcher::new(1, 8),
+            searchable,
+            &mut get_regex_strategy("straße"),
+            FrequencyStrategyFactory::make_for_testing().as_mut(),
+        );
+
+        assert_eq!(hits, vec![(7, String::from("straße"))]);
+    }
+
+    #[test]
+    fn test_applies_the_frequency_once_hits_are_final() {
+        let hits = stream_search(
+            &StreamSearcher::new(2, 4),
+            "1 2 3 4 5",
+            &mut get_regex_strategy(r"\d"),
+            &mut NthFrequencyStrategy::new(3),
+        );
+
+        assert_eq!(hits, vec![(4, String::from("3"))]);
+    }
+
+    #[test]
+    fn test_stops_reading_once_the_frequency_is_done() {
+        let mut reader = SlowReader {
+            bytes: b"1 2 3 4 5 6 7 8 9",
+            limit: 2,
+        };
+        StreamSearcher::new(2, 1)
+            .search(
+                &mut reader,
+                &mut get_regex_strategy(r"\d"),
+                &mut NthFrequencyStrategy::new(1),
+                |_, _| {},
+            )
+            .unwrap();
+
+        assert!(!reader.bytes.is_empty());
+    }
+
+    #[test]
+    fn test_reports_hits_in_complete_lines_after_a_short_read() {
+        let reads = Cell::new(0);
+        let reader = PausingReader {
+            chunks: vec!["id 1\nid 2\nid", " 3\n"],
+            reads: &reads,
+        };
+        let mut hits = Vec::new();
+
+        StreamSearcher::new(64, 16)
+            .search(
+                reader,
+                &mut get_regex_strategy(r"id \d"),
+                FrequencyStrategyFactory::make_for_testing().as_mut(),
+                |hit, value| hits.push((hit.get_position(), value.to_string(), reads.get())),
+            )
+            .unwrap();
+
+        assert_eq!(
+            hits,
+            vec![
+                (0, String::from("id 1"), 1),
+                (5, String::from("id 2"), 1),
+                (10, String::from("id 3"), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_finds_matches_across_lines_after_a_short_read() {
+        let reads = Cell::new(0);
+        let reader = PausingReader {
+            chunks: vec!["x <a\n", "b> y\n"],
+            reads: &reads,
+        };
+        let mut search_strategy = BetweenSearchStrategy::new(
+            String::from("<"),
+            String::from(">"),
+            false,
+            false,
+            Vec::new(),
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+        let mut values = Vec::new();
+
+        StreamSearcher::new(64, 16)
+            .search(
+                reader,
+                &mut search_strategy,
+                FrequencyStrategyFactory::make_for_testing().as_mut(),
+                |_, value| values.push(value.to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(values, vec![String::from("<a\nb>")]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_utf8() {
+        let result = StreamSearcher::new(4, 2).search(
+            &b"abc\xff"[..],
+            &mut get_regex_strategy("a"),
+            FrequencyStrategyFactory::make_for_testing().as_mut(),
+            |_, _| {},
+        );
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,319 @@
+use super::key_path::{KeyPath, Node, NodeKind};
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+use toml_edit::{ImDocument, Item, Table, Value};
+
+/// A path of keys, like `dependencies.serde.version`, that selects values in a TOML document.
+///
+/// The path is written as described for `KeyPath`. A key is found however the document writes it:
+/// under a `[table]` header, as a dotted key, or in an inline table.
+/// An array of tables, like `[[bin]]`, is indexed like any other array.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::toml_key::TomlKey;
+///
+/// let toml_key = TomlKey::parse("dependencies.serde.version").unwrap();
+/// let text = "[dependencies]\nserde = { version = \"1.0\", features = [\"derive\"] }\n";
+/// let values: Vec<&str> = toml_key
+///     .select(text)
+///     .unwrap()
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+///
+/// assert_eq!(values, vec!["1.0"]);
+/// ```
+#[derive(Debug)]
+pub struct TomlKey {
+    key_path: KeyPath,
+}
+
+impl TomlKey {
+    /// Parses a TOML key path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the path is not valid.
+    pub fn parse(path: &str) -> io::Result<Self> {
+        Ok(Self {
+            key_path: KeyPath::parse(path, "TOML")?,
+        })
+    }
+
+    /// Selects the values at this path in the given TOML text.
+    ///
+    /// A string is selected without its quotes, so that an edit keeps the value a string.
+    /// Its contents are still escaped as written, which the returned `Escaping` undoes.
+    /// Any other value is selected as written. Tables and arrays are not selected.
+    ///
+    /// # Returns
+    /// The start and end byte positions of each selected value, in the order they appear, and how it is escaped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the text is not valid TOML.
+    pub fn select(&self, text: &str) -> io::Result<Vec<(usize, usize, Escaping)>> {
+        let document = ImDocument::parse(text).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Invalid TOML. {}", e))
+        })?;
+        let roots = [get_table_node(text, document.as_table())];
+
+        let scalars: Vec<&Node> = self
+            .key_path
+            .select(&roots)
+            .into_iter()
+            .filter(|node| node.is_scalar())
+            .collect();
+        Ok(KeyPath::to_escaped_spans(&scalars))
+    }
+}
+
+/// Decodes the contents of a TOML basic or literal string, written without its quotes.
+///
+/// Either may be a multi-line string, whose first line break is not part of its value.
+///
+/// # Errors
+///
+/// Returns an `InvalidData` error if the contents are not validly escaped.
+pub fn unescape_toml_string(raw: &str, escaping: Escaping) -> io::Result<String> {
+    // Contents that fit in a single-line string mean the same in a multi-line one
+    let quotes = if escaping == Escaping::TomlLiteral {
+        "'''"
+    } else {
+        "\"\"\""
+    };
+    format!("{}{}{}", quotes, raw, quotes)
+        .parse::<Value>()
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("'{}' is not the contents of a TOML string", raw),
+            )
+        })
+}
+
+/// Escapes a text to be the contents of a TOML basic or literal string, written without its quotes.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error if a literal string would have to hold a single quote, a line break,
+/// or another control character other than a tab. A basic string can hold any text.
+pub fn escape_toml_string(text: &str, escaping: Escaping) -> io::Result<String> {
+    if escaping == Escaping::TomlLiteral {
+        if text.contains(|c: char| c == '\'' || (c.is_control() && c != '\t')) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{}' cannot be written in a TOML literal string", text),
+            ));
+        }
+        return Ok(String::from(text));
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            control if control.is_control() => {
+                escaped.push_str(&format!("\\u{:04X}", u32::from(control)))
+            }
+            _ => escaped.push(character),
+        }
+    }
+    Ok(escaped)
+}
+
+// Only scalars are selected, so the spans of tables and arrays are left empty.
+fn get_table_node(text: &str, table: &Table) -> Node {
+    let members = table
+        .iter()
+        .filter_map(|(key, item)| Some((key.to_string(), get_item_node(text, item)?)))
+        .collect();
+    Node::new(0, 0, NodeKind::Mapping(members))
+}
+
+fn get_item_node(text: &str, item: &Item) -> Option<Node> {
+    match item {
+        Item::Table(table) => Some(get_table_node(text, table)),
+        Item::ArrayOfTables(tables) => Some(Node::new(
+            0,
+            0,
+            NodeKind::Sequence(
+                tables
+                    .iter()
+                    .map(|table| get_table_node(text, table))
+                    .collect(),
+            ),
+        )),
+        Item::Value(value) => Some(get_value_node(text, value)),
+        Item::None => None,
+    }
+}
+
+fn get_value_node(text: &str, value: &Value) -> Node {
+    match value {
+        Value::InlineTable(table) => Node::new(
+            0,
+            0,
+            NodeKind::Mapping(
+                table
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), get_value_node(text, value)))
+                    .collect(),
+            ),
+        ),
+        Value::Array(array) => Node::new(
+            0,
+            0,
+            NodeKind::Sequence(
+                array
+                    .iter()
+                    .map(|value| get_value_node(text, value))
+                    .collect(),
+            ),
+        ),
+        _ => {
+            // A parsed value always knows where it was written
+            let span = value.span().unwrap_or_default();
+            let raw = &text[span.clone()];
+            let quote_len = get_quote_len(raw);
+            let mut node = Node::new(
+                span.start + quote_len,
+                span.end - quote_len,
+                NodeKind::Scalar,
+            );
+            if raw.starts_with('"') {
+                node.set_escaping(Escaping::TomlBasic);
+            } else if raw.starts_with('\'') {
+                node.set_escaping(Escaping::TomlLiteral);
+            }
+            node
+        }
+    }
+}
+
+// Returns the length of the quotes around a string, or 0 if the value is not a string.
+fn get_quote_len(raw: &str) -> usize {
+    if raw.starts_with("\"\"\"") || raw.starts_with("'''") {
+        3
+    } else if raw.starts_with('"') || raw.starts_with('\'') {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[package]\nname = \"seer\" # the name\nversion.workspace = true\n\n[dependencies]\nserde = { version = '1.0', features = [\"derive\"] }\nclap.version = \"4\"\nregex = \"1\"\n\n[dependencies.tokio]\nversion = \"\"\"1.38\"\"\"\n\n[[bin]]\nname = \"seer\"\n\n[[bin]]\nname = \"café\"\n";
+
+    fn select(path: &str) -> Vec<&'static str> {
+        TomlKey::parse(path)
+            .unwrap()
+            .select(MANIFEST)
+            .unwrap()
+            .into_iter()
+            .map(|(start, end, _)| &MANIFEST[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_selects_keys_however_they_are_written() {
+        assert_eq!(select("dependencies.serde.version"), vec!["1.0"]);
+        assert_eq!(select("dependencies.clap.version"), vec!["4"]);
+        assert_eq!(select("dependencies.tokio.version"), vec!["1.38"]);
+        assert_eq!(select("dependencies.regex"), vec!["1"]);
+        assert_eq!(select("package.version.workspace"), vec!["true"]);
+    }
+
+    #[test]
+    fn test_selects_every_dependency_version() {
+        assert_eq!(select("dependencies.*.version"), vec!["1.0", "4", "1.38"]);
+    }
+
+    #[test]
+    fn test_selects_in_arrays_of_tables_and_arrays() {
+        assert_eq!(select("bin[*].name"), vec!["seer", "café"]);
+        assert_eq!(select("bin[-1].name"), vec!["café"]);
+        assert_eq!(select("dependencies.serde.features[0]"), vec!["derive"]);
+    }
+
+    #[test]
+    fn test_skips_tables_and_arrays() {
+        assert!(select("dependencies.serde").is_empty());
+        assert!(select("dependencies.serde.features").is_empty());
+        assert!(select("package").is_empty());
+    }
+
+    #[test]
+    fn test_marks_strings_by_their_quotes() {
+        let escapings: Vec<Escaping> = TomlKey::parse("*")
+            .unwrap()
+            .select("a = \"x\"\nb = 'y'\nc = \"\"\"z\"\"\"\nd = 1\n")
+            .unwrap()
+            .into_iter()
+            .map(|(_, _, escaping)| escaping)
+            .collect();
+        assert_eq!(
+            escapings,
+            vec![
+                Escaping::TomlBasic,
+                Escaping::TomlLiteral,
+                Escaping::TomlBasic,
+                Escaping::None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unescapes_basic_strings() {
+        let unescape = |raw| unescape_toml_string(raw, Escaping::TomlBasic).unwrap();
+        assert_eq!(unescape(r#"say \"hi\"\n"#), "say \"hi\"\n");
+        assert_eq!(unescape(r"caf\u00e9 \U0001F600 C:\\dir"), "café 😀 C:\\dir");
+        assert_eq!(unescape("\nfirst \\\n   second"), "first second");
+        assert!(unescape_toml_string(r"a\q", Escaping::TomlBasic).is_err());
+    }
+
+    #[test]
+    fn test_leaves_literal_strings_as_written() {
+        let unescape = |raw| unescape_toml_string(raw, Escaping::TomlLiteral).unwrap();
+        assert_eq!(unescape(r"C:\dir\n"), r"C:\dir\n");
+        assert_eq!(unescape("\nit's"), "it's");
+    }
+
+    #[test]
+    fn test_escapes_basic_strings() {
+        let escape = |text| escape_toml_string(text, Escaping::TomlBasic).unwrap();
+        assert_eq!(escape("say \"hi\"\n"), r#"say \"hi\"\n"#);
+        assert_eq!(escape("C:\\dir\t\u{1}\u{7f}é"), r"C:\\dir\t\u0001\u007Fé");
+    }
+
+    #[test]
+    fn test_refuses_literal_strings_it_cannot_write() {
+        assert_eq!(
+            escape_toml_string(r"C:\dir", Escaping::TomlLiteral).unwrap(),
+            r"C:\dir"
+        );
+        for text in ["it's", "a\nb", "a\u{0}"] {
+            let error = escape_toml_string(text, Escaping::TomlLiteral).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_toml() {
+        let error = TomlKey::parse("a").unwrap().select("a = ").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,131 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Returns the number of worker threads to use.
+///
+/// # Arguments
+///
+/// * `requested` - The number of threads given with `--threads`, if any.
+///
+/// # Returns
+///
+/// The requested number of threads, or the number of CPUs if none was requested.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::worker_pool::thread_count;
+///
+/// assert_eq!(thread_count(Some(3)), 3);
+/// assert!(thread_count(None) >= 1);
+/// ```
+pub fn thread_count(requested: Option<usize>) -> usize {
+    requested.unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+/// Applies `work` to every item on a pool of worker threads and returns the results in item order.
+///
+/// Each worker takes the next unprocessed item until none are left, so a few large items
+/// don't hold up the rest. State that can't be shared between threads, such as a search strategy,
+/// is made once per worker with `make_state` and reused for every item that worker processes.
+///
+/// # Arguments
+///
+/// * `items` - The items to process.
+/// * `threads` - The number of worker threads. With 1 thread, the items are processed on the calling thread.
+/// * `make_state` - Makes the state for one worker.
+/// * `work` - Processes one item with the worker's state.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::worker_pool::map_in_order;
+///
+/// let lengths = map_in_order(&["a", "bbb", "cc"], 2, || (), |_, item| item.len());
+/// assert_eq!(lengths, vec![1, 3, 2]);
+/// ```
+pub fn map_in_order<T, S, R, M, W>(items: &[T], threads: usize, make_state: M, work: W) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    M: Fn() -> S + Sync,
+    W: Fn(&mut S, &T) -> R + Sync,
+{
+    let threads = threads.clamp(1, items.len().max(1));
+    if threads == 1 {
+        let mut state = make_state();
+        return items.iter().map(|item| work(&mut state, item)).collect();
+    }
+
+    let next_index = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut state = make_state();
+                    let mut worker_results = Vec::new();
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            return worker_results;
+                        };
+                        worker_results.push((index, work(&mut state, item)));
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            // A panic in a worker is passed on, as if the work had run on this thread
+            let worker_results = worker
+                .join()
+                .unwrap_or_else(|payload| std::panic::resume_unwind(payload));
+            for (index, result) in worker_results {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.expect("Every item is processed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_results_are_in_item_order() {
+        let items: Vec<usize> = (0..100).collect();
+        let results = map_in_order(&items, 4, || (), |_, item| item * 2);
+        assert_eq!(
+            results,
+            items.iter().map(|item| item * 2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_state_is_made_once_per_worker() {
+        let states_made = AtomicUsize::new(0);
+        let items: Vec<usize> = (0..50).collect();
+        map_in_order(
+            &items,
+            3,
+            || states_made.fetch_add(1, Ordering::Relaxed),
+            |_, item| *item,
+        );
+        assert!(states_made.load(Ordering::Relaxed) <= 3);
+    }
+
+    #[test]
+    fn test_no_items() {
+        let items: Vec<usize> = Vec::new();
+        assert!(map_in_order(&items, 4, || (), |_, item| *item).is_empty());
+    }
+}
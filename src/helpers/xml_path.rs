@@ -0,0 +1,465 @@
+use super::markup::{parse_markup, to_spans, Element};
+use crate::strategies::enums::escaping::Escaping;
+use std::io;
+
+/// A path in a small subset of XPath, like `//dependency[artifactId='junit']/version`,
+/// that selects element text or attribute values in an XML or HTML document.
+///
+/// The path is a list of steps, each after a `/` for a child or a `//` for a descendant at any depth:
+///
+/// * `name` or `*` selects elements, and the text of the last one is selected.
+/// * `@name` or `@*` selects attribute values. It must be the last step.
+/// * `text()` selects the text of the elements so far. It must be the last step.
+///
+/// An element step may be followed by predicates, which keep the elements that:
+///
+/// * `[1]` or `[last()]` are at that position among the siblings that matched the step.
+/// * `[@name]` or `[@name='value']` have the attribute, or have it with the value.
+/// * `[name]` or `[name='value']` have a child element, or have one with the text.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::xml_path::XmlPath;
+///
+/// let text = "<project><dependencies>\
+///     <dependency><artifactId>junit</artifactId><version>4.12</version></dependency>\
+///     <dependency><artifactId>guava</artifactId><version>31.1</version></dependency>\
+///     </dependencies></project>";
+/// let xml_path = XmlPath::parse("//dependency[artifactId='junit']/version").unwrap();
+/// let values: Vec<&str> = xml_path
+///     .select(text)
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+///
+/// assert_eq!(values, vec!["4.12"]);
+/// ```
+#[derive(Debug)]
+pub struct XmlPath {
+    steps: Vec<Step>,
+    target: Target,
+}
+
+// A step that selects elements from each element selected so far.
+#[derive(Debug, PartialEq)]
+struct Step {
+    // Whether the step selects from every element within, not just the children
+    descendant: bool,
+    // The name to match, or `None` for `*`
+    name: Option<String>,
+    predicates: Vec<Predicate>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Predicate {
+    Position(usize),
+    Last,
+    HasAttribute(String),
+    AttributeEquals(String, String),
+    HasChild(String),
+    ChildEquals(String, String),
+}
+
+// What the path selects from the elements its steps select.
+#[derive(Debug, PartialEq)]
+enum Target {
+    Text {
+        descendant: bool,
+    },
+    // An attribute by name, or every attribute for `None`
+    Attribute {
+        descendant: bool,
+        name: Option<String>,
+    },
+}
+
+impl XmlPath {
+    /// Parses an XML path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the path is not in the supported subset of XPath.
+    pub fn parse(path: &str) -> io::Result<Self> {
+        let invalid = |message: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid XML path '{}'. {}", path, message),
+            )
+        };
+        let mut rest = path.trim();
+        if !rest.starts_with('/') {
+            return Err(invalid("An XML path must start with '/' or '//'"));
+        }
+        let mut steps = Vec::new();
+
+        loop {
+            let descendant = rest.starts_with("//");
+            rest = if descendant { &rest[2..] } else { &rest[1..] };
+
+            if let Some(attribute) = rest.strip_prefix('@') {
+                if attribute.is_empty() || attribute.contains(['/', '[', ']', '@']) {
+                    return Err(invalid(
+                        "Expected an attribute name or '*' as the last step",
+                    ));
+                }
+                let name = (attribute != "*").then(|| attribute.to_string());
+                return Ok(Self {
+                    steps,
+                    target: Target::Attribute { descendant, name },
+                });
+            } else if rest == "text()" {
+                return Ok(Self {
+                    steps,
+                    target: Target::Text { descendant },
+                });
+            }
+
+            let name_end = rest.find(['/', '[']).unwrap_or(rest.len());
+            let name = match &rest[..name_end] {
+                "" => {
+                    return Err(invalid(
+                        "Expected a name, '*', '@name' or 'text()' after '/'",
+                    ))
+                }
+                "*" => None,
+                name if name.contains([']', '@', '(']) => {
+                    return Err(invalid(
+                        "Expected a name, '*', '@name' or 'text()' after '/'",
+                    ))
+                }
+                name => Some(name.to_string()),
+            };
+            rest = &rest[name_end..];
+
+            let mut predicates = Vec::new();
+            while let Some(bracketed) = rest.strip_prefix('[') {
+                let end = find_closing_bracket(bracketed).ok_or_else(|| invalid("Expected ']'"))?;
+                predicates.push(parse_predicate(&bracketed[..end]).ok_or_else(|| {
+                    invalid("Expected [1], [last()], [@name], [@name='value'], [name] or [name='value']")
+                })?);
+                rest = &bracketed[end + 1..];
+            }
+
+            steps.push(Step {
+                descendant,
+                name,
+                predicates,
+            });
+            if rest.is_empty() {
+                return Ok(Self {
+                    steps,
+                    target: Target::Text { descendant: false },
+                });
+            } else if !rest.starts_with('/') {
+                return Err(invalid("Expected '/' or '//' before each step"));
+            }
+        }
+    }
+
+    /// Selects the element text or attribute values at this path in the given XML or HTML text.
+    ///
+    /// The text of an element is its content without the whitespace around it, including any child elements.
+    /// An attribute value is selected without its quotes, and attributes without a value are left out.
+    /// Values within another selected value are left out, so the spans never overlap.
+    ///
+    /// # Returns
+    /// The start and end byte positions of each selected value, in the order they appear,
+    /// and how the value is escaped there.
+    pub fn select(&self, text: &str) -> Vec<(usize, usize, Escaping)> {
+        let root = parse_markup(text);
+        let mut elements = vec![&root];
+
+        for step in &self.steps {
+            let mut selected = Vec::new();
+            for parent in expand(elements, step.descendant) {
+                let mut matched: Vec<&Element> = parent
+                    .get_children()
+                    .iter()
+                    .filter(|child| {
+                        step.name
+                            .as_ref()
+                            .is_none_or(|name| child.get_name() == name)
+                    })
+                    .collect();
+                for predicate in &step.predicates {
+                    matched = apply_predicate(predicate, matched, text);
+                }
+                selected.extend(matched);
+            }
+            elements = selected;
+        }
+
+        let spans = match &self.target {
+            Target::Text { descendant } => expand(elements, *descendant)
+                .into_iter()
+                // The document itself has no text to select
+                .filter(|element| !element.get_name().is_empty())
+                .map(|element| element.get_text_span(text))
+                .collect(),
+            Target::Attribute { descendant, name } => expand(elements, *descendant)
+                .into_iter()
+                .flat_map(|element| element.get_attributes())
+                .filter(|attribute| {
+                    name.as_ref()
+                        .is_none_or(|name| attribute.get_name() == name)
+                })
+                .filter_map(|attribute| {
+                    let (start, end) = attribute.get_span()?;
+                    Some((start, end, attribute.get_escaping()))
+                })
+                .collect(),
+        };
+        to_spans(spans)
+    }
+}
+
+// Returns the elements themselves, or with every element within them for a descendant step.
+fn expand(elements: Vec<&Element>, descendant: bool) -> Vec<&Element> {
+    if descendant {
+        elements
+            .into_iter()
+            .flat_map(|element| element.get_descendants())
+            .collect()
+    } else {
+        elements
+    }
+}
+
+// Returns the position of the `]` that closes a predicate, skipping any within quotes.
+fn find_closing_bracket(bracketed: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in bracketed.char_indices() {
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(c),
+            (None, ']') => return Some(i),
+            (Some(open), _) if open == c => quote = None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_predicate(predicate: &str) -> Option<Predicate> {
+    let predicate = predicate.trim();
+    if predicate == "last()" {
+        return Some(Predicate::Last);
+    } else if let Ok(position) = predicate.parse::<usize>() {
+        return (position > 0).then_some(Predicate::Position(position));
+    }
+
+    let (is_attribute, rest) = match predicate.strip_prefix('@') {
+        Some(attribute) => (true, attribute),
+        None => (false, predicate),
+    };
+    let (name, value) = match rest.split_once('=') {
+        Some((name, value)) => (name.trim(), Some(parse_literal(value.trim())?)),
+        None => (rest.trim(), None),
+    };
+    if name.is_empty() || name.contains(|c: char| c.is_whitespace() || "[]/'\"()".contains(c)) {
+        return None;
+    }
+
+    let name = name.to_string();
+    Some(match (is_attribute, value) {
+        (true, None) => Predicate::HasAttribute(name),
+        (true, Some(value)) => Predicate::AttributeEquals(name, value),
+        (false, None) => Predicate::HasChild(name),
+        (false, Some(value)) => Predicate::ChildEquals(name, value),
+    })
+}
+
+// Parses a string in single or double quotes.
+fn parse_literal(literal: &str) -> Option<String> {
+    let quote = literal.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let inside = literal[1..].strip_suffix(quote)?;
+    (!inside.contains(quote)).then(|| inside.to_string())
+}
+
+// Keeps the elements, all matched by one step under one parent, that the predicate accepts.
+fn apply_predicate<'a>(
+    predicate: &Predicate,
+    elements: Vec<&'a Element>,
+    text: &str,
+) -> Vec<&'a Element> {
+    match predicate {
+        Predicate::Position(position) => elements.get(position - 1).copied().into_iter().collect(),
+        Predicate::Last => elements.last().copied().into_iter().collect(),
+        Predicate::HasAttribute(name) => elements
+            .into_iter()
+            .filter(|element| element.get_attribute(name).is_some())
+            .collect(),
+        Predicate::AttributeEquals(name, value) => elements
+            .into_iter()
+            .filter(|element| {
+                element
+                    .get_attribute(name)
+                    .is_some_and(|attribute| attribute.get_value() == value)
+            })
+            .collect(),
+        Predicate::HasChild(name) => elements
+            .into_iter()
+            .filter(|element| {
+                element
+                    .get_children()
+                    .iter()
+                    .any(|child| child.get_name() == name)
+            })
+            .collect(),
+        Predicate::ChildEquals(name, value) => elements
+            .into_iter()
+            .filter(|element| {
+                element.get_children().iter().any(|child| {
+                    child.get_name() == name && child.get_text(text).is_some_and(|t| t == *value)
+                })
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <version>1.0.0</version>
+  <dependencies>
+    <dependency scope="test">
+      <artifactId>junit</artifactId>
+      <version>4.12</version>
+    </dependency>
+    <dependency>
+      <artifactId>guava</artifactId>
+      <version> 31.1 </version>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+    fn select<'a>(path: &str, text: &'a str) -> Vec<&'a str> {
+        XmlPath::parse(path)
+            .unwrap()
+            .select(text)
+            .into_iter()
+            .map(|(start, end, _)| &text[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_steps_and_predicates() {
+        let xml_path = XmlPath::parse("/a//b[2][@id='x y'][c]/@href").unwrap();
+        assert_eq!(
+            xml_path.steps,
+            vec![
+                Step {
+                    descendant: false,
+                    name: Some(String::from("a")),
+                    predicates: vec![],
+                },
+                Step {
+                    descendant: true,
+                    name: Some(String::from("b")),
+                    predicates: vec![
+                        Predicate::Position(2),
+                        Predicate::AttributeEquals(String::from("id"), String::from("x y")),
+                        Predicate::HasChild(String::from("c")),
+                    ],
+                },
+            ]
+        );
+        assert_eq!(
+            xml_path.target,
+            Target::Attribute {
+                descendant: false,
+                name: Some(String::from("href")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_paths() {
+        for path in [
+            "a/b",
+            "/",
+            "/a/",
+            "/a[0]",
+            "/a[",
+            "/a[@]",
+            "/a[b=c]",
+            "/@id/b",
+            "/a/count()",
+        ] {
+            let error = XmlPath::parse(path).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", path);
+            assert!(error.to_string().starts_with("Invalid XML path"));
+        }
+    }
+
+    #[test]
+    fn test_selects_element_text() {
+        assert_eq!(select("/project/version", POM), vec!["1.0.0"]);
+        assert_eq!(select("//version", POM), vec!["1.0.0", "4.12", "31.1"]);
+        assert_eq!(
+            select("//dependency[last()]/artifactId", POM),
+            vec!["guava"]
+        );
+        assert_eq!(
+            select("//dependency[artifactId='junit']/version/text()", POM),
+            vec!["4.12"]
+        );
+        assert!(select("/version", POM).is_empty());
+    }
+
+    #[test]
+    fn test_selects_attribute_values() {
+        assert_eq!(select("//dependency/@scope", POM), vec!["test"]);
+        assert_eq!(
+            select("//dependency[@scope='test']/artifactId", POM),
+            vec!["junit"]
+        );
+        assert_eq!(
+            select("/project/@*", POM),
+            vec!["http://maven.apache.org/POM/4.0.0"]
+        );
+        assert_eq!(select("/project//@scope", POM), vec!["test"]);
+    }
+
+    #[test]
+    fn test_positions_count_siblings_under_each_parent() {
+        let text = "<r><a><b>1</b><b>2</b></a><a><b>3</b></a></r>";
+        assert_eq!(select("//b[1]", text), vec!["1", "3"]);
+        assert_eq!(select("/r/a/b[2]", text), vec!["2"]);
+    }
+
+    #[test]
+    fn test_leaves_out_nested_selections() {
+        let text = "<a><a>x</a></a>";
+        assert_eq!(select("//a", text), vec!["<a>x</a>"]);
+    }
+
+    #[test]
+    fn test_selects_how_values_are_escaped() {
+        let text = "<a x='1' y=2>&lt;b&gt;</a><c><![CDATA[<d>]]></c><e><f/></e>";
+        let escapings: Vec<Escaping> = XmlPath::parse("//*/text()")
+            .unwrap()
+            .select(text)
+            .into_iter()
+            .chain(XmlPath::parse("/a/@*").unwrap().select(text))
+            .map(|(_, _, escaping)| escaping)
+            .collect();
+
+        assert_eq!(
+            escapings,
+            vec![
+                Escaping::XmlText,
+                Escaping::XmlCData,
+                Escaping::None,
+                // The empty text of `<f/>`
+                Escaping::XmlText,
+                Escaping::XmlAttribute(Some('\'')),
+                Escaping::XmlAttribute(None),
+            ]
+        );
+    }
+}
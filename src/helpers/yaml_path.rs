@@ -0,0 +1,652 @@
+use super::key_path::{KeyPath, Node, NodeKind};
+use crate::strategies::enums::escaping::Escaping;
+use std::collections::HashMap;
+use std::io;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// A path of keys and indices, like `spec.template.spec.containers[0].image`,
+/// that selects scalar values in YAML documents.
+///
+/// The path is written as described for `KeyPath` and is applied to every document in the text.
+///
+/// # Example
+///
+/// ```
+/// use seer::helpers::yaml_path::YamlPath;
+///
+/// let yaml_path = YamlPath::parse("spec.containers[*].image").unwrap();
+/// let text = "spec:\n  containers:\n    - image: nginx:1 # pinned\n    - image: 'redis:7'\n";
+/// let values: Vec<&str> = yaml_path
+///     .select(text)
+///     .unwrap()
+///     .into_iter()
+///     .map(|(start, end, _)| &text[start..end])
+///     .collect();
+///
+/// assert_eq!(values, vec!["nginx:1", "redis:7"]);
+/// ```
+#[derive(Debug)]
+pub struct YamlPath {
+    key_path: KeyPath,
+}
+
+impl YamlPath {
+    /// Parses a YAML path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidInput` error if the path is not valid.
+    pub fn parse(path: &str) -> io::Result<Self> {
+        Ok(Self {
+            key_path: KeyPath::parse(path, "YAML")?,
+        })
+    }
+
+    /// Selects the scalar values at this path in every document of the given YAML text.
+    ///
+    /// A quoted scalar is selected without its quotes, and a block scalar (`|` or `>`)
+    /// from the first to the last character of its lines, so edits keep the style of the value.
+    /// Each is still escaped as written, which the returned `Escaping` undoes.
+    /// Comments after a value are never part of it. Mappings and sequences are not selected.
+    ///
+    /// An alias, like `*base`, selects from the value its anchor is on, so that value is where it is edited.
+    ///
+    /// # Returns
+    /// The start and end byte positions of each selected value, in the order they appear, and how it is escaped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidData` error if the text is not valid YAML.
+    pub fn select(&self, text: &str) -> io::Result<Vec<(usize, usize, Escaping)>> {
+        let mut builder = DocumentBuilder::new(text);
+        Parser::new_from_str(text)
+            .load(&mut builder, true)
+            .map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("Invalid YAML. {}", e))
+            })?;
+
+        let scalars: Vec<&Node> = self
+            .key_path
+            .select(&builder.documents)
+            .into_iter()
+            .filter(|node| node.is_scalar())
+            .collect();
+        Ok(KeyPath::to_escaped_spans(&scalars))
+    }
+}
+
+/// Decodes the value of a YAML scalar from the text a hit with the given escaping spans.
+///
+/// Returns `None` if the text is not a scalar written that way.
+pub fn unescape_yaml_scalar(raw: &str, escaping: Escaping) -> Option<String> {
+    let scalar = match escaping {
+        Escaping::YamlSingleQuoted => format!("'{}'", raw),
+        Escaping::YamlDoubleQuoted => format!("\"{}\"", raw),
+        // The first line is indented like the others, as it was after the `|` or `>`
+        Escaping::YamlLiteral(indent) => format!("|-\n{}{}", " ".repeat(indent), raw),
+        Escaping::YamlFolded(indent) => format!(">-\n{}{}", " ".repeat(indent), raw),
+        _ => String::from(raw),
+    };
+    parse_scalar(&scalar)
+}
+
+/// Escapes a text to replace a YAML scalar that a hit with the given escaping spans.
+///
+/// A plain scalar is kept plain if it reads back as the same text, and is double-quoted otherwise.
+/// Lines after the first in a block scalar are indented like its other lines.
+///
+/// # Errors
+///
+/// Returns an `InvalidInput` error for a line break or control character in a single-quoted scalar,
+/// since where its lines may start is not known, or a space at the start of a block scalar.
+pub fn escape_yaml_scalar(text: &str, escaping: Escaping) -> io::Result<String> {
+    let line_break = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let lines = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line));
+
+    match escaping {
+        Escaping::YamlPlain => {
+            // Flow indicators end a plain scalar in a flow collection
+            let is_plain = !text.contains([',', '[', ']', '{', '}'])
+                && parse_scalar(text).as_deref() == Some(text);
+            if is_plain {
+                Ok(String::from(text))
+            } else {
+                Ok(format!("\"{}\"", escape_double_quoted(text)))
+            }
+        }
+        Escaping::YamlSingleQuoted => {
+            if text.contains(|c: char| c.is_control() && c != '\t') {
+                return Err(unwritable(text, "a single-quoted"));
+            }
+            Ok(text.replace('\'', "''"))
+        }
+        Escaping::YamlDoubleQuoted => Ok(escape_double_quoted(text)),
+        Escaping::YamlLiteral(indent) | Escaping::YamlFolded(indent) => {
+            if text.starts_with(' ') {
+                return Err(unwritable(text, "a block"));
+            }
+
+            let is_folded = matches!(escaping, Escaping::YamlFolded(_));
+            let mut escaped = String::with_capacity(text.len());
+            let mut after_content = false;
+            for (index, line) in lines.enumerate() {
+                if index > 0 {
+                    // A single line break between lines of a folded scalar reads as a space
+                    if is_folded && after_content {
+                        escaped.push_str(line_break);
+                    }
+                    escaped.push_str(line_break);
+                    if !line.is_empty() {
+                        escaped.push_str(&" ".repeat(indent));
+                    }
+                }
+                escaped.push_str(line);
+                after_content = !line.is_empty();
+            }
+            Ok(escaped)
+        }
+        _ => Ok(String::from(text)),
+    }
+}
+
+// Escapes a text to be the contents of a double-quoted scalar.
+fn escape_double_quoted(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            control if control.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", u32::from(control)))
+            }
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn unwritable(text: &str, style: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("'{}' cannot be written in {} YAML scalar", text, style),
+    )
+}
+
+// Returns the value of the text if it is a single scalar, without an anchor or tag.
+fn parse_scalar(text: &str) -> Option<String> {
+    let mut parser = Parser::new_from_str(text);
+    let mut value = None;
+    loop {
+        match parser.next_token().ok()?.0 {
+            Event::Scalar(scalar, _, 0, None) if value.is_none() => value = Some(scalar),
+            Event::StreamStart | Event::DocumentStart | Event::DocumentEnd => {}
+            Event::StreamEnd => return value,
+            _ => return None,
+        }
+    }
+}
+
+// A mapping or sequence whose members are still being parsed.
+// Only scalars are selected, so the span of a collection is only roughly where it is.
+enum Collection {
+    Mapping {
+        start: usize,
+        anchor: usize,
+        is_flow: bool,
+        members: Vec<(String, Node)>,
+        // The key of the member whose value is next, or `None` while a key is next.
+        // A key that is not a scalar has no name to select it by.
+        key: Option<Option<String>>,
+    },
+    Sequence {
+        start: usize,
+        anchor: usize,
+        is_flow: bool,
+        elements: Vec<Node>,
+    },
+}
+
+// Builds a `Node` for each document from the parser's events.
+struct DocumentBuilder<'a> {
+    text: &'a str,
+    // The byte position of each character, since markers count characters
+    char_positions: Vec<usize>,
+    // The collections being parsed, innermost last
+    open: Vec<Collection>,
+    documents: Vec<Node>,
+    // The value each anchor is on, with its name if it is a scalar, so aliases can be resolved
+    anchors: HashMap<usize, (Node, Option<String>)>,
+}
+
+impl<'a> DocumentBuilder<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut char_positions: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        char_positions.push(text.len());
+        Self {
+            text,
+            char_positions,
+            open: Vec::new(),
+            documents: Vec::new(),
+            anchors: HashMap::new(),
+        }
+    }
+
+    fn get_position(&self, mark: &Marker) -> usize {
+        self.char_positions[mark.index().min(self.char_positions.len() - 1)]
+    }
+
+    // Returns true if the next node is the key of a mapping member.
+    fn is_key_next(&self) -> bool {
+        matches!(
+            self.open.last(),
+            Some(Collection::Mapping { key: None, .. })
+        )
+    }
+
+    // Returns true if the next node is within a flow collection, like `[a, b]`.
+    fn is_in_flow(&self) -> bool {
+        matches!(
+            self.open.last(),
+            Some(
+                Collection::Mapping { is_flow: true, .. }
+                    | Collection::Sequence { is_flow: true, .. }
+            )
+        )
+    }
+
+    // Adds a parsed node to the collection it is in, or as a document.
+    // `name` is the node's value if it is a scalar, used when the node is a key.
+    fn add(&mut self, node: Node, name: Option<String>) {
+        match self.open.last_mut() {
+            None => self.documents.push(node),
+            Some(Collection::Sequence { elements, .. }) => elements.push(node),
+            Some(Collection::Mapping { members, key, .. }) => match key.take() {
+                None => *key = Some(name),
+                Some(Some(name)) => members.push((name, node)),
+                Some(None) => {}
+            },
+        }
+    }
+
+    // Remembers the value an anchor is on, then adds it.
+    fn add_anchored(&mut self, anchor: usize, node: Node, name: Option<String>) {
+        if anchor != 0 {
+            self.anchors.insert(anchor, (node.clone(), name.clone()));
+        }
+        self.add(node, name);
+    }
+
+    fn add_scalar(&mut self, value: String, style: TScalarStyle, anchor: usize, mark: &Marker) {
+        let start = self.get_position(mark);
+        if self.is_key_next() {
+            let key = Node::new(start, start, NodeKind::Scalar);
+            self.add_anchored(anchor, key, Some(value));
+            return;
+        }
+
+        // An empty value, like `key:`, has no text to span
+        let node = if style == TScalarStyle::Plain && value.is_empty() {
+            get_placeholder(start)
+        } else {
+            let indent = start
+                - self.text[..start]
+                    .rfind('\n')
+                    .map_or(0, |newline| newline + 1);
+            let (start, end, escaping) = match style {
+                TScalarStyle::SingleQuoted => (
+                    start + 1,
+                    get_quoted_end(self.text, start),
+                    Escaping::YamlSingleQuoted,
+                ),
+                TScalarStyle::DoubleQuoted => (
+                    start + 1,
+                    get_quoted_end(self.text, start),
+                    Escaping::YamlDoubleQuoted,
+                ),
+                TScalarStyle::Literal => (
+                    start,
+                    get_block_end(self.text, start),
+                    Escaping::YamlLiteral(indent),
+                ),
+                TScalarStyle::Folded => (
+                    start,
+                    get_block_end(self.text, start),
+                    Escaping::YamlFolded(indent),
+                ),
+                _ => (
+                    start,
+                    get_plain_end(self.text, start, value.len(), self.is_in_flow()),
+                    Escaping::YamlPlain,
+                ),
+            };
+            let mut node = Node::new(start, end, NodeKind::Scalar);
+            node.set_escaping(escaping);
+            node
+        };
+        self.add_anchored(anchor, node, Some(value));
+    }
+
+    fn close(&mut self, mark: &Marker) {
+        let end = self.get_position(mark);
+        let (anchor, node) = match self.open.pop() {
+            Some(Collection::Mapping {
+                start,
+                anchor,
+                members,
+                ..
+            }) => (anchor, Node::new(start, end, NodeKind::Mapping(members))),
+            Some(Collection::Sequence {
+                start,
+                anchor,
+                elements,
+                ..
+            }) => (anchor, Node::new(start, end, NodeKind::Sequence(elements))),
+            None => return,
+        };
+        self.add_anchored(anchor, node, None);
+    }
+}
+
+impl MarkedEventReceiver for DocumentBuilder<'_> {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        match event {
+            Event::Scalar(value, style, anchor, _) => self.add_scalar(value, style, anchor, &mark),
+            Event::MappingStart(anchor, _) => {
+                let start = self.get_position(&mark);
+                self.open.push(Collection::Mapping {
+                    start,
+                    anchor,
+                    is_flow: self.text[start..].starts_with('{'),
+                    members: Vec::new(),
+                    key: None,
+                });
+            }
+            Event::SequenceStart(anchor, _) => {
+                let start = self.get_position(&mark);
+                self.open.push(Collection::Sequence {
+                    start,
+                    anchor,
+                    is_flow: self.text[start..].starts_with('['),
+                    elements: Vec::new(),
+                });
+            }
+            Event::MappingEnd | Event::SequenceEnd => self.close(&mark),
+            Event::Alias(anchor) => match self.anchors.get(&anchor).cloned() {
+                Some((node, name)) => self.add(node, name),
+                // An alias within the value its anchor is on
+                None => {
+                    let start = self.get_position(&mark);
+                    self.add(get_placeholder(start), None);
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+// Returns a node that keeps the place of a value that cannot be selected, like an empty value.
+fn get_placeholder(position: usize) -> Node {
+    Node::new(position, position, NodeKind::Sequence(Vec::new()))
+}
+
+// Returns the position of the quote that closes the quoted scalar starting at `start`.
+fn get_quoted_end(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+    let quote = bytes[start];
+    let mut pos = start + 1;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            // A single quote is escaped by doubling it
+            b'\'' if quote == b'\'' && bytes.get(pos + 1) == Some(&b'\'') => pos += 2,
+            b'\\' if quote == b'"' => pos += 2,
+            byte if byte == quote => return pos,
+            _ => pos += 1,
+        }
+    }
+    text.len()
+}
+
+// Returns the end of the last line of the block scalar whose content starts at `start`.
+// The content continues while lines are blank or indented at least as far as its first line.
+fn get_block_end(text: &str, start: usize) -> usize {
+    let line_start = text[..start].rfind('\n').map_or(0, |newline| newline + 1);
+    let indent = start - line_start;
+    let mut end = get_line_end(text, start);
+    let mut pos = end;
+
+    while let Some(newline) = text[pos..].find('\n') {
+        let next_line = pos + newline + 1;
+        let line_end = get_line_end(text, next_line);
+        let line = &text[next_line..line_end];
+        if !line.trim().is_empty() {
+            if line.len() - line.trim_start_matches(' ').len() < indent {
+                break;
+            }
+            end = line_end;
+        }
+        pos = line_end;
+    }
+
+    end
+}
+
+// Returns the end of the plain scalar starting at `start`, whose folded value is `value_len` bytes.
+// A plain scalar folds its lines into one, so it continues on the next lines until that length is reached.
+fn get_plain_end(text: &str, start: usize, value_len: usize, is_in_flow: bool) -> usize {
+    let mut end = get_plain_line_end(text, start, is_in_flow);
+    let mut folded_len = end - start;
+    let mut line_breaks = 0;
+    let mut pos = end;
+
+    while folded_len < value_len {
+        let Some(newline) = text[pos..].find('\n') else {
+            break;
+        };
+        let line_start = pos + newline + 1;
+        let line = &text[line_start..get_line_end(text, line_start)];
+        let content_start = line_start + line.len() - line.trim_start().len();
+        pos = content_start;
+        if line.trim().is_empty() {
+            line_breaks += 1;
+            continue;
+        }
+
+        // A blank line folds into a line break, and a line break between content into a space
+        let content_end = get_plain_line_end(text, content_start, is_in_flow);
+        folded_len += line_breaks.max(1) + content_end - content_start;
+        line_breaks = 0;
+        end = content_end;
+        pos = content_end;
+    }
+
+    end
+}
+
+// Returns the end of the part of a plain scalar on the line starting at `start`.
+// It ends before a comment and, within a flow collection, before `,`, `]` or `}`.
+fn get_plain_line_end(text: &str, start: usize, is_in_flow: bool) -> usize {
+    let line = &text[start..get_line_end(text, start)];
+    let mut end = line.len();
+    if let Some(comment) = line.find(" #").or_else(|| line.find("\t#")) {
+        end = comment;
+    }
+    if is_in_flow {
+        if let Some(indicator) = line[..end].find([',', ']', '}']) {
+            end = indicator;
+        }
+    }
+    start + line[..end].trim_end().len()
+}
+
+// Returns the end of the line containing `pos`, before its `\n` or `\r\n` terminator.
+fn get_line_end(text: &str, pos: usize) -> usize {
+    let end = text[pos..]
+        .find('\n')
+        .map_or(text.len(), |newline| pos + newline);
+    if end > pos && text.as_bytes()[end - 1] == b'\r' {
+        end - 1
+    } else {
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select<'a>(path: &str, text: &'a str) -> Vec<&'a str> {
+        YamlPath::parse(path)
+            .unwrap()
+            .select(text)
+            .unwrap()
+            .into_iter()
+            .map(|(start, end, _)| &text[start..end])
+            .collect()
+    }
+
+    fn select_values(path: &str, text: &str) -> Vec<String> {
+        YamlPath::parse(path)
+            .unwrap()
+            .select(text)
+            .unwrap()
+            .into_iter()
+            .map(|(start, end, escaping)| {
+                unescape_yaml_scalar(&text[start..end], escaping).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_selects_scalars_in_every_document() {
+        let text = "kind: Deployment\nspec:\n  template:\n    spec:\n      containers:\n        - name: web\n          image: nginx:1.25 # bump me\n---\nkind: Service\nspec:\n  ports: [80, 443]\n";
+        assert_eq!(
+            select("spec.template.spec.containers[0].image", text),
+            vec!["nginx:1.25"]
+        );
+        assert_eq!(select("kind", text), vec!["Deployment", "Service"]);
+        assert_eq!(select("spec.ports[*]", text), vec!["80", "443"]);
+    }
+
+    #[test]
+    fn test_selects_quoted_scalars_without_quotes() {
+        let text = "a: 'it''s'\nb: \"say \\\"hi\\\"\"\nc: !!str \"tagged\"\n";
+        assert_eq!(select("a", text), vec!["it''s"]);
+        assert_eq!(select("b", text), vec!["say \\\"hi\\\""]);
+        assert_eq!(select("c", text), vec!["tagged"]);
+    }
+
+    #[test]
+    fn test_selects_block_and_multi_line_scalars() {
+        let text =
+            "script: |\n  echo one\n\n  echo two\nnext: a plain\n  value\n\n  folded\nlast: x\n";
+        assert_eq!(select("script", text), vec!["echo one\n\n  echo two"]);
+        assert_eq!(select("next", text), vec!["a plain\n  value\n\n  folded"]);
+        assert_eq!(select("last", text), vec!["x"]);
+    }
+
+    #[test]
+    fn test_selects_scalars_in_flow_collections() {
+        let text = "env: {name: app, tag: v1}   # flow\nargs: [\"-v\", run ]\r\n";
+        assert_eq!(select("env.tag", text), vec!["v1"]);
+        assert_eq!(select("args[*]", text), vec!["-v", "run"]);
+    }
+
+    #[test]
+    fn test_skips_collections_and_empty_values() {
+        let text = "base:\n  image: nginx\nempty:\nlist:\n  - [a]\n  - x\n";
+        assert!(select("base", text).is_empty());
+        assert!(select("empty", text).is_empty());
+        assert!(select("list[0]", text).is_empty());
+        assert_eq!(select("list[1]", text), vec!["x"]);
+    }
+
+    #[test]
+    fn test_selects_through_aliases_at_their_anchors() {
+        let text =
+            "base: &base\n  image: nginx\ntag: &tag v1\nweb: *base\nf: *tag\nlist:\n  - *base\n";
+        assert_eq!(select("web.image", text), vec!["nginx"]);
+        assert_eq!(select("f", text), vec!["v1"]);
+        assert_eq!(select("list[0].image", text), vec!["nginx"]);
+        // Each anchored value is selected once, however many aliases lead to it
+        assert_eq!(select("..image", text), vec!["nginx"]);
+    }
+
+    #[test]
+    fn test_decodes_scalars_in_every_style() {
+        let text = "a: 'it''s'\nb: \"say \\\"hi\\\"\\n\"\nc: plain\n  folded\nd: |\n  one\n   two\ne: >\n  one\n  two\n\n  three\n";
+        assert_eq!(
+            select_values("*", text),
+            vec![
+                "it's",
+                "say \"hi\"\n",
+                "plain folded",
+                "one\n two",
+                "one two\nthree"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escapes_values_to_keep_the_scalar_style() {
+        let value = "it's \"a\": b\\c";
+        let cases = [
+            (Escaping::YamlPlain, "a: x\n", "\"it's \\\"a\\\": b\\\\c\""),
+            (Escaping::YamlSingleQuoted, "a: 'x'\n", "it''s \"a\": b\\c"),
+            (
+                Escaping::YamlDoubleQuoted,
+                "a: \"x\"\n",
+                "it's \\\"a\\\": b\\\\c",
+            ),
+        ];
+
+        for (escaping, text, escaped) in cases {
+            assert_eq!(escape_yaml_scalar(value, escaping).unwrap(), escaped);
+            let (start, end, _) = YamlPath::parse("a").unwrap().select(text).unwrap()[0];
+            let edited = format!("{}{}{}", &text[..start], escaped, &text[end..]);
+            assert_eq!(select_values("a", &edited), vec![value]);
+        }
+    }
+
+    #[test]
+    fn test_escapes_line_breaks_to_keep_the_scalar_style() {
+        let value = "one\ntwo\n\nthree";
+        let cases = [
+            ("a", "a: x\nb: y\n"),
+            ("a", "a: \"x\"\nb: y\n"),
+            ("a[0]", "a:\n  - |\n    x\nb: y\n"),
+            ("a[0]", "a:\n  - >\n    x\nb: y\n"),
+        ];
+
+        for (path, text) in cases {
+            let (start, end, escaping) = YamlPath::parse(path).unwrap().select(text).unwrap()[0];
+            let escaped = escape_yaml_scalar(value, escaping).unwrap();
+            let edited = format!("{}{}{}", &text[..start], escaped, &text[end..]);
+            assert_eq!(select_values(path, &edited), vec![value], "{}", edited);
+            assert_eq!(select_values("b", &edited), vec!["y"], "{}", edited);
+        }
+
+        assert!(escape_yaml_scalar(value, Escaping::YamlSingleQuoted).is_err());
+    }
+
+    #[test]
+    fn test_counts_characters_before_the_value() {
+        let text = "name: café\nimage: nginx\n";
+        assert_eq!(select("image", text), vec!["nginx"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_yaml() {
+        let error = YamlPath::parse("a")
+            .unwrap()
+            .select("a: [1, 2\n")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -8,7 +8,7 @@ pub fn get_source(inputs: &ArgMatches, pipe: &str, dto: &mut DTO) {
 
 use crate::read_file;
 use clap::ArgMatches;
-use std::io::{self, Read};
+use std::io::{self, IsTerminal, Read};
 
 /// Extracts the searchable body of text from the `--text` option, `--file` option, or piped input.
 ///
@@ -92,8 +92,13 @@ mod get_searchable_tests {
 /// # Returns
 ///
 /// An `Option<String>` containing the piped input if available.
+/// Stdin is not read when it is a terminal, since that would wait for input forever.
 ///
 pub fn read_pipe() -> Option<String> {
+    if io::stdin().is_terminal() {
+        return None;
+    }
+
     let mut pipe = String::new();
     let could_read_input = io::stdin().read_to_string(&mut pipe).is_ok();
 
@@ -1,6 +1,15 @@
-use seer::arg_buliders::build_command;
+use seer::dto::dto::DTO;
 use seer::handlers::build_command_handler::BuildCommandHandler;
-use seer::handlers::build_dto_handler::CommandData;
+use seer::handlers::edit_handler::EditHandler;
+use seer::handlers::get_sources_handler::GetSourcesHandler;
+use seer::handlers::handler::Handler;
+use seer::handlers::print_handler::PrintHandler;
+use seer::handlers::search_handler::SearchHandler;
+use seer::handlers::stream_handler::StreamHandler;
+use seer::handlers::write_handler::WriteHandler;
+use seer::strategy_factories::edit_strategy_factory::EditStrategyFactory;
+use std::io::{self, IsTerminal};
+use std::process;
 
 /// The main function.
 ///
@@ -10,16 +19,40 @@ use seer::handlers::build_dto_handler::CommandData;
 /// cargo run -- --find_string "foo" --text "foobar"
 /// ```
 fn main() {
-    // TODO: Pass command data to dto and then pass dto to BuildCommandHandler
-    // TODO: BuildCommandHandler can read any piped data from stdin and add it to the dto
-    let dto = DTO::new(
+    let mut dto = DTO::new(
         "seer",
         "1.0",
         "Ryan Ogden",
         "Search, Edit, Evaluate, and Replace text.",
     );
 
-    BuildCommandHandler::new().build_command(&mut dto);
+    BuildCommandHandler.handle(&mut dto);
     // ValidateHandler::new().validate(&mut dto);
-    GetSourcesHandler::new().get_sources(&mut dto);
+
+    let pipe = !io::stdin().is_terminal();
+
+    if dto.get_input::<bool>("stream").copied().unwrap_or(false) {
+        StreamHandler::new(pipe, io::stdout().lock(), io::stderr().lock()).handle(&mut dto);
+        return;
+    }
+
+    GetSourcesHandler::new(pipe, io::stderr().lock()).handle(&mut dto);
+    SearchHandler::new(io::stderr().lock()).handle(&mut dto);
+
+    let editing = dto
+        .get_inputs()
+        .as_ref()
+        .is_some_and(|inputs| EditStrategyFactory::make(inputs).is_some());
+
+    if editing {
+        EditHandler.handle(&mut dto);
+        WriteHandler::new(pipe, io::stdout().lock(), io::stderr().lock()).handle(&mut dto);
+    } else {
+        PrintHandler::new(io::stdout().lock(), io::stderr().lock()).handle(&mut dto);
+    }
+
+    // The failures were reported, but the exit status must show that sources were not searched or edits were lost
+    if !dto.get_unsearched_sources().is_empty() || !dto.get_unwritten_sources().is_empty() {
+        process::exit(1);
+    }
 }
@@ -9,7 +9,10 @@ use crate::dto::dto::DTO;
 /// ```
 /// let strategy = AllStrategy::new();
 /// ```
-pub struct AllStrategy {}
+#[derive(Default)]
+pub struct AllStrategy {
+    counter: usize,
+}
 
 impl AllStrategy {
     /// Creates a new AllStrategy instance.
@@ -19,7 +22,7 @@ impl AllStrategy {
     /// let strategy = AllStrategy::new();
     /// ```
     pub fn new() -> Self {
-        Self {}
+        Self::default()
     }
 }
 
@@ -40,8 +43,9 @@ impl Strategy for AllStrategy {
     /// # Example
     /// ```
     /// let mut dto = DTO::new("cmd", "1.0", "author", "about");
-    /// dto.add_hit(Hit::new("hit1", 0));
-    /// dto.add_hit(Hit::new("hit2", 1));
+    /// dto.add_source("source", "hit1 hit2");
+    /// dto.add_hit(Hit::new(0, 4), 0);
+    /// dto.add_hit(Hit::new(5, 4), 0);
     /// let strategy = AllStrategy::new();
     /// strategy.run(&mut dto);
     /// // Validate all hits are included
@@ -49,34 +53,43 @@ impl Strategy for AllStrategy {
     /// assert_eq!(hits.len(), 2);
     /// ```
     fn run(&self, dto: &mut DTO) {
-        dto
+        dto.retain_hits(|count| self.selects(count));
     }
 }
 
 impl FrequencyStrategy for AllStrategy {
-    /// Returns true if the frequency matches.
+    /// Returns true if the hit should be kept.
     /// For the AllStrategy, the function always returns true.
     /// Every hit is considered a match.
     ///
     /// # Example
     /// ```
-    /// let mut strategy = AllStrategy::new();
-    /// assert!(strategy.matches_frequency());
+    /// let strategy = AllStrategy::new();
+    /// assert!(strategy.selects(1));
+    /// assert!(strategy.selects(2));
     /// ```
-    fn matches_frequency(&mut self) -> bool {
+    fn selects(&self, _count: usize) -> bool {
         true
     }
 
-    /// Returns false if the strategy is not done.
+    /// Returns false if hits after the given count can still be kept.
     /// For the AllStrategy, the function always returns false.
     /// The frequeny will never be met before the search finishes.
     ///
     /// # Example
     /// ```
     /// let strategy = AllStrategy::new();
-    /// assert!(!strategy.is_done());
+    /// assert!(!strategy.is_exhausted_after(100));
     /// ```
-    fn is_done(&self) -> bool {
+    fn is_exhausted_after(&self, _count: usize) -> bool {
         false
     }
+
+    fn get_counter(&self) -> usize {
+        self.counter
+    }
+
+    fn set_counter(&mut self, counter: usize) {
+        self.counter = counter;
+    }
 }
@@ -0,0 +1,42 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::edit_strategy::EditStrategy;
+use crate::helpers::lines::with_line_ending;
+
+/// An edit strategy that appends the string given with `--append` to each hit.
+pub struct AppendEditStrategy {
+    suffix: String,
+}
+
+impl AppendEditStrategy {
+    pub fn new(suffix: String) -> Self {
+        Self { suffix }
+    }
+}
+
+impl EditStrategy for AppendEditStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Append
+    }
+
+    fn edit(&self, value: &str, line_ending: &str) -> String {
+        format!("{}{}", value, with_line_ending(&self.suffix, line_ending))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_edit_strategy() {
+        let strategy = AppendEditStrategy::new(String::from("bar"));
+        assert_eq!(strategy.edit("foo", "\n"), "foobar");
+        assert_eq!(strategy.strategy_type(), StrategyType::Append);
+    }
+
+    #[test]
+    fn test_append_uses_the_line_ending() {
+        let strategy = AppendEditStrategy::new(String::from("\nbar"));
+        assert_eq!(strategy.edit("foo\r\n", "\r\n"), "foo\r\n\r\nbar");
+    }
+}
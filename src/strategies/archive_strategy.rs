@@ -0,0 +1,190 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::helpers::archive::{list_archive, ArchiveMember, ArchiveReader, MEMBER_SEPARATOR};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::io::{self, Cursor, Read};
+
+/// A source strategy that searches every file in the archive given with `--archive`.
+///
+/// Each file is its own source, named like `archive.zip!/path/in/archive`. A path the archive holds
+/// more than once gets a numbered name for each later copy, like `archive.zip!/path/in/archive (2)`.
+/// The archive is listed the first time its files are needed, and each file is only read when it is opened.
+/// Sources are read-only, so they cannot be edited in place.
+pub struct ArchiveSourceStrategy {
+    archive_path: String,
+    members: OnceCell<ArchiveIndex>,
+    reader: RefCell<Option<ArchiveReader>>,
+}
+
+// The files of an archive by source name, with the names in the order the archive lists them.
+struct ArchiveIndex {
+    names: Vec<String>,
+    members: HashMap<String, ArchiveMember>,
+}
+
+impl ArchiveSourceStrategy {
+    pub fn new(archive_path: String) -> Self {
+        Self {
+            archive_path,
+            members: OnceCell::new(),
+            reader: RefCell::new(None),
+        }
+    }
+
+    // Returns the files in the archive, listing it on first use.
+    fn get_members(&self) -> io::Result<&ArchiveIndex> {
+        if let Some(members) = self.members.get() {
+            return Ok(members);
+        }
+
+        let mut index = ArchiveIndex {
+            names: Vec::new(),
+            members: HashMap::new(),
+        };
+        for member in list_archive(&self.archive_path)? {
+            let mut name = format!("{}{}{}", self.archive_path, MEMBER_SEPARATOR, member.path);
+            let mut copy = 1;
+            while index.members.contains_key(&name) {
+                copy += 1;
+                name = format!(
+                    "{}{}{} ({})",
+                    self.archive_path, MEMBER_SEPARATOR, member.path, copy
+                );
+            }
+            index.names.push(name.clone());
+            index.members.insert(name, member);
+        }
+
+        Ok(self.members.get_or_init(|| index))
+    }
+}
+
+impl SourceStrategy for ArchiveSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Archive
+    }
+
+    /// Returns a source for each file in the archive, named by the archive path and its path inside.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        Ok(self.get_members()?.names.clone())
+    }
+
+    /// Reads the file with the given name out of the archive.
+    ///
+    /// Only this file is held in memory, and the archive is kept open for the next one.
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        let member = self.get_members()?.members.get(name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Failed to read source: '{}'. No such file in the archive",
+                    name
+                ),
+            )
+        })?;
+
+        let mut reader = self.reader.borrow_mut();
+        let reader = match reader.as_mut() {
+            Some(reader) => reader,
+            None => reader.insert(ArchiveReader::new(&self.archive_path)?),
+        };
+
+        Ok(Box::new(Cursor::new(reader.read_member(member)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+    use zip::write::SimpleFileOptions;
+
+    fn get_zip_file() -> NamedTempFile {
+        let temp_file = NamedTempFile::with_suffix(".zip").unwrap();
+        let mut writer = zip::ZipWriter::new(temp_file.reopen().unwrap());
+        writer
+            .start_file("a.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"first").unwrap();
+        writer
+            .start_file("sub/b.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"second").unwrap();
+        writer.finish().unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_archive_source_strategy() {
+        let temp_file = get_zip_file();
+        let archive_path = temp_file.path().to_str().unwrap();
+
+        let strategy = ArchiveSourceStrategy::new(String::from(archive_path));
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(
+            names,
+            vec![
+                format!("{}!/a.txt", archive_path),
+                format!("{}!/sub/b.txt", archive_path),
+            ]
+        );
+        assert_eq!(strategy.read(&names[0]).unwrap(), "first");
+        assert_eq!(&*strategy.load(&names[1], None).unwrap(), "second");
+    }
+
+    #[test]
+    fn test_archive_source_strategy_is_read_only() {
+        let temp_file = get_zip_file();
+        let archive_path = temp_file.path().to_str().unwrap();
+
+        let strategy = ArchiveSourceStrategy::new(String::from(archive_path));
+        let name = format!("{}!/a.txt", archive_path);
+
+        assert_eq!(
+            strategy.write(&name, b"edited").unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_archive_source_strategy_with_missing_member() {
+        let temp_file = get_zip_file();
+        let archive_path = temp_file.path().to_str().unwrap();
+
+        let strategy = ArchiveSourceStrategy::new(String::from(archive_path));
+
+        assert!(strategy.read(&format!("{}!/c.txt", archive_path)).is_err());
+    }
+
+    #[test]
+    fn test_archive_source_strategy_keeps_files_with_the_same_path_apart() {
+        let temp_file = NamedTempFile::with_suffix(".tar").unwrap();
+        let mut builder = tar::Builder::new(temp_file.reopen().unwrap());
+        for contents in ["old", "new"] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, "a.txt", contents.as_bytes())
+                .unwrap();
+        }
+        builder.finish().unwrap();
+        let archive_path = temp_file.path().to_str().unwrap();
+
+        let strategy = ArchiveSourceStrategy::new(String::from(archive_path));
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(
+            names,
+            vec![
+                format!("{}!/a.txt", archive_path),
+                format!("{}!/a.txt (2)", archive_path),
+            ]
+        );
+        assert_eq!(strategy.read(&names[0]).unwrap(), "old");
+        assert_eq!(strategy.read(&names[1]).unwrap(), "new");
+    }
+}
@@ -0,0 +1,365 @@
+use super::enums::column::Column;
+use super::enums::escaping::Escaping;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::delimited::{parse_records, Field};
+use std::io;
+
+/// A search strategy that matches another search strategy within the fields of delimited records,
+/// like CSV or TSV.
+///
+/// Patterns never see past the end of a field, so a match cannot cross into the next one.
+/// Quoted fields are searched by their value, without the quotes and with doubled quotes unescaped,
+/// and hits map back to the text so that edits leave every other byte as it was.
+/// Text written into a quoted field has its quotes doubled, and a whole field without quotes
+/// is put in quotes if its new value holds the delimiter, a quote, or a line break.
+///
+/// The first record is a header unless told otherwise, and is never searched.
+/// With a column, only that column's fields are searched. A column given by name is looked up
+/// in the header. A source without the column has no hits, which `check` reports.
+pub struct ColumnSearchStrategy {
+    search_strategy: Box<dyn SearchStrategy>,
+    delimiter: char,
+    column: Option<Column>,
+    has_header: bool,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl ColumnSearchStrategy {
+    pub fn new(
+        search_strategy: Box<dyn SearchStrategy>,
+        delimiter: char,
+        column: Option<Column>,
+        has_header: bool,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Self {
+        Self {
+            search_strategy,
+            delimiter,
+            column,
+            has_header,
+            frequency_strategy,
+        }
+    }
+
+    // Returns the index of the searched column, if any.
+    //
+    // A column given by name needs a header with that name, unless there are no records at all.
+    fn get_column_index(&self, records: &[Vec<Field>]) -> io::Result<Option<usize>> {
+        match &self.column {
+            None => Ok(None),
+            Some(Column::Index(index)) => Ok(Some(index.saturating_sub(1))),
+            Some(Column::Name(name)) if !self.has_header => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Column '{}' is given by name, but there is no header", name),
+            )),
+            Some(Column::Name(name)) => match records.first() {
+                None => Ok(None),
+                Some(header) => header
+                    .iter()
+                    .position(|field| field.get_value() == name)
+                    .map(Some)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::NotFound,
+                            format!("The header has no column '{}'", name),
+                        )
+                    }),
+            },
+        }
+    }
+
+    // Returns how a hit in the field's value is escaped in the text.
+    fn get_escaping(&self, field: &Field, field_hit: &Hit) -> Escaping {
+        if field.is_quoted() {
+            Escaping::DelimitedQuoted
+        } else if field_hit.get_position() == 0 && field_hit.get_length() == field.get_value().len()
+        {
+            Escaping::DelimitedField(self.delimiter)
+        } else {
+            Escaping::DelimitedFieldPart(self.delimiter)
+        }
+    }
+}
+
+impl SearchStrategy for ColumnSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Column
+    }
+
+    /// Searches each field in the column, or every field without one.
+    ///
+    /// # Parameters
+    /// - `searchable`: The delimited text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs with positions in the searchable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::column_strategy::ColumnSearchStrategy;
+    /// use seer::strategies::enums::column::Column;
+    /// use seer::strategies::find_regex_strategy::RegexSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = ColumnSearchStrategy::new(
+    ///     Box::new(
+    ///         RegexSearchStrategy::new(
+    ///             vec![String::from(r"\d+")],
+    ///             SearchModifiers::default(),
+    ///             FrequencyStrategyFactory::make_for_testing(),
+    ///         )
+    ///         .unwrap(),
+    ///     ),
+    ///     ',',
+    ///     Some(Column::Name(String::from("price"))),
+    ///     true,
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "id,price\n7,\"1,200\"\n";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 2);
+    /// assert_eq!(hits[0].get_value(searchable), "1");
+    /// assert_eq!(hits[1].get_value(searchable), "200");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let records = parse_records(searchable, self.delimiter);
+        let Ok(column_index) = self.get_column_index(&records) else {
+            return hits;
+        };
+
+        for record in records.iter().skip(usize::from(self.has_header)) {
+            let fields = match column_index {
+                Some(index) => record.get(index..=index).unwrap_or_default(),
+                None => record.as_slice(),
+            };
+
+            for field in fields {
+                for field_hit in self.search_strategy.search(field.get_value()) {
+                    let start = field.get_position(field_hit.get_position());
+                    let end = field.get_position(field_hit.get_position() + field_hit.get_length());
+
+                    if self.frequency_strategy.matches_frequency() {
+                        hits.push(Hit::escaped_for_pattern(
+                            start,
+                            end - start,
+                            field_hit.get_pattern_index(),
+                            self.get_escaping(field, &field_hit),
+                        ));
+                    }
+
+                    if self.frequency_strategy.is_done() {
+                        return hits;
+                    }
+                }
+            }
+        }
+
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+
+    /// Returns an error if the column is given by name and the searchable has no header with that name.
+    fn check(&self, searchable: &str) -> io::Result<()> {
+        if !matches!(self.column, Some(Column::Name(_))) {
+            return Ok(());
+        }
+
+        self.get_column_index(&parse_records(searchable, self.delimiter))
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::helpers::escaping::escape;
+    use crate::helpers::search_modifiers::SearchModifiers;
+    use crate::strategies::find_regex_strategy::RegexSearchStrategy;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    fn get_column_strategy(
+        regex: &str,
+        delimiter: char,
+        column: Option<Column>,
+        has_header: bool,
+    ) -> ColumnSearchStrategy {
+        ColumnSearchStrategy::new(
+            Box::new(
+                RegexSearchStrategy::new(
+                    vec![String::from(regex)],
+                    SearchModifiers::default(),
+                    FrequencyStrategyFactory::make_for_testing(),
+                )
+                .unwrap(),
+            ),
+            delimiter,
+            column,
+            has_header,
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+    }
+
+    fn get_values<'a>(strategy: &mut ColumnSearchStrategy, searchable: &'a str) -> Vec<&'a str> {
+        strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| hit.get_value(searchable))
+            .collect()
+    }
+
+    #[test]
+    fn test_searches_only_the_named_column() {
+        let searchable = "name,email\nbob@example.com,bob@old.example\nann,ann@old.example\r\n";
+        let mut strategy = get_column_strategy(
+            r"@old\.example",
+            ',',
+            Some(Column::Name(String::from("email"))),
+            true,
+        );
+
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_position(), 30);
+        assert_eq!(hits[1].get_value(searchable), "@old.example");
+    }
+
+    #[test]
+    fn test_searches_the_column_at_an_index() {
+        let searchable = "a\tb\tc\n1\t2\t3\n";
+        let mut strategy = get_column_strategy(r"\w", '\t', Some(Column::Index(2)), true);
+        assert_eq!(get_values(&mut strategy, searchable), vec!["2"]);
+    }
+
+    #[test]
+    fn test_skips_the_header_without_a_column() {
+        let searchable = "a,b\n1,2\n";
+        let mut strategy = get_column_strategy(r"\w", ',', None, true);
+        assert_eq!(get_values(&mut strategy, searchable), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn test_searches_the_first_record_without_a_header() {
+        let searchable = "a\tb\n1\t2\n";
+        let mut strategy = get_column_strategy(r"\w", '\t', Some(Column::Index(2)), false);
+        assert_eq!(get_values(&mut strategy, searchable), vec!["b", "2"]);
+        assert!(strategy.check(searchable).is_ok());
+    }
+
+    #[test]
+    fn test_matches_do_not_cross_fields() {
+        let searchable = "x,y\nab,cd\n";
+        let mut strategy = get_column_strategy("b,c|a.*", ',', None, true);
+        assert_eq!(get_values(&mut strategy, searchable), vec!["ab"]);
+    }
+
+    #[test]
+    fn test_reports_a_missing_named_column() {
+        let searchable = "name\nemail\n";
+        let mut strategy =
+            get_column_strategy("e", ',', Some(Column::Name(String::from("email"))), true);
+        assert!(strategy.search(searchable).is_empty());
+        assert_eq!(
+            strategy.check(searchable).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        assert!(strategy.check("").is_ok());
+    }
+
+    #[test]
+    fn test_reports_a_named_column_without_a_header() {
+        let strategy =
+            get_column_strategy("e", ',', Some(Column::Name(String::from("email"))), false);
+        assert_eq!(
+            strategy.check("email\n").unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_edits_in_quoted_fields_keep_other_bytes() {
+        let searchable = "id,note\r\n1,\"say \"\"hi\"\", ok\"\r\n2,plain hi\r\n";
+        let mut strategy =
+            get_column_strategy("hi", ',', Some(Column::Name(String::from("note"))), true);
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| Edit::replacing(hit.get_position(), hit.get_length(), String::from("bye")))
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "id,note\r\n1,\"say \"\"bye\"\", ok\"\r\n2,plain bye\r\n"
+        );
+    }
+
+    #[test]
+    fn test_quotes_new_values_that_need_quotes() {
+        let searchable = "id,note\n1,plain\n2,\"quoted\"\n";
+        let mut strategy =
+            get_column_strategy(r"\w+", ',', Some(Column::Name(String::from("note"))), true);
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                let new_value = escape("say \"hi\", ok", hit.get_escaping()).unwrap();
+                Edit::replacing(hit.get_position(), hit.get_length(), new_value.into_owned())
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "id,note\n1,\"say \"\"hi\"\", ok\"\n2,\"say \"\"hi\"\", ok\"\n"
+        );
+    }
+
+    #[test]
+    fn test_does_not_quote_part_of_a_field() {
+        let searchable = "a b\n";
+        let mut strategy = get_column_strategy("b", ',', None, false);
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits[0].get_escaping(), Escaping::DelimitedFieldPart(','));
+        assert_eq!(escape("c", hits[0].get_escaping()).unwrap(), "c");
+        assert!(escape("c,d", hits[0].get_escaping()).is_err());
+    }
+
+    #[test]
+    fn test_applies_the_frequency_across_fields() {
+        let mut strategy = ColumnSearchStrategy::new(
+            Box::new(
+                RegexSearchStrategy::new(
+                    vec![String::from(r"\d")],
+                    SearchModifiers::default(),
+                    FrequencyStrategyFactory::make_for_testing(),
+                )
+                .unwrap(),
+            ),
+            ',',
+            Some(Column::Index(1)),
+            true,
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "n,m\n1,9\n2,9\n3,9\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+}
@@ -0,0 +1,139 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::css_selector::CssSelector;
+
+/// A search strategy that finds the element text or attribute values a CSS selector matches
+/// in an HTML or XML document.
+///
+/// Each hit spans one value, so edits change it and leave the rest of the document as it was written.
+/// Entities like `&amp;` are decoded for display, and new text is escaped to fit where it is written.
+pub struct CssSelectorSearchStrategy {
+    css_selector: CssSelector,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl CssSelectorSearchStrategy {
+    pub fn new(css_selector: CssSelector, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+        Self {
+            css_selector,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for CssSelectorSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::CssSelector
+    }
+
+    /// Searches for the values the CSS selector matches.
+    ///
+    /// # Parameters
+    /// - `searchable`: The HTML or XML text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::css_selector::CssSelector;
+    /// use seer::strategies::css_selector_strategy::CssSelectorSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = CssSelectorSearchStrategy::new(
+    ///     CssSelector::parse("img::attr(src)").unwrap(),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = r#"<p><img src="/logo.png"></p>"#;
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "/logo.png");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+
+        for (start, end, escaping) in self.css_selector.select(searchable) {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_edits_at_hits_keep_the_formatting() {
+        let mut strategy = CssSelectorSearchStrategy::new(
+            CssSelector::parse("a.external::attr(href)").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "<!DOCTYPE html>\n<ul>\n  <li><A CLASS=\"external\" HREF='http://old.example'>Old</A></li>\n  <li><a href=\"http://old.example\">Kept</a></li>\n</ul>\n";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                Edit::replacing(
+                    hit.get_position(),
+                    hit.get_length(),
+                    String::from("https://new.example"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "<!DOCTYPE html>\n<ul>\n  <li><A CLASS=\"external\" HREF='https://new.example'>Old</A></li>\n  <li><a href=\"http://old.example\">Kept</a></li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn test_css_selector_strategy_selects_text() {
+        let mut strategy = CssSelectorSearchStrategy::new(
+            CssSelector::parse("title").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "<html><head><title> Home </title></head></html>";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_position(), 20);
+        assert_eq!(hits[0].get_value(searchable), "Home");
+    }
+
+    #[test]
+    fn test_css_selector_strategy_applies_the_frequency() {
+        let mut strategy = CssSelectorSearchStrategy::new(
+            CssSelector::parse("li").unwrap(),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "<ul><li>1</li><li>2</li><li>3</li></ul>";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+}
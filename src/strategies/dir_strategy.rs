@@ -0,0 +1,120 @@
+use super::enums::compression::Compression;
+use super::enums::encoding::Encoding;
+use super::enums::mmap_mode::MmapMode;
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::dto::source_text::SourceText;
+use crate::helpers::compression::detect_compression;
+use crate::helpers::file_sys::{
+    load_decompressed, load_file, open_decompressed, open_file, walk_dir, write_file,
+};
+use std::io::{self, Read};
+
+/// A source strategy that searches every file under the directory given with `--dir`.
+///
+/// Files are searched in path order, down to `--max-depth` subdirectory levels if given.
+pub struct DirSourceStrategy {
+    dir: String,
+    max_depth: Option<u64>,
+    mmap_mode: MmapMode,
+    search_zip: bool,
+}
+
+impl DirSourceStrategy {
+    pub fn new(dir: String, max_depth: Option<u64>, mmap_mode: MmapMode, search_zip: bool) -> Self {
+        Self {
+            dir,
+            max_depth,
+            mmap_mode,
+            search_zip,
+        }
+    }
+
+    // Returns the compression of the file with the given name, if it is searched decompressed.
+    fn get_compression(&self, name: &str) -> Option<Compression> {
+        detect_compression(name).filter(|_| self.search_zip)
+    }
+}
+
+impl SourceStrategy for DirSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Dir
+    }
+
+    /// Returns a source for each file, named by its path.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        Ok(walk_dir(&self.dir, self.max_depth)?
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        match self.get_compression(name) {
+            Some(compression) => open_decompressed(name, compression),
+            None => Ok(Box::new(open_file(name)?)),
+        }
+    }
+
+    fn load(&self, name: &str, encoding: Option<Encoding>) -> io::Result<SourceText> {
+        match self.get_compression(name) {
+            Some(compression) => load_decompressed(name, compression, encoding),
+            None => load_file(name, self.mmap_mode, encoding),
+        }
+    }
+
+    /// Replaces the file, unless it is searched decompressed.
+    fn write(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        if self.get_compression(name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Compressed source '{}' cannot be edited in place", name),
+            ));
+        }
+        write_file(name, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_dir_source_strategy() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub").join("b.txt"), "b").unwrap();
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+
+        let strategy = DirSourceStrategy::new(
+            String::from(dir.path().to_str().unwrap()),
+            None,
+            MmapMode::Auto,
+            false,
+        );
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names.len(), 2);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "a");
+        assert_eq!(strategy.read(&names[1]).unwrap(), "b");
+    }
+
+    #[test]
+    fn test_dir_source_strategy_with_max_depth() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub").join("b.txt"), "b").unwrap();
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+
+        let strategy = DirSourceStrategy::new(
+            String::from(dir.path().to_str().unwrap()),
+            Some(0),
+            MmapMode::Auto,
+            false,
+        );
+
+        assert_eq!(strategy.get_names().unwrap().len(), 1);
+    }
+}
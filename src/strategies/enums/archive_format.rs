@@ -0,0 +1,8 @@
+/// The format of an archive, as given by its extension.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ArchiveFormat {
+    // .tar, optionally compressed, like .tar.gz or .tgz
+    Tar,
+    // .zip
+    Zip,
+}
@@ -0,0 +1,10 @@
+/// Determines what happens to sources that look binary.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BinaryPolicy {
+    // Leave binary sources out of the search
+    Skip,
+    // Search binary sources as text, replacing invalid UTF-8
+    Text,
+    // Search binary sources, but only report whether they have a hit
+    Report,
+}
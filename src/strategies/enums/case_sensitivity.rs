@@ -0,0 +1,7 @@
+/// Determines how letter case is compared when matching search patterns.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CaseSensitivity {
+    Sensitive,
+    Insensitive,
+    Smart,
+}
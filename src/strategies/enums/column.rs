@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+/// Selects the column of delimited records that is searched.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Column {
+    // The column at this position, counting from 1
+    Index(usize),
+    // The column whose header in the first record has this value
+    Name(String),
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    /// Reads a column as an index counting from 1 if it is a number, or as a header name otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty or the index is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use seer::strategies::enums::column::Column;
+    ///
+    /// assert_eq!("2".parse::<Column>(), Ok(Column::Index(2)));
+    /// assert_eq!("email".parse::<Column>(), Ok(Column::Name(String::from("email"))));
+    /// assert!("0".parse::<Column>().is_err());
+    /// ```
+    fn from_str(column: &str) -> Result<Self, Self::Err> {
+        if column.is_empty() {
+            return Err(String::from("The column name must not be empty"));
+        }
+        match column.parse::<usize>() {
+            Ok(0) => Err(String::from("The column index must be at least 1")),
+            Ok(index) => Ok(Column::Index(index)),
+            Err(_) => Ok(Column::Name(column.to_string())),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// The compression format of a file, as given by its extension.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Compression {
+    // .gz
+    Gzip,
+    // .zst
+    Zstd,
+    // .xz
+    Xz,
+    // .bz2
+    Bzip2,
+}
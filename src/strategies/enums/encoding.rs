@@ -0,0 +1,9 @@
+/// A text encoding that sources can be read and written in.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+    Windows1252,
+}
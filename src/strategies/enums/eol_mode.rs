@@ -0,0 +1,8 @@
+/// Determines the line endings of edited sources when they are written.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum EolMode {
+    // Keep every line ending as it is
+    Preserve,
+    Lf,
+    Crlf,
+}
@@ -0,0 +1,43 @@
+/// Determines how the text a hit covers is escaped in its source.
+///
+/// A hit's value is shown with its escaping undone, and text that replaces it is escaped the same way.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Escaping {
+    // The text is its own value
+    None,
+    // The contents of a JSON string, between its quotes
+    JsonString,
+    // A YAML scalar without quotes, which may need quotes to hold a new value
+    YamlPlain,
+    // The contents of a YAML scalar in single quotes, where `''` is a quote
+    YamlSingleQuoted,
+    // The contents of a YAML scalar in double quotes, with backslash escapes
+    YamlDoubleQuoted,
+    // The lines of a YAML block scalar (`|` or `>`) after the first, indented by this many spaces
+    YamlLiteral(usize),
+    YamlFolded(usize),
+    // The contents of a TOML basic string, with backslash escapes like `\n` and `\u00e9`
+    TomlBasic,
+    // The contents of a TOML literal string, which has no escapes and cannot hold its quote or a line break
+    TomlLiteral,
+    // A whole field of delimited text without quotes, which is quoted to hold this delimiter, a quote, or a line break
+    DelimitedField(char),
+    // Part of a field of delimited text without quotes, which cannot hold this delimiter, a quote, or a line break
+    DelimitedFieldPart(char),
+    // The contents of a quoted field of delimited text, where `""` is a quote
+    DelimitedQuoted,
+    // The text of an XML or HTML element, with character references like `&amp;`
+    XmlText,
+    // The value of an XML or HTML attribute, with character references, in this quote if it has one
+    XmlAttribute(Option<char>),
+    // The inside of a CDATA section, which cannot hold `]]>`
+    XmlCData,
+    // A `.env`, INI, or `.properties` value without quotes, which may need quotes to hold a new value
+    KvPlain,
+    // The contents of a single quoted key-value value, which has no escapes
+    KvSingleQuoted,
+    // The contents of a double quoted key-value value, with `\"`, `\\`, and `\n` escapes
+    KvDoubleQuoted,
+    // A `.properties` value, with backslash escapes like `\n` and `\u00e9`, which may go on over lines ending in `\`
+    KvProperties,
+}
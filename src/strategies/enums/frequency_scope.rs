@@ -0,0 +1,6 @@
+/// Determines whether frequency counters span every source or restart for each one.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum FrequencyScope {
+    Global,
+    PerSource,
+}
@@ -0,0 +1,10 @@
+/// Determines which changes the git source strategy searches.
+#[derive(PartialEq, Debug, Clone)]
+pub enum GitSelection {
+    // Changes in the working copy since the last commit, including untracked files
+    Changed,
+    // Changes staged for the next commit
+    Staged,
+    // Changes in a revision range, like `main..HEAD`
+    Diff(String),
+}
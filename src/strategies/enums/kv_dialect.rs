@@ -0,0 +1,45 @@
+/// Determines how key-value text is read, which depends on the kind of file it is in.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum KvDialect {
+    // `.env` and INI text, with sections, quoted values, and inline comments
+    Ini,
+    // `.properties` text, with backslash escapes, continued lines, and no inline comments
+    Properties,
+}
+
+impl KvDialect {
+    /// Returns the dialect of a source from its name, `Properties` for a `.properties` file and `Ini` otherwise.
+    ///
+    /// A line number after the file name, as in the name of a git hunk, is ignored.
+    pub fn from_source_name(name: &str) -> Self {
+        let file_name = name
+            .rsplit_once(':')
+            .filter(|(_, line)| !line.is_empty() && line.chars().all(|c| c.is_ascii_digit()))
+            .map_or(name, |(file_name, _)| file_name);
+
+        if file_name.ends_with(".properties") {
+            Self::Properties
+        } else {
+            Self::Ini
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chooses_the_dialect_from_the_extension() {
+        assert_eq!(
+            KvDialect::from_source_name("src/main/resources/app.properties"),
+            KvDialect::Properties
+        );
+        assert_eq!(
+            KvDialect::from_source_name("app.properties:12"),
+            KvDialect::Properties
+        );
+        assert_eq!(KvDialect::from_source_name(".env"), KvDialect::Ini);
+        assert_eq!(KvDialect::from_source_name("config.ini"), KvDialect::Ini);
+    }
+}
@@ -0,0 +1,8 @@
+/// Determines when file sources are memory-mapped instead of read into memory.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MmapMode {
+    // Map files at or above the size threshold
+    Auto,
+    Always,
+    Never,
+}
@@ -1 +1,13 @@
+pub mod archive_format;
+pub mod binary_policy;
+pub mod case_sensitivity;
+pub mod column;
+pub mod compression;
+pub mod encoding;
+pub mod eol_mode;
+pub mod escaping;
+pub mod frequency_scope;
+pub mod git_selection;
+pub mod kv_dialect;
+pub mod mmap_mode;
 pub mod strategy_type;
@@ -2,7 +2,10 @@
 pub enum StrategyType {
     All,
     Append,
+    Archive,
+    Column,
     Count,
+    CssSelector,
     Dir,
     EveryNth,
     File,
@@ -10,9 +13,18 @@ pub enum StrategyType {
     FindBetween,
     FindRegex,
     FindString,
+    Git,
+    GitRev,
+    Invert,
+    JsonPath,
+    KvKey,
+    Lines,
     Nth,
     Pipe,
     Prepend,
     ReplaceWith,
     Text,
+    TomlKey,
+    XmlPath,
+    YamlPath,
 }
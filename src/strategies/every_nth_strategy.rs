@@ -1,5 +1,7 @@
-use super::frequency_strategy::FrequencyStrategy;
-use super::frequency_strategy_type::FrequencyStrategyType;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::strategy::Strategy;
+use crate::dto::dto::DTO;
 
 pub struct EveryNthFrequencyStrategy {
     frequency: usize,
@@ -8,37 +10,89 @@ pub struct EveryNthFrequencyStrategy {
 }
 
 impl EveryNthFrequencyStrategy {
+    /// Creates a new `EveryNthFrequencyStrategy`.
+    ///
+    /// # Parameters
+    /// - `frequency`: Keep one hit out of every `frequency` hits.
+    /// - `offset`: The number of hits to skip before counting starts.
+    ///
+    /// # Returns
+    /// A new `EveryNthFrequencyStrategy` that keeps hits `offset + frequency`,
+    /// `offset + 2 * frequency`, and so on.
+    ///
+    /// # Panics
+    /// Panics if `frequency` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::strategies::every_nth_strategy::EveryNthFrequencyStrategy;
+    ///
+    /// let strategy = EveryNthFrequencyStrategy::new(2, 0);
+    /// ```
     pub fn new(frequency: usize, offset: usize) -> Self {
+        assert!(frequency > 0, "The every nth frequency must be at least 1");
+
         Self {
-            frequency: frequency,
+            frequency,
             counter: 0,
-            offset: offset,
+            offset,
         }
     }
+}
 
-    fn index(&self) -> usize {
-        self.counter - 1
+impl Strategy for EveryNthFrequencyStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::EveryNth
     }
 
-    fn increment_counter(&mut self) {
-        self.counter += 1;
+    fn run(&self, dto: &mut DTO) {
+        dto.retain_hits(|count| self.selects(count));
     }
 }
 
 impl FrequencyStrategy for EveryNthFrequencyStrategy {
-    fn strategy_type(&self) -> FrequencyStrategyType {
-        FrequencyStrategyType::EveryNth
+    fn selects(&self, count: usize) -> bool {
+        let has_reached_offset = count > self.offset;
+
+        has_reached_offset && (count - self.offset).is_multiple_of(self.frequency)
     }
 
-    fn matches_frequency(&mut self) -> bool {
-        self.increment_counter();
-        let has_reached_offset = self.index() <= self.offset;
-        let matches_frequency = ((self.index() - self.offset) % self.frequency) == 0;
+    fn is_exhausted_after(&self, _count: usize) -> bool {
+        false
+    }
 
-        has_reached_offset && matches_frequency
+    fn get_counter(&self) -> usize {
+        self.counter
     }
 
-    fn is_done(&self) -> bool {
-        false
+    fn set_counter(&mut self, counter: usize) {
+        self.counter = counter;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selects_every_nth_hit() {
+        let strategy = EveryNthFrequencyStrategy::new(2, 0);
+        let selected: Vec<usize> = (1..=6).filter(|count| strategy.selects(*count)).collect();
+        assert_eq!(selected, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_selects_every_nth_hit_after_offset() {
+        let strategy = EveryNthFrequencyStrategy::new(2, 3);
+        let selected: Vec<usize> = (1..=9).filter(|count| strategy.selects(*count)).collect();
+        assert_eq!(selected, vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn test_every_first_hit_selects_all() {
+        let mut strategy = EveryNthFrequencyStrategy::new(1, 0);
+        assert!((0..5).all(|_| strategy.matches_frequency()));
+        assert!(!strategy.is_done());
     }
 }
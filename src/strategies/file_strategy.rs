@@ -0,0 +1,144 @@
+use super::enums::compression::Compression;
+use super::enums::encoding::Encoding;
+use super::enums::mmap_mode::MmapMode;
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::dto::source_text::SourceText;
+use crate::helpers::compression::detect_compression;
+use crate::helpers::file_sys::{
+    load_decompressed, load_file, open_decompressed, open_file, write_file,
+};
+use std::io::{self, Read};
+
+/// A source strategy that searches the file given with `--file`.
+pub struct FileSourceStrategy {
+    file_path: String,
+    mmap_mode: MmapMode,
+    search_zip: bool,
+}
+
+impl FileSourceStrategy {
+    pub fn new(file_path: String, mmap_mode: MmapMode, search_zip: bool) -> Self {
+        Self {
+            file_path,
+            mmap_mode,
+            search_zip,
+        }
+    }
+
+    // Returns the compression of the file with the given name, if it is searched decompressed.
+    fn get_compression(&self, name: &str) -> Option<Compression> {
+        detect_compression(name).filter(|_| self.search_zip)
+    }
+}
+
+impl SourceStrategy for FileSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::File
+    }
+
+    /// Returns the single source, named by its path.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        Ok(vec![self.file_path.clone()])
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        match self.get_compression(name) {
+            Some(compression) => open_decompressed(name, compression),
+            None => Ok(Box::new(open_file(name)?)),
+        }
+    }
+
+    fn load(&self, name: &str, encoding: Option<Encoding>) -> io::Result<SourceText> {
+        match self.get_compression(name) {
+            Some(compression) => load_decompressed(name, compression, encoding),
+            None => load_file(name, self.mmap_mode, encoding),
+        }
+    }
+
+    /// Replaces the file, unless it is searched decompressed.
+    fn write(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        if self.get_compression(name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Compressed source '{}' cannot be edited in place", name),
+            ));
+        }
+        write_file(name, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_file_source_strategy() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "file content").unwrap();
+        let file_path = temp_file.path().to_str().unwrap();
+
+        let strategy = FileSourceStrategy::new(String::from(file_path), MmapMode::Auto, false);
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names, vec![file_path]);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "file content");
+    }
+
+    #[test]
+    fn test_file_source_strategy_maps_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "file content").unwrap();
+        let file_path = temp_file.path().to_str().unwrap();
+
+        let strategy = FileSourceStrategy::new(String::from(file_path), MmapMode::Always, false);
+        let text = strategy.load(file_path, None).unwrap();
+
+        assert!(text.is_mapped());
+        assert_eq!(&*text, "file content");
+    }
+
+    #[test]
+    fn test_file_source_strategy_with_missing_file() {
+        let strategy = FileSourceStrategy::new(String::from("missing_file"), MmapMode::Auto, false);
+        assert!(strategy.read("missing_file").is_err());
+    }
+
+    fn get_gz_file(contents: &str) -> NamedTempFile {
+        let temp_file = NamedTempFile::with_suffix(".log.gz").unwrap();
+        let mut encoder =
+            GzEncoder::new(temp_file.reopen().unwrap(), flate2::Compression::default());
+        encoder.write_all(contents.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+        temp_file
+    }
+
+    #[test]
+    fn test_file_source_strategy_with_search_zip() {
+        let temp_file = get_gz_file("rotated log");
+        let file_path = temp_file.path().to_str().unwrap();
+
+        let strategy = FileSourceStrategy::new(String::from(file_path), MmapMode::Always, true);
+
+        assert_eq!(strategy.get_names().unwrap(), vec![file_path]);
+        assert_eq!(strategy.read(file_path).unwrap(), "rotated log");
+        assert_eq!(&*strategy.load(file_path, None).unwrap(), "rotated log");
+        assert_eq!(
+            strategy.write(file_path, b"edited").unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_file_source_strategy_without_search_zip() {
+        let temp_file = get_gz_file("rotated log");
+        let file_path = temp_file.path().to_str().unwrap();
+
+        let strategy = FileSourceStrategy::new(String::from(file_path), MmapMode::Auto, false);
+
+        assert!(strategy.load(file_path, None).unwrap().is_binary());
+    }
+}
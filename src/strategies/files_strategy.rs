@@ -0,0 +1,137 @@
+use super::enums::compression::Compression;
+use super::enums::encoding::Encoding;
+use super::enums::mmap_mode::MmapMode;
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::dto::source_text::SourceText;
+use crate::helpers::compression::detect_compression;
+use crate::helpers::file_sys::{
+    load_decompressed, load_file, open_decompressed, open_file, read_file_list, write_file,
+};
+use std::io::{self, Read};
+
+/// A source strategy that searches the files given with `--files` or `--files-from`, in the order given.
+pub struct FilesSourceStrategy {
+    file_paths: FilePaths,
+    mmap_mode: MmapMode,
+    search_zip: bool,
+}
+
+// Where the paths of the files come from.
+enum FilePaths {
+    // Given with `--files`
+    Given(Vec<String>),
+    // Listed in a file given with `--files-from`
+    Listed { list_path: String, null: bool },
+}
+
+impl FilesSourceStrategy {
+    pub fn new(file_paths: Vec<String>, mmap_mode: MmapMode, search_zip: bool) -> Self {
+        Self {
+            file_paths: FilePaths::Given(file_paths),
+            mmap_mode,
+            search_zip,
+        }
+    }
+
+    /// Creates a strategy for the files listed in a file, or in stdin if the path is `-`.
+    ///
+    /// The list is only read when the sources are listed. With `null`, paths are separated by NUL bytes.
+    pub fn from_list(list_path: String, null: bool, mmap_mode: MmapMode, search_zip: bool) -> Self {
+        Self {
+            file_paths: FilePaths::Listed { list_path, null },
+            mmap_mode,
+            search_zip,
+        }
+    }
+
+    // Returns the compression of the file with the given name, if it is searched decompressed.
+    fn get_compression(&self, name: &str) -> Option<Compression> {
+        detect_compression(name).filter(|_| self.search_zip)
+    }
+}
+
+impl SourceStrategy for FilesSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Files
+    }
+
+    /// Returns a source for each file, named by its path.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        match &self.file_paths {
+            FilePaths::Given(file_paths) => Ok(file_paths.clone()),
+            FilePaths::Listed { list_path, null } => read_file_list(list_path, *null),
+        }
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        match self.get_compression(name) {
+            Some(compression) => open_decompressed(name, compression),
+            None => Ok(Box::new(open_file(name)?)),
+        }
+    }
+
+    fn load(&self, name: &str, encoding: Option<Encoding>) -> io::Result<SourceText> {
+        match self.get_compression(name) {
+            Some(compression) => load_decompressed(name, compression, encoding),
+            None => load_file(name, self.mmap_mode, encoding),
+        }
+    }
+
+    /// Replaces the file, unless it is searched decompressed.
+    fn write(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        if self.get_compression(name).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Compressed source '{}' cannot be edited in place", name),
+            ));
+        }
+        write_file(name, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_files_source_strategy() {
+        let mut first_file = NamedTempFile::new().unwrap();
+        write!(first_file, "first").unwrap();
+        let mut second_file = NamedTempFile::new().unwrap();
+        write!(second_file, "second").unwrap();
+        let file_paths = vec![
+            String::from(second_file.path().to_str().unwrap()),
+            String::from(first_file.path().to_str().unwrap()),
+        ];
+
+        let strategy = FilesSourceStrategy::new(file_paths.clone(), MmapMode::Auto, false);
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names, file_paths);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "second");
+        assert_eq!(strategy.read(&names[1]).unwrap(), "first");
+    }
+
+    #[test]
+    fn test_files_source_strategy_from_list() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "content").unwrap();
+        let file_path = file.path().to_str().unwrap();
+        let mut list_file = NamedTempFile::new().unwrap();
+        write!(list_file, "{}\0", file_path).unwrap();
+
+        let strategy = FilesSourceStrategy::from_list(
+            String::from(list_file.path().to_str().unwrap()),
+            true,
+            MmapMode::Auto,
+            false,
+        );
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names, vec![file_path]);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "content");
+    }
+}
@@ -1,40 +1,164 @@
-use super::hit::Hit;
-use super::search_strategy::SearchStrategy;
-use super::search_strategy_type::SearchStrategyType;
-use crate::frequency_strategies::frequency_strategy::FrequencyStrategy;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::search_modifiers::SearchModifiers;
 use regex::Regex;
 
+/// The quote characters that start string literals in balanced mode unless others are given.
+pub const DEFAULT_QUOTES: &str = "\"'";
+
 /// A search strategy that finds text between two regex patterns.
+///
+/// By default each `from` is paired with the next `to`.
+/// In balanced mode nested pairs are skipped over, so the hit ends at the `to` that closes the first `from`.
+/// Delimiters inside string literals, or escaped with a backslash, are ignored in balanced mode.
 pub struct BetweenSearchStrategy {
-    from: String,
-    to: String,
+    from_regex: Regex,
+    to_regex: Regex,
     exclude_matches: bool,
+    balanced: bool,
+    quotes: Vec<char>,
+    search_modifiers: SearchModifiers,
     frequency_strategy: Box<dyn FrequencyStrategy>,
 }
 
 impl BetweenSearchStrategy {
+    /// Compiles the delimiter regexes once, to search every searchable with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either delimiter is not a valid regex.
     pub fn new(
         from: String,
         to: String,
         exclude_matches: bool,
+        balanced: bool,
+        quotes: Vec<char>,
+        search_modifiers: SearchModifiers,
         frequency_strategy: Box<dyn FrequencyStrategy>,
-    ) -> Self {
-        Self {
-            from,
-            to,
+    ) -> Result<Self, regex::Error> {
+        Ok(Self {
+            from_regex: search_modifiers.build_regex(&from)?,
+            to_regex: search_modifiers.build_regex(&to)?,
             exclude_matches,
+            balanced,
+            quotes,
+            search_modifiers,
             frequency_strategy,
+        })
+    }
+
+    /// Finds the `to` that closes the `from` ending at `pos`, counting nested pairs.
+    ///
+    /// # Returns
+    /// The start and end byte positions of the closing `to`, or `None` if the `from` is never closed.
+    fn find_closing(
+        &self,
+        from_regex: &Regex,
+        to_regex: &Regex,
+        searchable: &str,
+        code: &[bool],
+        mut pos: usize,
+    ) -> Option<(usize, usize)> {
+        let mut depth = 1;
+
+        loop {
+            let (to_start, to_end) = self.find_in_code(to_regex, searchable, code, pos)?;
+
+            match self.find_in_code(from_regex, searchable, code, pos) {
+                Some((from_start, from_end)) if from_start < to_start && from_end > from_start => {
+                    depth += 1;
+                    pos = from_end;
+                }
+                _ => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some((to_start, to_end));
+                    }
+                    pos = next_position(searchable, to_start, to_end);
+                }
+            }
         }
     }
+
+    /// Finds the first match at or after `start` that lies entirely outside string literals and escapes.
+    fn find_in_code(
+        &self,
+        regex: &Regex,
+        searchable: &str,
+        code: &[bool],
+        start: usize,
+    ) -> Option<(usize, usize)> {
+        let mut pos = start;
+
+        while pos <= searchable.len() {
+            let (mat_start, mat_end) = self.search_modifiers.find_at(regex, searchable, pos)?;
+
+            if !self.balanced || code[mat_start..mat_end].iter().all(|&is_code| is_code) {
+                return Some((mat_start, mat_end));
+            }
+
+            pos = next_position(searchable, mat_start, mat_start);
+        }
+
+        None
+    }
+}
+
+/// Marks each byte that is outside string literals and escapes.
+///
+/// A backslash escapes the character after it, both inside and outside string literals.
+/// A string literal starts at any of the quote characters and ends at the same character.
+fn code_mask(searchable: &str, quotes: &[char]) -> Vec<bool> {
+    let mut code = vec![true; searchable.len()];
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (index, c) in searchable.char_indices() {
+        let is_code = if escaped {
+            escaped = false;
+            false
+        } else if c == '\\' {
+            escaped = true;
+            false
+        } else if let Some(open) = quote {
+            if c == open {
+                quote = None;
+            }
+            false
+        } else if quotes.contains(&c) {
+            quote = Some(c);
+            false
+        } else {
+            true
+        };
+
+        code[index..index + c.len_utf8()].fill(is_code);
+    }
+
+    code
+}
+
+/// Returns the position to continue searching from after a match, stepping past empty matches.
+fn next_position(searchable: &str, start: usize, end: usize) -> usize {
+    if end > start {
+        end
+    } else {
+        end + searchable[end..].chars().next().map_or(1, char::len_utf8)
+    }
 }
 
 impl SearchStrategy for BetweenSearchStrategy {
-    fn strategy_type(&self) -> SearchStrategyType {
-        SearchStrategyType::Between
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::FindBetween
     }
 
     /// Searches for text between two regex patterns.
     ///
+    /// The search modifiers apply to both patterns.
+    /// In balanced mode, a `from` that is never closed is skipped and the search continues after it.
+    ///
     /// # Parameters
     /// - `searchable`: The text to search within.
     ///
@@ -44,71 +168,73 @@ impl SearchStrategy for BetweenSearchStrategy {
     /// # Examples
     ///
     /// ```
-    /// use seer::search_strategies::between_search_strategy::BetweenSearchStrategy;
-    /// use seer::search_strategies::search_strategy::SearchStrategy;
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::find_between_strategy::BetweenSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
     ///
     /// let mut strategy = BetweenSearchStrategy::new(
     ///     String::from("start"),
     ///     String::from("end"),
     ///     false,
+    ///     false,
+    ///     Vec::new(),
+    ///     SearchModifiers::default(),
     ///     FrequencyStrategyFactory::make_for_testing(),
-    /// );
+    /// ).unwrap();
     /// let searchable = "start123endstart456endstart789end";
     /// let hits = strategy.search(searchable);
     ///
     /// assert_eq!(hits.len(), 3);
-    /// assert_eq!(hits[0].get_value(), "start123end");
+    /// assert_eq!(hits[0].get_value(searchable), "start123end");
     /// assert_eq!(hits[0].get_position(), 0);
     /// assert_eq!(hits[0].get_end_position(), 10);
     /// ```
     fn search(&mut self, searchable: &str) -> Vec<Hit> {
         let mut hits = Vec::new();
+        let mut pos = 0;
 
-        // Compile regex patterns
-        let from_regex = match Regex::new(&self.from) {
-            Ok(regex) => regex,
-            Err(_) => return hits,
+        // In balanced mode, mark where delimiters may be found
+        let code = if self.balanced {
+            code_mask(searchable, &self.quotes)
+        } else {
+            Vec::new()
         };
-        let to_regex = match Regex::new(&self.to) {
-            Ok(regex) => regex,
-            Err(_) => return hits,
-        };
-
-        let mut pos = 0;
 
         // Search for matches
-        while let Some(from_match) = from_regex.find(&searchable[pos..]) {
-            let from_start = pos + from_match.start();
-            let from_end = pos + from_match.end();
+        while let Some((from_start, from_end)) =
+            self.find_in_code(&self.from_regex, searchable, &code, pos)
+        {
             pos = from_end;
 
-            if let Some(to_match) = to_regex.find(&searchable[pos..]) {
-                let to_start = pos + to_match.start();
-                let to_end = pos + to_match.end();
+            let closing = if self.balanced {
+                self.find_closing(&self.from_regex, &self.to_regex, searchable, &code, pos)
+            } else {
+                self.search_modifiers
+                    .find_at(&self.to_regex, searchable, pos)
+            };
+
+            if let Some((to_start, to_end)) = closing {
                 pos = to_end;
 
-                // Determine hit value and position
-                let hit_value = if self.exclude_matches {
-                    &searchable[from_end..to_start]
-                } else {
-                    &searchable[from_start..to_end]
-                };
-                let hit_position = if self.exclude_matches {
-                    from_end
+                // Determine hit position and length
+                let (hit_position, hit_end) = if self.exclude_matches {
+                    (from_end, to_start)
                 } else {
-                    from_start
+                    (from_start, to_end)
                 };
 
                 // Add hit to results
                 if self.frequency_strategy.matches_frequency() {
-                    hits.push(Hit::new(hit_value.to_string(), hit_position));
+                    hits.push(Hit::new(hit_position, hit_end - hit_position));
                 }
 
                 // If the frequency strategy is done, return early
                 if self.frequency_strategy.is_done() {
                     return hits;
                 }
+            } else if self.balanced {
+                pos = next_position(searchable, from_start, from_end);
             } else {
                 break;
             }
@@ -116,12 +242,17 @@ impl SearchStrategy for BetweenSearchStrategy {
 
         hits
     }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
 
     #[test]
     fn test_between_strategy_including_matches() {
@@ -131,20 +262,24 @@ mod tests {
             from.to_string(),
             to.to_string(),
             false,
+            false,
+            Vec::new(),
+            SearchModifiers::default(),
             FrequencyStrategyFactory::make_for_testing(),
-        );
+        )
+        .unwrap();
 
         let searchable = "start123endstart456endstart789end";
         let hits = strategy.search(searchable);
 
         assert_eq!(hits.len(), 3);
-        assert_eq!(hits[0].get_value(), "start123end");
+        assert_eq!(hits[0].get_value(searchable), "start123end");
         assert_eq!(hits[0].get_position(), 0);
         assert_eq!(hits[0].get_end_position(), 10);
-        assert_eq!(hits[1].get_value(), "start456end");
+        assert_eq!(hits[1].get_value(searchable), "start456end");
         assert_eq!(hits[1].get_position(), 11);
         assert_eq!(hits[1].get_end_position(), 21);
-        assert_eq!(hits[2].get_value(), "start789end");
+        assert_eq!(hits[2].get_value(searchable), "start789end");
         assert_eq!(hits[2].get_position(), 22);
         assert_eq!(hits[2].get_end_position(), 32);
     }
@@ -157,21 +292,147 @@ mod tests {
             from.to_string(),
             to.to_string(),
             true,
+            false,
+            Vec::new(),
+            SearchModifiers::default(),
             FrequencyStrategyFactory::make_for_testing(),
-        );
+        )
+        .unwrap();
 
         let searchable = "start123endstart456endstart789end";
         let hits = strategy.search(searchable);
 
         assert_eq!(hits.len(), 3);
-        assert_eq!(hits[0].get_value(), "123");
+        assert_eq!(hits[0].get_value(searchable), "123");
         assert_eq!(hits[0].get_position(), 5);
         assert_eq!(hits[0].get_end_position(), 7);
-        assert_eq!(hits[1].get_value(), "456");
+        assert_eq!(hits[1].get_value(searchable), "456");
         assert_eq!(hits[1].get_position(), 16);
         assert_eq!(hits[1].get_end_position(), 18);
-        assert_eq!(hits[2].get_value(), "789");
+        assert_eq!(hits[2].get_value(searchable), "789");
         assert_eq!(hits[2].get_position(), 27);
         assert_eq!(hits[2].get_end_position(), 29);
     }
+
+    #[test]
+    fn test_between_strategy_ignoring_case() {
+        let mut strategy = BetweenSearchStrategy::new(
+            String::from("begin"),
+            String::from("end"),
+            true,
+            false,
+            Vec::new(),
+            SearchModifiers::new(CaseSensitivity::Insensitive, false),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "BEGIN 1 END begin 2 End";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), " 1 ");
+        assert_eq!(hits[1].get_value(searchable), " 2 ");
+    }
+
+    #[test]
+    fn test_between_strategy_whole_word() {
+        let mut strategy = BetweenSearchStrategy::new(
+            String::from("if"),
+            String::from("fi"),
+            false,
+            false,
+            Vec::new(),
+            SearchModifiers::new(CaseSensitivity::Sensitive, true),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "gift if x; fifty fi";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "if x; fifty fi");
+    }
+
+    fn get_balanced_strategy(exclude_matches: bool) -> BetweenSearchStrategy {
+        BetweenSearchStrategy::new(
+            String::from(r"\{"),
+            String::from(r"\}"),
+            exclude_matches,
+            true,
+            DEFAULT_QUOTES.chars().collect(),
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_between_strategy_balanced_skips_nested_pairs() {
+        let mut strategy = get_balanced_strategy(false);
+
+        let searchable = "fn a() { if x { y(); } } fn b() { {} }";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "{ if x { y(); } }");
+        assert_eq!(hits[1].get_value(searchable), "{ {} }");
+    }
+
+    #[test]
+    fn test_between_strategy_balanced_ignores_strings_and_escapes() {
+        let mut strategy = get_balanced_strategy(true);
+
+        let searchable = r#"{"a": "}", "b": {"c": '{'}, "d": \}}"#;
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].get_value(searchable),
+            r#""a": "}", "b": {"c": '{'}, "d": \}"#
+        );
+    }
+
+    #[test]
+    fn test_between_strategy_balanced_escapes_quotes_in_strings() {
+        let mut strategy = get_balanced_strategy(false);
+
+        let searchable = r#"{ "say \"}\"" } }"#;
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), r#"{ "say \"}\"" }"#);
+    }
+
+    #[test]
+    fn test_between_strategy_balanced_uses_given_quotes() {
+        let mut strategy = BetweenSearchStrategy::new(
+            String::from(r"\("),
+            String::from(r"\)"),
+            false,
+            true,
+            vec!['`'],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "(`)` ')')";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "(`)` ')");
+    }
+
+    #[test]
+    fn test_between_strategy_balanced_skips_unclosed_from() {
+        let mut strategy = get_balanced_strategy(false);
+
+        let searchable = "{ { }";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "{ }");
+    }
 }
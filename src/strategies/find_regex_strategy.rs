@@ -1,30 +1,48 @@
-use super::hit::Hit;
-use super::search_strategy::SearchStrategy;
-use super::search_strategy_type::SearchStrategyType;
-use crate::frequency_strategies::frequency_strategy::FrequencyStrategy;
-use regex::Regex;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::lines::line_spans;
+use crate::helpers::pattern_set::PatternSet;
+use crate::helpers::search_modifiers::SearchModifiers;
 
-/// A search strategy that finds text matching a regex pattern.
+/// A search strategy that finds text matching one or more regex patterns.
+///
+/// Like grep, the patterns are matched within each line, so `^` and `$` anchor to it
+/// and no match crosses it. With the multiline or dotall flag, they are matched across lines.
 pub struct RegexSearchStrategy {
-    regex: String,
+    pattern_set: PatternSet,
+    across_lines: bool,
     frequency_strategy: Box<dyn FrequencyStrategy>,
 }
 
 impl RegexSearchStrategy {
-    pub fn new(regex: String, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
-        Self {
-            regex,
+    /// Compiles the regex patterns once, to search every searchable with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the patterns is not a valid regex.
+    pub fn new(
+        regexes: Vec<String>,
+        search_modifiers: SearchModifiers,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern_set: PatternSet::new(&regexes, search_modifiers)?,
+            across_lines: search_modifiers.matches_across_lines(),
             frequency_strategy,
-        }
+        })
     }
 }
 
 impl SearchStrategy for RegexSearchStrategy {
-    fn strategy_type(&self) -> SearchStrategyType {
-        SearchStrategyType::Regex
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::FindRegex
     }
 
-    /// Searches for text matching a regex pattern.
+    /// Searches for text matching any of the regex patterns in a single scan.
+    ///
+    /// When several patterns match at the same position, the one given first wins.
     ///
     /// # Parameters
     /// - `searchable`: The text to search within.
@@ -35,42 +53,72 @@ impl SearchStrategy for RegexSearchStrategy {
     /// # Examples
     ///
     /// ```
-    /// use seer::search_strategies::regex_search_strategy::RegexSearchStrategy;
-    /// use seer::search_strategies::search_strategy::SearchStrategy;
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::find_regex_strategy::RegexSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
     ///
     /// let mut strategy = RegexSearchStrategy::new(
-    ///     String::from(r"\d+"),
+    ///     vec![String::from(r"\d+")],
+    ///     SearchModifiers::default(),
     ///     FrequencyStrategyFactory::make_for_testing()
-    /// );
+    /// ).unwrap();
     ///
     /// let searchable = "test1234567890tester1234567890retest1234567890test";
     /// let hits = strategy.search(searchable);
     ///
     /// assert_eq!(hits.len(), 3);
-    /// assert_eq!(hits[0].get_value(), "1234567890");
+    /// assert_eq!(hits[0].get_value(searchable), "1234567890");
     /// assert_eq!(hits[0].get_position(), 4);
     /// ```
     fn search(&mut self, searchable: &str) -> Vec<Hit> {
         let mut hits = Vec::new();
-        if let Ok(regex) = Regex::new(&self.regex) {
-            for mat in regex.find_iter(searchable) {
-                if self.frequency_strategy.matches_frequency() {
-                    hits.push(Hit::new(mat.as_str().to_string(), mat.start()));
-                }
-
-                if self.frequency_strategy.is_done() {
-                    return hits;
+        if self.across_lines {
+            self.search_span(searchable, 0, &mut hits);
+        } else {
+            for (start, end) in line_spans(searchable) {
+                if self.search_span(&searchable[start..end], start, &mut hits) {
+                    break;
                 }
             }
         }
         hits
     }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+impl RegexSearchStrategy {
+    // Adds the hits in `span`, which starts at `offset` in the searchable.
+    // Returns true once the frequency strategy is done.
+    fn search_span(&mut self, span: &str, offset: usize, hits: &mut Vec<Hit>) -> bool {
+        let mut pos = 0;
+        while let Some((start, end, pattern_index)) = self.pattern_set.find_at(span, pos) {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::for_pattern(offset + start, end - start, pattern_index));
+            }
+
+            if self.frequency_strategy.is_done() {
+                return true;
+            }
+
+            // Step past empty matches so the search always moves forward.
+            pos = if end > start {
+                end
+            } else {
+                end + span[end..].chars().next().map_or(1, char::len_utf8)
+            };
+        }
+        false
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
 
     use super::*;
 
@@ -78,25 +126,148 @@ mod tests {
     fn test_regex_strategy() {
         let regex = r"[a-z]*test[a-z]*";
         let mut strategy = RegexSearchStrategy::new(
-            String::from(regex),
+            vec![String::from(regex)],
+            SearchModifiers::default(),
             FrequencyStrategyFactory::make_for_testing(),
-        );
+        )
+        .unwrap();
 
         let searchable = "test1234567890tester1234567890retest1234567890test";
         let hits = strategy.search(searchable);
 
         assert_eq!(hits.len(), 4);
-        assert_eq!(hits[0].get_value(), "test");
+        assert_eq!(hits[0].get_value(searchable), "test");
         assert_eq!(hits[0].get_position(), 0);
         assert_eq!(hits[0].get_end_position(), 3);
-        assert_eq!(hits[1].get_value(), "tester");
+        assert_eq!(hits[1].get_value(searchable), "tester");
         assert_eq!(hits[1].get_position(), 14);
         assert_eq!(hits[1].get_end_position(), 19);
-        assert_eq!(hits[2].get_value(), "retest");
+        assert_eq!(hits[2].get_value(searchable), "retest");
         assert_eq!(hits[2].get_position(), 30);
         assert_eq!(hits[2].get_end_position(), 35);
-        assert_eq!(hits[3].get_value(), "test");
+        assert_eq!(hits[3].get_value(searchable), "test");
         assert_eq!(hits[3].get_position(), 46);
         assert_eq!(hits[3].get_end_position(), 49);
     }
+
+    #[test]
+    fn test_regex_strategy_ignoring_case() {
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"todo:\s*\w+")],
+            SearchModifiers::new(CaseSensitivity::Insensitive, false),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "TODO: fix\ntodo: test\nToDo: ship";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[2].get_value(searchable), "ToDo: ship");
+    }
+
+    #[test]
+    fn test_regex_strategy_whole_word() {
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"[a-z]*test")],
+            SearchModifiers::new(CaseSensitivity::Sensitive, true),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "test tester retest";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "test");
+        assert_eq!(hits[1].get_value(searchable), "retest");
+    }
+
+    #[test]
+    fn test_regex_strategy_moves_past_empty_matches() {
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"x*")],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let hits = strategy.search("aé");
+
+        assert_eq!(hits.len(), 3);
+    }
+
+    #[test]
+    fn test_regex_strategy_with_several_patterns() {
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"\d+"), String::from(r"deprecated_\w+")],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "deprecated_call(42); deprecated_flag";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].get_value(searchable), "deprecated_call");
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_value(searchable), "42");
+        assert_eq!(hits[1].get_pattern_index(), 0);
+        assert_eq!(hits[2].get_value(searchable), "deprecated_flag");
+        assert_eq!(hits[2].get_pattern_index(), 1);
+    }
+
+    #[test]
+    fn test_regex_strategy_matches_within_each_line() {
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"^\w+\s+\w+$")],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "one\ntwo three\r\nfour";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "two three");
+        assert_eq!(hits[0].get_position(), 4);
+    }
+
+    #[test]
+    fn test_regex_strategy_matches_across_lines_with_multiline() {
+        let mut search_modifiers = SearchModifiers::default();
+        search_modifiers.set_multi_line(true);
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"^\w+\s+\w+")],
+            search_modifiers,
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "one\ntwo three\nfour";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "one\ntwo");
+    }
+
+    #[test]
+    fn test_regex_strategy_matches_across_lines_with_dotall() {
+        let mut search_modifiers = SearchModifiers::default();
+        search_modifiers.set_dot_all(true);
+        let mut strategy = RegexSearchStrategy::new(
+            vec![String::from(r"<a>.*</a>")],
+            search_modifiers,
+            FrequencyStrategyFactory::make_for_testing(),
+        )
+        .unwrap();
+
+        let searchable = "<a>\n1\n</a>";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), searchable);
+    }
 }
@@ -1,29 +1,107 @@
-use super::hit::Hit;
-use super::search_strategy::SearchStrategy;
-use super::search_strategy_type::SearchStrategyType;
-use crate::frequency_strategies::frequency_strategy::FrequencyStrategy;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::pattern_set::PatternSet;
+use crate::helpers::search_modifiers::SearchModifiers;
+use aho_corasick::{AhoCorasick, MatchKind};
 
-/// A search strategy that finds occurrences of a exact string.
+/// A search strategy that finds occurrences of one or more exact strings.
 pub struct ExactSearchStrategy {
-    exact: String,
+    matcher: ExactMatcher,
     frequency_strategy: Box<dyn FrequencyStrategy>,
 }
 
+// How the exact strings are matched, compiled once for every searchable.
+enum ExactMatcher {
+    // Matches the strings as given, byte for byte
+    Automaton(AhoCorasick),
+    // Matches the escaped strings with the search modifiers, longest first,
+    // along with the index each one was given at
+    Patterns(PatternSet, Vec<usize>),
+}
+
 impl ExactSearchStrategy {
-    pub fn new(exact: String, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+    pub fn new(
+        exacts: Vec<String>,
+        search_modifiers: SearchModifiers,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Self {
+        let matcher = if search_modifiers.is_default() {
+            ExactMatcher::Automaton(
+                AhoCorasick::builder()
+                    .match_kind(MatchKind::LeftmostLongest)
+                    .build(&exacts)
+                    .unwrap_or_else(|e| panic!("Failed to compile the search strings. {}", e)),
+            )
+        } else {
+            let mut order: Vec<usize> = (0..exacts.len()).collect();
+            order.sort_by_key(|&index| std::cmp::Reverse(exacts[index].len()));
+            let patterns: Vec<String> = order
+                .iter()
+                .map(|&index| regex::escape(&exacts[index]))
+                .collect();
+            ExactMatcher::Patterns(
+                PatternSet::new(&patterns, search_modifiers)
+                    .unwrap_or_else(|e| panic!("Failed to compile the search strings. {}", e)),
+                order,
+            )
+        };
+
         Self {
-            exact,
+            matcher,
             frequency_strategy,
         }
     }
+
+    fn search_bytes(
+        automaton: &AhoCorasick,
+        frequency_strategy: &mut dyn FrequencyStrategy,
+        searchable: &str,
+    ) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        for mat in automaton.find_iter(searchable) {
+            if frequency_strategy.matches_frequency() {
+                hits.push(Hit::for_pattern(
+                    mat.start(),
+                    mat.len(),
+                    mat.pattern().as_usize(),
+                ));
+            }
+            if frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn search_with_modifiers(
+        pattern_set: &PatternSet,
+        order: &[usize],
+        frequency_strategy: &mut dyn FrequencyStrategy,
+        searchable: &str,
+    ) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let mut pos = 0;
+        while let Some((start, end, pattern_index)) = pattern_set.find_at(searchable, pos) {
+            if frequency_strategy.matches_frequency() {
+                hits.push(Hit::for_pattern(start, end - start, order[pattern_index]));
+            }
+            if frequency_strategy.is_done() {
+                return hits;
+            }
+            pos = end;
+        }
+        hits
+    }
 }
 
 impl SearchStrategy for ExactSearchStrategy {
-    fn strategy_type(&self) -> SearchStrategyType {
-        SearchStrategyType::Exact
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::FindString
     }
 
-    /// Searches for occurrences of a exact string.
+    /// Searches for occurrences of the exact strings in a single scan.
     ///
     /// # Parameters
     /// - `searchable`: The text to search within.
@@ -34,12 +112,14 @@ impl SearchStrategy for ExactSearchStrategy {
     /// # Examples
     ///
     /// ```
-    /// use seer::search_strategies::exact_search_strategy::ExactSearchStrategy;
-    /// use seer::search_strategies::search_strategy::SearchStrategy;
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::find_string_strategy::ExactSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
     ///
     /// let mut strategy = ExactSearchStrategy::new(
-    ///     String::from("test"),
+    ///     vec![String::from("test")],
+    ///     SearchModifiers::default(),
     ///     FrequencyStrategyFactory::make_for_testing()
     /// );
     ///
@@ -47,30 +127,32 @@ impl SearchStrategy for ExactSearchStrategy {
     /// let hits = strategy.search(searchable);
     ///
     /// assert_eq!(hits.len(), 4);
-    /// assert_eq!(hits[0].get_value(), "test");
+    /// assert_eq!(hits[0].get_value(searchable), "test");
     /// assert_eq!(hits[0].get_position(), 0);
     /// ```
     fn search(&mut self, searchable: &str) -> Vec<Hit> {
-        let mut hits = Vec::new();
-        let exact_len = self.exact.len();
-        let mut pos = 0;
-        while let Some(start) = searchable[pos..].find(&self.exact) {
-            let position = pos + start;
-            if self.frequency_strategy.matches_frequency() {
-                hits.push(Hit::new(self.exact.clone(), position));
-            }
-            if self.frequency_strategy.is_done() {
-                return hits;
+        match &self.matcher {
+            ExactMatcher::Automaton(automaton) => {
+                Self::search_bytes(automaton, self.frequency_strategy.as_mut(), searchable)
             }
-            pos = position + exact_len;
+            ExactMatcher::Patterns(pattern_set, order) => Self::search_with_modifiers(
+                pattern_set,
+                order,
+                self.frequency_strategy.as_mut(),
+                searchable,
+            ),
         }
-        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
+    use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
 
     use super::*;
 
@@ -78,7 +160,8 @@ mod tests {
     fn test_exact_strategy() {
         let exact = "test";
         let mut strategy = ExactSearchStrategy::new(
-            exact.to_string(),
+            vec![exact.to_string()],
+            SearchModifiers::default(),
             FrequencyStrategyFactory::make_for_testing(),
         );
 
@@ -86,17 +169,112 @@ mod tests {
         let hits = strategy.search(searchable);
 
         assert_eq!(hits.len(), 4);
-        assert_eq!(hits[0].get_value(), exact);
+        assert_eq!(hits[0].get_value(searchable), exact);
         assert_eq!(hits[0].get_position(), 0);
         assert_eq!(hits[0].get_end_position(), 3);
-        assert_eq!(hits[1].get_value(), exact);
+        assert_eq!(hits[1].get_value(searchable), exact);
         assert_eq!(hits[1].get_position(), 14);
         assert_eq!(hits[1].get_end_position(), 17);
-        assert_eq!(hits[2].get_value(), exact);
+        assert_eq!(hits[2].get_value(searchable), exact);
         assert_eq!(hits[2].get_position(), 32);
         assert_eq!(hits[2].get_end_position(), 35);
-        assert_eq!(hits[3].get_value(), exact);
+        assert_eq!(hits[3].get_value(searchable), exact);
         assert_eq!(hits[3].get_position(), 46);
         assert_eq!(hits[3].get_end_position(), 49);
     }
+
+    #[test]
+    fn test_exact_strategy_ignoring_case() {
+        let mut strategy = ExactSearchStrategy::new(
+            vec![String::from("straße")],
+            SearchModifiers::new(CaseSensitivity::Insensitive, false),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "Straße STRAßE strasse";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "Straße");
+        assert_eq!(hits[1].get_value(searchable), "STRAßE");
+    }
+
+    #[test]
+    fn test_exact_strategy_smart_case() {
+        let searchable = "import Foo; import foo;";
+
+        let mut strategy = ExactSearchStrategy::new(
+            vec![String::from("foo")],
+            SearchModifiers::new(CaseSensitivity::Smart, false),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+        assert_eq!(strategy.search(searchable).len(), 2);
+
+        let mut strategy = ExactSearchStrategy::new(
+            vec![String::from("Foo")],
+            SearchModifiers::new(CaseSensitivity::Smart, false),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_position(), 7);
+    }
+
+    #[test]
+    fn test_exact_strategy_whole_word() {
+        let mut strategy = ExactSearchStrategy::new(
+            vec![String::from("test")],
+            SearchModifiers::new(CaseSensitivity::Sensitive, true),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "test tester retest (test) test_case";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_position(), 0);
+        assert_eq!(hits[1].get_position(), 20);
+    }
+
+    #[test]
+    fn test_exact_strategy_with_several_strings() {
+        let mut strategy = ExactSearchStrategy::new(
+            vec![
+                String::from("old_api"),
+                String::from("legacy"),
+                String::from("old_api_v2"),
+            ],
+            SearchModifiers::default(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "legacy(old_api_v2(old_api))";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].get_value(searchable), "legacy");
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_value(searchable), "old_api_v2");
+        assert_eq!(hits[1].get_pattern_index(), 2);
+        assert_eq!(hits[2].get_value(searchable), "old_api");
+        assert_eq!(hits[2].get_pattern_index(), 0);
+    }
+
+    #[test]
+    fn test_exact_strategy_with_several_strings_and_modifiers() {
+        let mut strategy = ExactSearchStrategy::new(
+            vec![String::from("old_api"), String::from("old_api_v2")],
+            SearchModifiers::new(CaseSensitivity::Insensitive, true),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "OLD_API_V2 old_api_v3 Old_Api";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "OLD_API_V2");
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_value(searchable), "Old_Api");
+        assert_eq!(hits[1].get_pattern_index(), 0);
+    }
 }
@@ -0,0 +1,147 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::helpers::git::{list_files_at_rev, read_file_at_rev};
+use std::io::{self, Cursor, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// A source strategy that searches the files given with `--dir` or `--files` as they were at the
+/// git revision given with `--at-rev`.
+///
+/// Files are read from the repository's objects, so the working copy is never touched and the
+/// sources are read-only. Git is run locally and never fetches.
+pub struct GitRevSourceStrategy {
+    dir: PathBuf,
+    rev: String,
+    paths: Vec<String>,
+    max_depth: Option<u64>,
+}
+
+impl GitRevSourceStrategy {
+    /// Creates a strategy that runs git in `dir`, or in the current directory if `dir` is empty.
+    ///
+    /// Directories in `paths` are searched down to `max_depth` subdirectory levels if given.
+    pub fn new(dir: PathBuf, rev: String, paths: Vec<String>, max_depth: Option<u64>) -> Self {
+        Self {
+            dir,
+            rev,
+            paths,
+            max_depth,
+        }
+    }
+}
+
+impl SourceStrategy for GitRevSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::GitRev
+    }
+
+    /// Returns a source for each file at the revision, named by its path.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        let names = list_files_at_rev(&self.dir, &self.rev, &self.paths)?;
+        let Some(max_depth) = self.max_depth else {
+            return Ok(names);
+        };
+
+        Ok(names
+            .into_iter()
+            .filter(|name| {
+                self.paths
+                    .iter()
+                    .any(|path| get_depth(path, name) <= max_depth as usize)
+            })
+            .collect())
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        let bytes = read_file_at_rev(&self.dir, &self.rev, name).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read source: '{}' at '{}'. {}", name, self.rev, e),
+            )
+        })?;
+        Ok(Box::new(Cursor::new(bytes)))
+    }
+}
+
+// Returns how many directory levels below `dir` the file at `path` is.
+fn get_depth(dir: &str, path: &str) -> usize {
+    let count = |path: &str| {
+        Path::new(path)
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .count()
+    };
+    count(path).saturating_sub(count(dir) + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::git::run_git;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn get_repo() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let mut all_args = vec!["-c", "user.name=test", "-c", "user.email=test@test"];
+            all_args.extend(args);
+            run_git(dir.path(), &all_args).unwrap();
+        };
+        git(&["init", "-q"]);
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("a.txt"), "old a").unwrap();
+        fs::write(dir.path().join("sub").join("b.txt"), "old b").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "first"]);
+        git(&["tag", "v1"]);
+        fs::write(dir.path().join("a.txt"), "new a").unwrap();
+        git(&["commit", "-q", "-a", "-m", "second"]);
+        dir
+    }
+
+    #[test]
+    fn test_git_rev_source_strategy() {
+        let repo = get_repo();
+        let strategy = GitRevSourceStrategy::new(
+            repo.path().to_path_buf(),
+            String::from("v1"),
+            vec![String::from(".")],
+            None,
+        );
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names, vec!["a.txt", "sub/b.txt"]);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "old a");
+        assert_eq!(
+            strategy.write(&names[0], b"edited").unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_git_rev_source_strategy_with_max_depth() {
+        let repo = get_repo();
+        let strategy = GitRevSourceStrategy::new(
+            repo.path().to_path_buf(),
+            String::from("v1"),
+            vec![String::from("./")],
+            Some(0),
+        );
+
+        assert_eq!(strategy.get_names().unwrap(), vec!["a.txt"]);
+    }
+
+    #[test]
+    fn test_git_rev_source_strategy_with_missing_rev() {
+        let repo = get_repo();
+        let strategy = GitRevSourceStrategy::new(
+            repo.path().to_path_buf(),
+            String::from("v2"),
+            vec![String::from("a.txt")],
+            None,
+        );
+
+        assert!(strategy.get_names().is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use super::enums::encoding::Encoding;
+use super::enums::git_selection::GitSelection;
+use super::enums::mmap_mode::MmapMode;
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use crate::dto::source_text::SourceText;
+use crate::helpers::file_sys::{load_file, open_file, write_file};
+use crate::helpers::git::{list_added_lines, list_changed_files, AddedLines};
+use std::cell::OnceCell;
+use std::io::{self, Cursor, Read};
+use std::path::PathBuf;
+
+/// A source strategy that searches the files changed in a git repository,
+/// as given with `--git-changed`, `--git-staged`, or `--git-diff`.
+///
+/// Git is run locally and never fetches. Files are read from the working copy.
+/// With `--git-hunks`, only the lines added by the changes are searched instead. Each run of
+/// added lines is its own read-only source, named by its path and the line it starts on, like `src/main.rs:12`.
+pub struct GitSourceStrategy {
+    dir: PathBuf,
+    selection: GitSelection,
+    hunks: bool,
+    mmap_mode: MmapMode,
+    added_lines: OnceCell<Vec<AddedLines>>,
+}
+
+impl GitSourceStrategy {
+    /// Creates a strategy that runs git in `dir`, or in the current directory if `dir` is empty.
+    pub fn new(dir: PathBuf, selection: GitSelection, hunks: bool, mmap_mode: MmapMode) -> Self {
+        Self {
+            dir,
+            selection,
+            hunks,
+            mmap_mode,
+            added_lines: OnceCell::new(),
+        }
+    }
+
+    // Returns the runs of added lines, running git on first use.
+    fn get_added_lines(&self) -> io::Result<&Vec<AddedLines>> {
+        if let Some(added_lines) = self.added_lines.get() {
+            return Ok(added_lines);
+        }
+
+        let added_lines = list_added_lines(&self.dir, &self.selection)?;
+        Ok(self.added_lines.get_or_init(|| added_lines))
+    }
+
+    // Returns the added lines of the source with the given name.
+    fn find_added_lines(&self, name: &str) -> io::Result<&AddedLines> {
+        self.get_added_lines()?
+            .iter()
+            .find(|added_lines| get_hunk_name(added_lines) == name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("Failed to read source: '{}'. No such added lines", name),
+                )
+            })
+    }
+}
+
+impl SourceStrategy for GitSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Git
+    }
+
+    /// Returns a source for each changed file, named by its path, or for each run of added lines.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        if !self.hunks {
+            return list_changed_files(&self.dir, &self.selection);
+        }
+
+        Ok(self.get_added_lines()?.iter().map(get_hunk_name).collect())
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>> {
+        if !self.hunks {
+            return Ok(Box::new(open_file(name)?));
+        }
+
+        let text = self.find_added_lines(name)?.get_text();
+        Ok(Box::new(Cursor::new(text.as_bytes().to_vec())))
+    }
+
+    fn load(&self, name: &str, encoding: Option<Encoding>) -> io::Result<SourceText> {
+        if !self.hunks {
+            return load_file(name, self.mmap_mode, encoding);
+        }
+
+        let text = self.find_added_lines(name)?.get_text();
+        Ok(SourceText::from_bytes(text.as_bytes().to_vec(), encoding))
+    }
+
+    /// Replaces the changed file. Added lines are only part of a file, so they cannot be edited in place.
+    fn write(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        if self.hunks {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Source '{}' cannot be edited in place", name),
+            ));
+        }
+        write_file(name, bytes)
+    }
+}
+
+// Returns the name of the source for a run of added lines.
+fn get_hunk_name(added_lines: &AddedLines) -> String {
+    format!(
+        "{}:{}",
+        added_lines.get_path(),
+        added_lines.get_start_line()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::git::run_git;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn get_repo() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let mut all_args = vec!["-c", "user.name=test", "-c", "user.email=test@test"];
+            all_args.extend(args);
+            run_git(dir.path(), &all_args).unwrap();
+        };
+        git(&["init", "-q"]);
+        fs::write(dir.path().join("a.txt"), "one\ntwo\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "first"]);
+        fs::write(dir.path().join("a.txt"), "one\nnew\ntwo\n").unwrap();
+        git(&["add", "."]);
+        dir
+    }
+
+    #[test]
+    fn test_git_source_strategy() {
+        let repo = get_repo();
+        let strategy = GitSourceStrategy::new(
+            repo.path().to_path_buf(),
+            GitSelection::Staged,
+            false,
+            MmapMode::Auto,
+        );
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names.len(), 1);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "one\nnew\ntwo\n");
+    }
+
+    #[test]
+    fn test_git_source_strategy_with_hunks() {
+        let repo = get_repo();
+        let strategy = GitSourceStrategy::new(
+            repo.path().to_path_buf(),
+            GitSelection::Staged,
+            true,
+            MmapMode::Auto,
+        );
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names.len(), 1);
+        assert!(names[0].ends_with("a.txt:2"));
+        assert_eq!(&*strategy.load(&names[0], None).unwrap(), "new\n");
+        assert_eq!(
+            strategy.write(&names[0], b"edited").unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::lines::lines_without_hits;
+use std::io;
+
+/// A search strategy that selects the lines another search strategy has no hit in, like `grep -v`.
+///
+/// Each selected line becomes one hit spanning the line, without its terminator,
+/// so the edit stage operates on whole lines. For example, prepending `# ` comments out every line without a hit.
+/// The frequency strategy counts the selected lines.
+pub struct InvertSearchStrategy {
+    search_strategy: Box<dyn SearchStrategy>,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl InvertSearchStrategy {
+    pub fn new(
+        search_strategy: Box<dyn SearchStrategy>,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Self {
+        Self {
+            search_strategy,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for InvertSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Invert
+    }
+
+    /// Searches the whole text and returns a hit for every line that no hit touches.
+    ///
+    /// A hit spanning several lines, like one from `--find_between`, excludes all of them.
+    ///
+    /// # Parameters
+    /// - `searchable`: The text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the lines without a hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::find_string_strategy::ExactSearchStrategy;
+    /// use seer::strategies::invert_strategy::InvertSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = InvertSearchStrategy::new(
+    ///     Box::new(ExactSearchStrategy::new(
+    ///         vec![String::from("keep")],
+    ///         SearchModifiers::default(),
+    ///         FrequencyStrategyFactory::make_for_testing(),
+    ///     )),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "keep me\ndrop me\nkeep me too";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "drop me");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let excluded = self.search_strategy.search(searchable);
+
+        for (start, end) in lines_without_hits(searchable, &excluded) {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::new(start, end - start));
+            }
+
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+
+    fn check(&self, searchable: &str) -> io::Result<()> {
+        self.search_strategy.check(searchable)
+    }
+
+    fn set_source_name(&mut self, name: &str) {
+        self.search_strategy.set_source_name(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::search_modifiers::SearchModifiers;
+    use crate::strategies::every_nth_strategy::EveryNthFrequencyStrategy;
+    use crate::strategies::find_between_strategy::BetweenSearchStrategy;
+    use crate::strategies::find_string_strategy::ExactSearchStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_invert_strategy_excludes_every_line_of_a_hit() {
+        let mut strategy = InvertSearchStrategy::new(
+            Box::new(
+                BetweenSearchStrategy::new(
+                    String::from(r"/\*"),
+                    String::from(r"\*/"),
+                    false,
+                    false,
+                    Vec::new(),
+                    SearchModifiers::default(),
+                    FrequencyStrategyFactory::make_for_testing(),
+                )
+                .unwrap(),
+            ),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "a();\n/* b();\nc(); */\nd(); /* e */\n\nf();";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 3);
+        assert_eq!(hits[0].get_value(searchable), "a();");
+        assert_eq!(hits[1].get_value(searchable), "");
+        assert_eq!(hits[2].get_value(searchable), "f();");
+    }
+
+    #[test]
+    fn test_invert_strategy_counts_lines_for_frequency() {
+        let mut strategy = InvertSearchStrategy::new(
+            Box::new(ExactSearchStrategy::new(
+                vec![String::from("x")],
+                SearchModifiers::default(),
+                FrequencyStrategyFactory::make_for_testing(),
+            )),
+            Box::new(EveryNthFrequencyStrategy::new(2, 0)),
+        );
+
+        let searchable = "a\nx\nb\nc\nd";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "b");
+        assert_eq!(hits[1].get_value(searchable), "d");
+    }
+}
@@ -0,0 +1,154 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::json_path::JsonPath;
+
+/// A search strategy that finds the values a JSONPath selects in a JSON document.
+///
+/// Each hit spans one value, so edits replace, append to, or prepend to the value
+/// and leave the rest of the document as it was written.
+/// A string is shown with its escapes decoded, and text written into it is escaped.
+/// A searchable that is not valid JSON has no hits.
+pub struct JsonPathSearchStrategy {
+    json_path: JsonPath,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl JsonPathSearchStrategy {
+    pub fn new(json_path: JsonPath, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+        Self {
+            json_path,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for JsonPathSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::JsonPath
+    }
+
+    /// Searches for the values at the JSONPath.
+    ///
+    /// # Parameters
+    /// - `searchable`: The JSON text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::json_path::JsonPath;
+    /// use seer::strategies::json_path_strategy::JsonPathSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = JsonPathSearchStrategy::new(
+    ///     JsonPath::parse("$.services[*].image").unwrap(),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = r#"{"services": [{"image": "nginx:1"}, {"image": "redis:7"}]}"#;
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 2);
+    /// assert_eq!(hits[1].get_value(searchable), "redis:7");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let Ok(spans) = self.json_path.select(searchable) else {
+            return hits;
+        };
+
+        for (start, end, escaping) in spans {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_json_path_strategy() {
+        let mut strategy = JsonPathSearchStrategy::new(
+            JsonPath::parse("$.services[*].image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "{\n  \"services\": [\n    { \"image\": \"nginx:1\" },\n    { \"image\": \"redis:7\" }\n  ]\n}\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "nginx:1");
+        assert_eq!(hits[0].get_position(), 34);
+        assert_eq!(hits[1].get_value(searchable), "redis:7");
+    }
+
+    #[test]
+    fn test_edits_at_hits_keep_the_formatting() {
+        let mut strategy = JsonPathSearchStrategy::new(
+            JsonPath::parse("$..image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "{\n\t\"image\" :\"nginx:1\",  \"port\": 80,\r\n\t\"image\": \"\" }";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                Edit::replacing(
+                    hit.get_position(),
+                    hit.get_length(),
+                    String::from("nginx:2"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "{\n\t\"image\" :\"nginx:2\",  \"port\": 80,\r\n\t\"image\": \"nginx:2\" }"
+        );
+    }
+
+    #[test]
+    fn test_json_path_strategy_applies_the_frequency() {
+        let mut strategy = JsonPathSearchStrategy::new(
+            JsonPath::parse("$[*]").unwrap(),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "[1, 2, 3]";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+
+    #[test]
+    fn test_json_path_strategy_without_json() {
+        let mut strategy = JsonPathSearchStrategy::new(
+            JsonPath::parse("$.image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        assert!(strategy.search("image: nginx").is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::key_value::find_values;
+use crate::strategies::enums::kv_dialect::KvDialect;
+
+/// A search strategy that finds the values of a key in `.env`, INI, or `.properties` files,
+/// either within one INI section or before any section header.
+/// A source is read as `.properties` text if its name has that extension, and as `.env` or INI text otherwise.
+///
+/// Each hit spans one value, so edits change it and leave keys, quotes, and comments as they were written.
+/// A new value is quoted or escaped as needed, so that a `#` or `;` in it does not start a comment.
+pub struct KvKeySearchStrategy {
+    key: String,
+    section: Option<String>,
+    dialect: KvDialect,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl KvKeySearchStrategy {
+    pub fn new(
+        key: String,
+        section: Option<String>,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Self {
+        Self {
+            key,
+            section,
+            dialect: KvDialect::Ini,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for KvKeySearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::KvKey
+    }
+
+    /// Searches for the values of the key.
+    ///
+    /// # Parameters
+    /// - `searchable`: The key-value text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::strategies::kv_key_strategy::KvKeySearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = KvKeySearchStrategy::new(
+    ///     String::from("DB_HOST"),
+    ///     None,
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "# DB_HOST=old\nDB_HOST=\"db.internal\"\n";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "db.internal");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+
+        for (start, end, escaping) in
+            find_values(searchable, &self.key, self.section.as_deref(), self.dialect)
+        {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+
+    fn set_source_name(&mut self, name: &str) {
+        self.dialect = KvDialect::from_source_name(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::helpers::escaping::escape;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    fn replace_values(strategy: &mut KvKeySearchStrategy, searchable: &str, value: &str) -> String {
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                let new_value = escape(value, hit.get_escaping()).unwrap();
+                Edit::replacing(hit.get_position(), hit.get_length(), new_value.into_owned())
+            })
+            .collect();
+        apply_edits(searchable, edits.iter())
+    }
+
+    #[test]
+    fn test_edits_leave_comments_and_other_keys() {
+        let mut strategy = KvKeySearchStrategy::new(
+            String::from("LOG_LEVEL"),
+            None,
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "# LOG_LEVEL=debug for local runs\r\nLOG_LEVEL='info' # default\r\nLOG_LEVEL_SQL=info\r\nEMPTY=\r\n";
+        assert_eq!(
+            replace_values(&mut strategy, searchable, "warn"),
+            "# LOG_LEVEL=debug for local runs\r\nLOG_LEVEL='warn' # default\r\nLOG_LEVEL_SQL=info\r\nEMPTY=\r\n"
+        );
+    }
+
+    #[test]
+    fn test_edits_only_within_the_section() {
+        let mut strategy = KvKeySearchStrategy::new(
+            String::from("port"),
+            Some(String::from("database")),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "[server]\nport = 8080\n\n[database]\n; port = 1\nport = 5432\n";
+        assert_eq!(
+            replace_values(&mut strategy, searchable, "6432"),
+            "[server]\nport = 8080\n\n[database]\n; port = 1\nport = 6432\n"
+        );
+    }
+
+    #[test]
+    fn test_edits_quote_values_that_would_start_a_comment() {
+        let mut strategy = KvKeySearchStrategy::new(
+            String::from("GREETING"),
+            None,
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "GREETING=hi # short\n[other]\nGREETING=hi\n";
+        assert_eq!(
+            replace_values(&mut strategy, searchable, "hi; #1 \"fan\""),
+            "GREETING=\"hi; #1 \\\"fan\\\"\" # short\n[other]\nGREETING=hi\n"
+        );
+    }
+
+    #[test]
+    fn test_kv_key_strategy_applies_the_frequency() {
+        let mut strategy = KvKeySearchStrategy::new(
+            String::from("a"),
+            None,
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "a=1\na=2\na=3\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+
+    #[test]
+    fn test_edits_properties_values_by_the_source_name() {
+        let mut strategy = KvKeySearchStrategy::new(
+            String::from("greeting"),
+            None,
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+        let searchable = "greeting hi # not a comment \\\n  there\n";
+
+        strategy.set_source_name("messages.properties");
+        assert_eq!(
+            replace_values(&mut strategy, searchable, " hi; #1\n"),
+            "greeting \\ hi; #1\\n\n"
+        );
+
+        strategy.set_source_name(".env");
+        assert!(strategy.search(searchable).is_empty());
+    }
+}
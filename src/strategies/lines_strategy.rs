@@ -0,0 +1,170 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::lines::line_spans;
+
+/// A search strategy that matches another search strategy within each line, like grep.
+///
+/// Patterns never see past the end of a line, so `^` and `$` anchor to it and `.` cannot cross it.
+/// Each line with a match becomes one hit spanning the line, without its terminator.
+/// The frequency strategy counts lines rather than the matches within them.
+pub struct LineSearchStrategy {
+    search_strategy: Box<dyn SearchStrategy>,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl LineSearchStrategy {
+    pub fn new(
+        search_strategy: Box<dyn SearchStrategy>,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Self {
+        Self {
+            search_strategy,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for LineSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Lines
+    }
+
+    /// Searches each line and returns a hit for every line with a match.
+    ///
+    /// The hit records the pattern of the first match in the line.
+    ///
+    /// # Parameters
+    /// - `searchable`: The text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the lines with a match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::search_modifiers::SearchModifiers;
+    /// use seer::strategies::find_regex_strategy::RegexSearchStrategy;
+    /// use seer::strategies::lines_strategy::LineSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = LineSearchStrategy::new(
+    ///     Box::new(
+    ///         RegexSearchStrategy::new(
+    ///             vec![String::from(r"^\d+$")],
+    ///             SearchModifiers::default(),
+    ///             FrequencyStrategyFactory::make_for_testing(),
+    ///         )
+    ///         .unwrap(),
+    ///     ),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "abc\n123\n4x";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "123");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+
+        for (start, end) in line_spans(searchable) {
+            let line_hits = self.search_strategy.search(&searchable[start..end]);
+
+            if let Some(first_hit) = line_hits.first() {
+                if self.frequency_strategy.matches_frequency() {
+                    hits.push(Hit::for_pattern(
+                        start,
+                        end - start,
+                        first_hit.get_pattern_index(),
+                    ));
+                }
+
+                if self.frequency_strategy.is_done() {
+                    return hits;
+                }
+            }
+        }
+
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::search_modifiers::SearchModifiers;
+    use crate::strategies::find_regex_strategy::RegexSearchStrategy;
+    use crate::strategies::find_string_strategy::ExactSearchStrategy;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    fn get_regex_strategy(regexes: &[&str]) -> Box<dyn SearchStrategy> {
+        Box::new(
+            RegexSearchStrategy::new(
+                regexes.iter().map(|regex| regex.to_string()).collect(),
+                SearchModifiers::default(),
+                FrequencyStrategyFactory::make_for_testing(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_lines_strategy_anchors_to_each_line() {
+        let mut strategy = LineSearchStrategy::new(
+            get_regex_strategy(&[r"^fn .*\)$"]),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "fn a()\r\n  fn b()\nfn c() {\nfn d()";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "fn a()");
+        assert_eq!(hits[1].get_value(searchable), "fn d()");
+        assert_eq!(hits[1].get_position(), 26);
+    }
+
+    #[test]
+    fn test_lines_strategy_reports_each_line_once() {
+        let mut strategy = LineSearchStrategy::new(
+            get_regex_strategy(&["x", "y"]),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "a y x\nb\nx x";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "a y x");
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_value(searchable), "x x");
+        assert_eq!(hits[1].get_pattern_index(), 0);
+    }
+
+    #[test]
+    fn test_lines_strategy_counts_lines_for_frequency() {
+        let mut strategy = LineSearchStrategy::new(
+            Box::new(ExactSearchStrategy::new(
+                vec![String::from("a")],
+                SearchModifiers::default(),
+                FrequencyStrategyFactory::make_for_testing(),
+            )),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "a a a\nb\na\na";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_position(), 8);
+    }
+}
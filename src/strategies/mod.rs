@@ -3,7 +3,10 @@ pub mod traits;
 
 pub mod all_strategy;
 pub mod append_strategy;
+pub mod archive_strategy;
+pub mod column_strategy;
 pub mod count_strategy;
+pub mod css_selector_strategy;
 pub mod dir_strategy;
 pub mod every_nth_strategy;
 pub mod file_strategy;
@@ -11,8 +14,17 @@ pub mod files_strategy;
 pub mod find_between_strategy;
 pub mod find_regex_strategy;
 pub mod find_string_strategy;
+pub mod git_rev_strategy;
+pub mod git_strategy;
+pub mod invert_strategy;
+pub mod json_path_strategy;
+pub mod kv_key_strategy;
+pub mod lines_strategy;
 pub mod nth_strategy;
 pub mod pipe_strategy;
 pub mod prepend_strategy;
 pub mod replace_strategy;
 pub mod text_strategy;
+pub mod toml_key_strategy;
+pub mod xml_path_strategy;
+pub mod yaml_path_strategy;
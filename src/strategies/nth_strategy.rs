@@ -1,8 +1,10 @@
-use super::frequency_strategy::FrequencyStrategy;
-use super::frequency_strategy_type::FrequencyStrategyType;
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::strategy::Strategy;
+use crate::dto::dto::DTO;
 
 pub struct NthFrequencyStrategy {
-    exact_count: usize,
+    nth: usize,
     counter: usize,
 }
 
@@ -10,41 +12,91 @@ impl NthFrequencyStrategy {
     /// Creates a new `NthFrequencyStrategy`.
     ///
     /// # Parameters
-    /// - `nth`: The exact count for the strategy.
+    /// - `nth`: The 1-based count of the only hit to keep.
     ///
     /// # Returns
     /// A new `NthFrequencyStrategy` instance.
     ///
+    /// # Panics
+    /// Panics if `nth` is 0.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use seer::frequency_strategies::nth_frequency_strategy::NthFrequencyStrategy;
+    /// use seer::strategies::nth_strategy::NthFrequencyStrategy;
     ///
     /// let strategy = NthFrequencyStrategy::new(3);
     /// ```
     pub fn new(nth: usize) -> Self {
-        Self {
-            exact_count: nth,
-            counter: 0,
-        }
+        assert!(nth > 0, "The nth hit is counted from 1");
+
+        Self { nth, counter: 0 }
+    }
+}
+
+impl Strategy for NthFrequencyStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Nth
     }
 
-    fn increment_counter(&mut self) {
-        self.counter += 1;
+    fn run(&self, dto: &mut DTO) {
+        dto.retain_hits(|count| self.selects(count));
     }
 }
 
 impl FrequencyStrategy for NthFrequencyStrategy {
-    fn strategy_type(&self) -> FrequencyStrategyType {
-        FrequencyStrategyType::Nth
+    fn selects(&self, count: usize) -> bool {
+        count == self.nth
+    }
+
+    fn is_exhausted_after(&self, count: usize) -> bool {
+        count >= self.nth
+    }
+
+    fn get_counter(&self) -> usize {
+        self.counter
+    }
+
+    fn set_counter(&mut self, counter: usize) {
+        self.counter = counter;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selects_only_the_nth_hit() {
+        let strategy = NthFrequencyStrategy::new(2);
+        assert!(!strategy.selects(1));
+        assert!(strategy.selects(2));
+        assert!(!strategy.selects(3));
+    }
+
+    #[test]
+    fn test_is_done_after_the_nth_hit() {
+        let mut strategy = NthFrequencyStrategy::new(2);
+        assert!(!strategy.matches_frequency());
+        assert!(!strategy.is_done());
+        assert!(strategy.matches_frequency());
+        assert!(strategy.is_done());
+        assert!(!strategy.matches_frequency());
+        assert!(strategy.is_done());
     }
 
-    fn matches_frequency(&mut self) -> bool {
-        self.increment_counter();
-        self.counter == self.exact_count
+    #[test]
+    fn test_reset_restarts_the_count() {
+        let mut strategy = NthFrequencyStrategy::new(1);
+        assert!(strategy.matches_frequency());
+        strategy.reset();
+        assert!(!strategy.is_done());
+        assert!(strategy.matches_frequency());
     }
 
-    fn is_done(&self) -> bool {
-        self.counter == self.exact_count
+    #[test]
+    #[should_panic(expected = "The nth hit is counted from 1")]
+    fn test_nth_of_zero_is_rejected() {
+        NthFrequencyStrategy::new(0);
     }
 }
@@ -0,0 +1,23 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use std::io::{self, Read};
+
+/// A source strategy that searches the text piped to stdin, with `--stdin`, `--file -`, or no other source.
+///
+/// Stdin is read as it arrives, so `tail -f | seer --stream` prints hits as they are found.
+pub struct PipeSourceStrategy;
+
+impl SourceStrategy for PipeSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Pipe
+    }
+
+    /// Returns the single source, named `stdin`.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        Ok(vec![String::from("stdin")])
+    }
+
+    fn open(&self, _name: &str) -> io::Result<Box<dyn Read>> {
+        Ok(Box::new(io::stdin()))
+    }
+}
@@ -0,0 +1,36 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::edit_strategy::EditStrategy;
+use crate::helpers::lines::with_line_ending;
+
+/// An edit strategy that prepends the string given with `--prepend` to each hit.
+pub struct PrependEditStrategy {
+    prefix: String,
+}
+
+impl PrependEditStrategy {
+    pub fn new(prefix: String) -> Self {
+        Self { prefix }
+    }
+}
+
+impl EditStrategy for PrependEditStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Prepend
+    }
+
+    fn edit(&self, value: &str, line_ending: &str) -> String {
+        format!("{}{}", with_line_ending(&self.prefix, line_ending), value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prepend_edit_strategy() {
+        let strategy = PrependEditStrategy::new(String::from("bar"));
+        assert_eq!(strategy.edit("foo", "\n"), "barfoo");
+        assert_eq!(strategy.strategy_type(), StrategyType::Prepend);
+    }
+}
@@ -0,0 +1,36 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::edit_strategy::EditStrategy;
+use crate::helpers::lines::with_line_ending;
+
+/// An edit strategy that replaces each hit with the string given with `--replace_with`.
+pub struct ReplaceEditStrategy {
+    replacement: String,
+}
+
+impl ReplaceEditStrategy {
+    pub fn new(replacement: String) -> Self {
+        Self { replacement }
+    }
+}
+
+impl EditStrategy for ReplaceEditStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::ReplaceWith
+    }
+
+    fn edit(&self, _value: &str, line_ending: &str) -> String {
+        with_line_ending(&self.replacement, line_ending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_edit_strategy() {
+        let strategy = ReplaceEditStrategy::new(String::from("bar"));
+        assert_eq!(strategy.edit("foo", "\n"), "bar");
+        assert_eq!(strategy.strategy_type(), StrategyType::ReplaceWith);
+    }
+}
@@ -0,0 +1,47 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::source_strategy::SourceStrategy;
+use std::io::{self, Cursor, Read};
+
+/// A source strategy that searches the text given with `--text`.
+pub struct TextSourceStrategy {
+    text: String,
+}
+
+impl TextSourceStrategy {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+}
+
+impl SourceStrategy for TextSourceStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::Text
+    }
+
+    /// Returns the single source, named `text`.
+    fn get_names(&self) -> io::Result<Vec<String>> {
+        Ok(vec![String::from("text")])
+    }
+
+    fn open(&self, _name: &str) -> io::Result<Box<dyn Read>> {
+        Ok(Box::new(Cursor::new(self.text.clone().into_bytes())))
+    }
+
+    fn read(&self, _name: &str) -> io::Result<String> {
+        Ok(self.text.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_source_strategy() {
+        let strategy = TextSourceStrategy::new(String::from("some text"));
+        let names = strategy.get_names().unwrap();
+
+        assert_eq!(names, vec!["text"]);
+        assert_eq!(strategy.read(&names[0]).unwrap(), "some text");
+    }
+}
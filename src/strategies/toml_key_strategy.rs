@@ -0,0 +1,146 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::toml_key::TomlKey;
+
+/// A search strategy that finds the values of a key in a TOML document, like a `Cargo.toml`.
+///
+/// Each hit spans one value, so edits rewrite the value and leave comments
+/// and the rest of the formatting as they were written.
+/// A searchable that is not valid TOML has no hits.
+pub struct TomlKeySearchStrategy {
+    toml_key: TomlKey,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl TomlKeySearchStrategy {
+    pub fn new(toml_key: TomlKey, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+        Self {
+            toml_key,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for TomlKeySearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::TomlKey
+    }
+
+    /// Searches for the values of the key.
+    ///
+    /// # Parameters
+    /// - `searchable`: The TOML text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::toml_key::TomlKey;
+    /// use seer::strategies::toml_key_strategy::TomlKeySearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = TomlKeySearchStrategy::new(
+    ///     TomlKey::parse("dependencies.serde.version").unwrap(),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "[dependencies.serde]\nversion = \"1.0\"\n";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "1.0");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let Ok(spans) = self.toml_key.select(searchable) else {
+            return hits;
+        };
+
+        for (start, end, escaping) in spans {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_toml_key_strategy() {
+        let mut strategy = TomlKeySearchStrategy::new(
+            TomlKey::parse("dependencies.serde.version").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "[dependencies]\nserde = { version = \"1.0\" }\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "1.0");
+        assert_eq!(hits[0].get_position(), 36);
+    }
+
+    #[test]
+    fn test_edits_at_hits_keep_comments_and_formatting() {
+        let mut strategy = TomlKeySearchStrategy::new(
+            TomlKey::parse("dependencies.*.version").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "[dependencies]\n# serialization\nserde = {version='1.0' , features=[\"derive\"]}\nserde_json.version = \"1.0\" # json\r\n";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| Edit::replacing(hit.get_position(), hit.get_length(), String::from("1.1")))
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "[dependencies]\n# serialization\nserde = {version='1.1' , features=[\"derive\"]}\nserde_json.version = \"1.1\" # json\r\n"
+        );
+    }
+
+    #[test]
+    fn test_toml_key_strategy_applies_the_frequency() {
+        let mut strategy = TomlKeySearchStrategy::new(
+            TomlKey::parse("values[*]").unwrap(),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "values = [1, 2, 3]\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+
+    #[test]
+    fn test_toml_key_strategy_without_toml() {
+        let mut strategy = TomlKeySearchStrategy::new(
+            TomlKey::parse("version").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        assert!(strategy.search("version: 1.0").is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+use crate::strategies::enums::strategy_type::StrategyType;
+
+/// Decides what each hit is replaced with when editing a source.
+pub trait EditStrategy {
+    fn strategy_type(&self) -> StrategyType;
+
+    /// Returns the text that replaces a hit with the given value.
+    ///
+    /// Line endings in the inserted text are written as `line_ending`, so they match the source.
+    /// The hit value itself is kept as it is.
+    fn edit(&self, value: &str, line_ending: &str) -> String;
+}
@@ -1,6 +1,50 @@
 use super::strategy::Strategy;
+use crate::dto::hit::Hit;
 
+/// Decides which hits are kept.
+///
+/// Hits are counted from 1 in the order they are found:
+///
+/// - `--nth N` keeps only hit N.
+/// - `--every_nth N` keeps hits N, 2N, 3N, and so on.
+/// - `--all` keeps every hit.
+///
+/// Implementors only describe which counts they select. Streaming with `matches_frequency`
+/// and post-filtering with `filter` are both built on `selects`, so they always keep the same hits.
 pub trait FrequencyStrategy: Strategy {
-    fn matches_frequency(&mut self) -> bool;
-    fn is_done(&self) -> bool;
+    /// Returns true if the hit with the given 1-based count should be kept.
+    fn selects(&self, count: usize) -> bool;
+
+    /// Returns true if no hit after the given 1-based count can be kept.
+    fn is_exhausted_after(&self, count: usize) -> bool;
+
+    fn get_counter(&self) -> usize;
+
+    fn set_counter(&mut self, counter: usize);
+
+    /// Counts the next hit and returns true if it should be kept.
+    fn matches_frequency(&mut self) -> bool {
+        let count = self.get_counter() + 1;
+        self.set_counter(count);
+        self.selects(count)
+    }
+
+    /// Returns true if no further hits can be kept, so the search may stop early.
+    fn is_done(&self) -> bool {
+        self.is_exhausted_after(self.get_counter())
+    }
+
+    /// Restarts the count before searching a new source.
+    fn reset(&mut self) {
+        self.set_counter(0);
+    }
+
+    /// Keeps the hits that `matches_frequency` would keep if they were streamed in order.
+    fn filter(&self, hits: Vec<Hit>) -> Vec<Hit> {
+        hits.into_iter()
+            .enumerate()
+            .filter(|(index, _)| self.selects(index + 1))
+            .map(|(_, hit)| hit)
+            .collect()
+    }
 }
@@ -1,2 +1,5 @@
+pub mod edit_strategy;
 pub mod frequency_strategy;
+pub mod search_strategy;
+pub mod source_strategy;
 pub mod strategy;
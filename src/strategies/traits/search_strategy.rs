@@ -0,0 +1,20 @@
+use super::frequency_strategy::FrequencyStrategy;
+use crate::dto::hit::Hit;
+use crate::strategies::enums::strategy_type::StrategyType;
+use std::io;
+
+pub trait SearchStrategy {
+    fn strategy_type(&self) -> StrategyType;
+    fn search(&mut self, searchable: &str) -> Vec<Hit>;
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy;
+
+    /// Returns an error if the searchable cannot be searched as asked, so it would have no hits
+    /// for a reason other than not matching. Most searchables can always be searched.
+    fn check(&self, _searchable: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Tells the strategy the name of the source it searches next, for strategies that read
+    /// sources differently by their kind of file. Most strategies don't need it.
+    fn set_source_name(&mut self, _name: &str) {}
+}
@@ -0,0 +1,57 @@
+use crate::dto::source_text::SourceText;
+use crate::strategies::enums::encoding::Encoding;
+use crate::strategies::enums::strategy_type::StrategyType;
+use std::io::{self, Read};
+
+/// Provides the sources to search.
+///
+/// Sources are listed by name first and opened one at a time,
+/// so a large directory never has every file open or in memory at once.
+pub trait SourceStrategy {
+    fn strategy_type(&self) -> StrategyType;
+
+    /// Returns the name of each source, in the order they are searched.
+    fn get_names(&self) -> io::Result<Vec<String>>;
+
+    /// Opens the source with the given name for reading.
+    fn open(&self, name: &str) -> io::Result<Box<dyn Read>>;
+
+    /// Reads the whole source with the given name into a string.
+    fn read(&self, name: &str) -> io::Result<String> {
+        let mut text = String::new();
+        self.open(name)?.read_to_string(&mut text).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read source: '{}'. {}", name, e),
+            )
+        })?;
+        Ok(text)
+    }
+
+    /// Loads the whole source with the given name, mapping it from its file if the strategy allows.
+    ///
+    /// The source is decoded in the given encoding, or the one its BOM gives if `None`.
+    /// Unlike `read`, a source that is not valid UTF-8 is loaded as binary instead of failing.
+    fn load(&self, name: &str, encoding: Option<Encoding>) -> io::Result<SourceText> {
+        let mut bytes = Vec::new();
+        self.open(name)?.read_to_end(&mut bytes).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("Failed to read source: '{}'. {}", name, e),
+            )
+        })?;
+        Ok(SourceText::from_bytes(bytes, encoding))
+    }
+
+    /// Replaces the source with the given name with the given bytes, for `--edit_in_place`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error by default, since only files can be edited in place.
+    fn write(&self, name: &str, _bytes: &[u8]) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Source '{}' cannot be edited in place", name),
+        ))
+    }
+}
@@ -0,0 +1,160 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::xml_path::XmlPath;
+
+/// A search strategy that finds the element text or attribute values an XML path selects
+/// in an XML or HTML document.
+///
+/// Each hit spans one value, so edits change it and leave the rest of the document as it was written.
+/// A value is shown with its character references decoded, and text written into it is escaped.
+pub struct XmlPathSearchStrategy {
+    xml_path: XmlPath,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl XmlPathSearchStrategy {
+    pub fn new(xml_path: XmlPath, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+        Self {
+            xml_path,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for XmlPathSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::XmlPath
+    }
+
+    /// Searches for the values at the XML path.
+    ///
+    /// # Parameters
+    /// - `searchable`: The XML or HTML text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::xml_path::XmlPath;
+    /// use seer::strategies::xml_path_strategy::XmlPathSearchStrategy;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = XmlPathSearchStrategy::new(
+    ///     XmlPath::parse("//dependency/version").unwrap(),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "<dependency><version>4.12</version></dependency>";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "4.12");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+
+        for (start, end, escaping) in self.xml_path.select(searchable) {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::helpers::escaping::escape;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_edits_at_hits_keep_the_formatting() {
+        let mut strategy = XmlPathSearchStrategy::new(
+            XmlPath::parse("//dependency[artifactId='junit']/version").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "<project>\r\n  <!-- pinned -->\r\n  <dependency>\r\n    <artifactId>junit</artifactId>\r\n    <version>\r\n      4.12\r\n    </version>\r\n  </dependency>\r\n  <dependency><artifactId>guava</artifactId><version>31.1</version></dependency>\r\n</project>\r\n";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                Edit::replacing(hit.get_position(), hit.get_length(), String::from("4.13.2"))
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            searchable.replace("4.12", "4.13.2")
+        );
+    }
+
+    #[test]
+    fn test_xml_path_strategy_selects_attributes() {
+        let mut strategy = XmlPathSearchStrategy::new(
+            XmlPath::parse("//plugin/@version").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "<build><plugin version='1'/><plugin version=\"2\"/></build>";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_position(), 24);
+        assert_eq!(hits[1].get_value(searchable), "2");
+    }
+
+    #[test]
+    fn test_decodes_values_and_escapes_new_text() {
+        let mut strategy = XmlPathSearchStrategy::new(
+            XmlPath::parse("//b/@title").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "<a><b title=\"Tom &amp; Jerry\"/></a>";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                assert_eq!(hit.get_unescaped_value(searchable), "Tom & Jerry");
+                let new_value = escape("\"Tom\" & Jerry", hit.get_escaping()).unwrap();
+                Edit::replacing(hit.get_position(), hit.get_length(), new_value.into_owned())
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "<a><b title=\"&quot;Tom&quot; &amp; Jerry\"/></a>"
+        );
+    }
+
+    #[test]
+    fn test_xml_path_strategy_applies_the_frequency() {
+        let mut strategy = XmlPathSearchStrategy::new(
+            XmlPath::parse("/a/b").unwrap(),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "<a><b>1</b><b>2</b><b>3</b></a>";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+}
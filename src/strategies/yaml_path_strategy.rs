@@ -0,0 +1,154 @@
+use super::enums::strategy_type::StrategyType;
+use super::traits::frequency_strategy::FrequencyStrategy;
+use super::traits::search_strategy::SearchStrategy;
+use crate::dto::hit::Hit;
+use crate::helpers::yaml_path::YamlPath;
+
+/// A search strategy that finds the scalar values a key path selects in YAML documents.
+///
+/// Each hit spans one value, so edits rewrite the value and leave comments
+/// and the rest of the formatting as they were written.
+/// A value is shown decoded from its style, and text written into it is quoted or escaped to keep the document valid.
+/// A searchable that is not valid YAML has no hits.
+pub struct YamlPathSearchStrategy {
+    yaml_path: YamlPath,
+    frequency_strategy: Box<dyn FrequencyStrategy>,
+}
+
+impl YamlPathSearchStrategy {
+    pub fn new(yaml_path: YamlPath, frequency_strategy: Box<dyn FrequencyStrategy>) -> Self {
+        Self {
+            yaml_path,
+            frequency_strategy,
+        }
+    }
+}
+
+impl SearchStrategy for YamlPathSearchStrategy {
+    fn strategy_type(&self) -> StrategyType {
+        StrategyType::YamlPath
+    }
+
+    /// Searches every document for the scalar values at the key path.
+    ///
+    /// # Parameters
+    /// - `searchable`: The YAML text to search within.
+    ///
+    /// # Returns
+    /// A vector of `Hit` structs spanning the selected values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seer::helpers::yaml_path::YamlPath;
+    /// use seer::strategies::traits::search_strategy::SearchStrategy;
+    /// use seer::strategies::yaml_path_strategy::YamlPathSearchStrategy;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    ///
+    /// let mut strategy = YamlPathSearchStrategy::new(
+    ///     YamlPath::parse("spec.containers[0].image").unwrap(),
+    ///     FrequencyStrategyFactory::make_for_testing(),
+    /// );
+    ///
+    /// let searchable = "spec:\n  containers:\n    - image: nginx:1\n";
+    /// let hits = strategy.search(searchable);
+    ///
+    /// assert_eq!(hits.len(), 1);
+    /// assert_eq!(hits[0].get_value(searchable), "nginx:1");
+    /// ```
+    fn search(&mut self, searchable: &str) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        let Ok(spans) = self.yaml_path.select(searchable) else {
+            return hits;
+        };
+
+        for (start, end, escaping) in spans {
+            if self.frequency_strategy.matches_frequency() {
+                hits.push(Hit::escaped(start, end - start, escaping));
+            }
+            if self.frequency_strategy.is_done() {
+                return hits;
+            }
+        }
+        hits
+    }
+
+    fn get_frequency_strategy(&mut self) -> &mut dyn FrequencyStrategy {
+        self.frequency_strategy.as_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::edit::Edit;
+    use crate::helpers::edits::apply_edits;
+    use crate::strategies::nth_strategy::NthFrequencyStrategy;
+    use crate::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+
+    #[test]
+    fn test_yaml_path_strategy() {
+        let mut strategy = YamlPathSearchStrategy::new(
+            YamlPath::parse("spec.template.spec.containers[*].image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "spec:\n  template:\n    spec:\n      containers:\n        - image: nginx:1\n        - image: \"redis:7\"\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "nginx:1");
+        assert_eq!(hits[0].get_position(), 63);
+        assert_eq!(hits[1].get_value(searchable), "redis:7");
+    }
+
+    #[test]
+    fn test_edits_at_hits_keep_comments_and_formatting() {
+        let mut strategy = YamlPathSearchStrategy::new(
+            YamlPath::parse("image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        let searchable = "# web\nimage:   nginx:1   # pinned\r\n---\nimage: 'nginx:1'\n";
+        let edits: Vec<Edit> = strategy
+            .search(searchable)
+            .iter()
+            .map(|hit| {
+                Edit::replacing(
+                    hit.get_position(),
+                    hit.get_length(),
+                    String::from("nginx:2"),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            apply_edits(searchable, edits.iter()),
+            "# web\nimage:   nginx:2   # pinned\r\n---\nimage: 'nginx:2'\n"
+        );
+    }
+
+    #[test]
+    fn test_yaml_path_strategy_applies_the_frequency() {
+        let mut strategy = YamlPathSearchStrategy::new(
+            YamlPath::parse("[*]").unwrap(),
+            Box::new(NthFrequencyStrategy::new(2)),
+        );
+
+        let searchable = "- 1\n- 2\n- 3\n";
+        let hits = strategy.search(searchable);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "2");
+    }
+
+    #[test]
+    fn test_yaml_path_strategy_without_yaml() {
+        let mut strategy = YamlPathSearchStrategy::new(
+            YamlPath::parse("image").unwrap(),
+            FrequencyStrategyFactory::make_for_testing(),
+        );
+
+        assert!(strategy.search("image: [nginx").is_empty());
+    }
+}
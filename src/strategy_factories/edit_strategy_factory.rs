@@ -0,0 +1,119 @@
+use crate::strategies::append_strategy::AppendEditStrategy;
+use crate::strategies::enums::eol_mode::EolMode;
+use crate::strategies::prepend_strategy::PrependEditStrategy;
+use crate::strategies::replace_strategy::ReplaceEditStrategy;
+use crate::strategies::traits::edit_strategy::EditStrategy;
+use clap::ArgMatches;
+
+/// Factory for creating edit strategies based on command line arguments.
+pub struct EditStrategyFactory;
+
+impl EditStrategyFactory {
+    /// Creates an edit strategy based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// A boxed `EditStrategy` instance, or `None` if no edit was requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Arg, Command};
+    /// use seer::strategy_factories::edit_strategy_factory::EditStrategyFactory;
+    /// use seer::strategies::enums::strategy_type::StrategyType;
+    ///
+    /// let cmd = Command::new("test")
+    ///     .arg(Arg::new("append").long("append"))
+    ///     .arg(Arg::new("prepend").long("prepend"))
+    ///     .arg(Arg::new("replace_with").long("replace_with"));
+    /// let matches = cmd.try_get_matches_from(vec!["test", "--append", "foo"]).unwrap();
+    ///
+    /// let strategy = EditStrategyFactory::make(&matches).unwrap();
+    /// assert_eq!(strategy.strategy_type(), StrategyType::Append);
+    /// ```
+    pub fn make(inputs: &ArgMatches) -> Option<Box<dyn EditStrategy>> {
+        if let Some(suffix) = inputs.get_one::<String>("append") {
+            return Some(Box::new(AppendEditStrategy::new(suffix.clone())));
+        } else if let Some(prefix) = inputs.get_one::<String>("prepend") {
+            return Some(Box::new(PrependEditStrategy::new(prefix.clone())));
+        } else if let Some(replacement) = inputs.get_one::<String>("replace_with") {
+            return Some(Box::new(ReplaceEditStrategy::new(replacement.clone())));
+        }
+
+        None
+    }
+
+    /// Creates the line ending mode for written sources based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// The `EolMode` given with `--eol`, or `EolMode::Preserve` if none was given.
+    pub fn make_eol_mode(inputs: &ArgMatches) -> EolMode {
+        match inputs.get_one::<String>("eol").map(String::as_str) {
+            Some("lf") => EolMode::Lf,
+            Some("crlf") => EolMode::Crlf,
+            _ => EolMode::Preserve,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::enums::strategy_type::StrategyType;
+    use clap::{Arg, Command};
+
+    fn get_matches(args: Vec<&str>) -> ArgMatches {
+        Command::new("test")
+            .arg(Arg::new("append").long("append"))
+            .arg(Arg::new("prepend").long("prepend"))
+            .arg(Arg::new("replace_with").long("replace_with"))
+            .arg(Arg::new("eol").long("eol"))
+            .try_get_matches_from(args)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_edit_strategies() {
+        let cases = [
+            ("--append", StrategyType::Append),
+            ("--prepend", StrategyType::Prepend),
+            ("--replace_with", StrategyType::ReplaceWith),
+        ];
+
+        for (arg, strategy_type) in cases {
+            let strategy = EditStrategyFactory::make(&get_matches(vec!["test", arg, "foo"]));
+            assert_eq!(strategy.unwrap().strategy_type(), strategy_type);
+        }
+    }
+
+    #[test]
+    fn test_no_edit_strategy() {
+        assert!(EditStrategyFactory::make(&get_matches(vec!["test"])).is_none());
+    }
+
+    #[test]
+    fn test_eol_mode() {
+        let cases = [
+            (vec!["test"], EolMode::Preserve),
+            (vec!["test", "--eol", "preserve"], EolMode::Preserve),
+            (vec!["test", "--eol", "lf"], EolMode::Lf),
+            (vec!["test", "--eol", "crlf"], EolMode::Crlf),
+        ];
+
+        for (args, eol_mode) in cases {
+            assert_eq!(
+                EditStrategyFactory::make_eol_mode(&get_matches(args)),
+                eol_mode
+            );
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use super::all_frequency_strategy::AllFrequencyStrategy;
-use super::every_nth_frequency_strategy::EveryNthFrequencyStrategy;
-use super::frequency_strategy::FrequencyStrategy;
-use super::nth_frequency_strategy::NthFrequencyStrategy;
+use crate::strategies::all_strategy::AllStrategy;
+use crate::strategies::enums::frequency_scope::FrequencyScope;
+use crate::strategies::every_nth_strategy::EveryNthFrequencyStrategy;
+use crate::strategies::nth_strategy::NthFrequencyStrategy;
+use crate::strategies::traits::frequency_strategy::FrequencyStrategy;
 use clap::ArgMatches;
 
 /// Factory for creating frequency strategies based on command line arguments.
@@ -16,18 +17,14 @@ impl FrequencyStrategyFactory {
     ///
     /// # Returns
     ///
-    /// A boxed `FrequencyStrategy` instance.
-    ///
-    /// # Panics
-    ///
-    /// Panics if no valid frequency strategy is provided.
+    /// A boxed `FrequencyStrategy` instance. Without a frequency argument, only the first hit is kept.
     ///
     /// # Examples
     ///
     /// ```
     /// use clap::{Command, Arg};
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
-    /// use seer::frequency_strategies::frequency_strategy_type::FrequencyStrategyType;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::strategies::enums::strategy_type::StrategyType;
     ///
     /// let cmd = Command::new("test")
     ///     .arg(Arg::new("nth")
@@ -38,17 +35,51 @@ impl FrequencyStrategyFactory {
     /// let matches = cmd.try_get_matches_from(vec!["test", "--nth", "3"]).unwrap();
     ///
     /// let strategy = FrequencyStrategyFactory::make(&matches);
-    /// assert_eq!(strategy.strategy_type(), FrequencyStrategyType::Nth);
+    /// assert_eq!(strategy.strategy_type(), StrategyType::Nth);
     /// ```
     pub fn make(inputs: &ArgMatches) -> Box<dyn FrequencyStrategy> {
         if let Some(nth) = inputs.get_one::<u64>("nth") {
-            return Box::new(NthFrequencyStrategy::new(*nth as usize));
+            Box::new(NthFrequencyStrategy::new(*nth as usize))
         } else if let Some(every_nth) = inputs.get_one::<u64>("every_nth") {
-            return Box::new(EveryNthFrequencyStrategy::new(*every_nth as usize, 0));
+            Box::new(EveryNthFrequencyStrategy::new(*every_nth as usize, 0))
         } else if inputs.get_flag("all") {
-            return Box::new(AllFrequencyStrategy::new());
+            Box::new(AllStrategy::new())
         } else {
-            return Box::new(NthFrequencyStrategy::new(1));
+            Box::new(NthFrequencyStrategy::new(1))
+        }
+    }
+
+    /// Determines the scope of the frequency strategy based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the seerd command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// `FrequencyScope::PerSource` if `--per-source` is given, otherwise `FrequencyScope::Global`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::{Command, Arg};
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::strategies::enums::frequency_scope::FrequencyScope;
+    ///
+    /// let cmd = Command::new("test")
+    ///     .arg(Arg::new("per-source")
+    ///     .long("per-source")
+    ///     .action(clap::ArgAction::SetTrue));
+    /// let matches = cmd.try_get_matches_from(vec!["test", "--per-source"]).unwrap();
+    ///
+    /// let scope = FrequencyStrategyFactory::make_scope(&matches);
+    /// assert_eq!(scope, FrequencyScope::PerSource);
+    /// ```
+    pub fn make_scope(inputs: &ArgMatches) -> FrequencyScope {
+        if inputs.get_flag("per-source") {
+            FrequencyScope::PerSource
+        } else {
+            FrequencyScope::Global
         }
     }
 
@@ -56,26 +87,26 @@ impl FrequencyStrategyFactory {
     ///
     /// # Returns
     ///
-    /// A boxed `FrequencyStrategy` instance that always returns an `AllFrequencyStrategy`.
+    /// A boxed `FrequencyStrategy` instance that always returns an `AllStrategy`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
-    /// use seer::frequency_strategies::frequency_strategy_type::FrequencyStrategyType;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::strategies::enums::strategy_type::StrategyType;
     ///
     /// let strategy = FrequencyStrategyFactory::make_for_testing();
-    /// assert_eq!(strategy.strategy_type(), FrequencyStrategyType::All);
+    /// assert_eq!(strategy.strategy_type(), StrategyType::All);
     /// ```
     pub fn make_for_testing() -> Box<dyn FrequencyStrategy> {
-        Box::new(AllFrequencyStrategy::new())
+        Box::new(AllStrategy::new())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::frequency_strategies::frequency_strategy_type::FrequencyStrategyType;
+    use crate::strategies::enums::strategy_type::StrategyType;
     use clap::{Arg, Command};
 
     fn get_command() -> Command {
@@ -95,6 +126,16 @@ mod tests {
                     .action(clap::ArgAction::Set),
             )
             .arg(Arg::new("all").long("all").action(clap::ArgAction::SetTrue))
+            .arg(
+                Arg::new("global")
+                    .long("global")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("per-source")
+                    .long("per-source")
+                    .action(clap::ArgAction::SetTrue),
+            )
     }
 
     #[test]
@@ -104,7 +145,7 @@ mod tests {
             .try_get_matches_from(vec!["test", "--nth", "3"])
             .unwrap();
         let strategy = FrequencyStrategyFactory::make(&matches);
-        assert_eq!(strategy.strategy_type(), FrequencyStrategyType::Nth);
+        assert_eq!(strategy.strategy_type(), StrategyType::Nth);
     }
 
     #[test]
@@ -114,7 +155,7 @@ mod tests {
             .try_get_matches_from(vec!["test", "--every_nth", "2"])
             .unwrap();
         let strategy = FrequencyStrategyFactory::make(&matches);
-        assert_eq!(strategy.strategy_type(), FrequencyStrategyType::EveryNth);
+        assert_eq!(strategy.strategy_type(), StrategyType::EveryNth);
     }
 
     #[test]
@@ -122,7 +163,7 @@ mod tests {
         let cmd = get_command();
         let matches = cmd.try_get_matches_from(vec!["test", "--all"]).unwrap();
         let strategy = FrequencyStrategyFactory::make(&matches);
-        assert_eq!(strategy.strategy_type(), FrequencyStrategyType::All);
+        assert_eq!(strategy.strategy_type(), StrategyType::All);
     }
 
     #[test]
@@ -130,6 +171,32 @@ mod tests {
         let cmd = get_command();
         let matches = cmd.try_get_matches_from(vec!["test"]).unwrap();
         let strategy = FrequencyStrategyFactory::make(&matches);
-        assert_eq!(strategy.strategy_type(), FrequencyStrategyType::Nth);
+        assert_eq!(strategy.strategy_type(), StrategyType::Nth);
+    }
+
+    #[test]
+    fn test_per_source_scope() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--per-source"])
+            .unwrap();
+        let scope = FrequencyStrategyFactory::make_scope(&matches);
+        assert_eq!(scope, FrequencyScope::PerSource);
+    }
+
+    #[test]
+    fn test_global_scope() {
+        let cmd = get_command();
+        let matches = cmd.try_get_matches_from(vec!["test", "--global"]).unwrap();
+        let scope = FrequencyStrategyFactory::make_scope(&matches);
+        assert_eq!(scope, FrequencyScope::Global);
+    }
+
+    #[test]
+    fn test_default_scope() {
+        let cmd = get_command();
+        let matches = cmd.try_get_matches_from(vec!["test"]).unwrap();
+        let scope = FrequencyStrategyFactory::make_scope(&matches);
+        assert_eq!(scope, FrequencyScope::Global);
     }
 }
@@ -1,9 +1,27 @@
-use crate::frequency_strategies::frequency_strategy::FrequencyStrategy;
-
-use super::between_search_strategy::BetweenSearchStrategy;
-use super::exact_search_strategy::ExactSearchStrategy;
-use super::regex_search_strategy::RegexSearchStrategy;
-use super::search_strategy::SearchStrategy;
+use crate::helpers::css_selector::CssSelector;
+use crate::helpers::file_sys::read_file;
+use crate::helpers::json_path::JsonPath;
+use crate::helpers::search_modifiers::SearchModifiers;
+use crate::helpers::toml_key::TomlKey;
+use crate::helpers::xml_path::XmlPath;
+use crate::helpers::yaml_path::YamlPath;
+use crate::strategies::all_strategy::AllStrategy;
+use crate::strategies::column_strategy::ColumnSearchStrategy;
+use crate::strategies::css_selector_strategy::CssSelectorSearchStrategy;
+use crate::strategies::enums::case_sensitivity::CaseSensitivity;
+use crate::strategies::enums::column::Column;
+use crate::strategies::find_between_strategy::{BetweenSearchStrategy, DEFAULT_QUOTES};
+use crate::strategies::find_regex_strategy::RegexSearchStrategy;
+use crate::strategies::find_string_strategy::ExactSearchStrategy;
+use crate::strategies::invert_strategy::InvertSearchStrategy;
+use crate::strategies::json_path_strategy::JsonPathSearchStrategy;
+use crate::strategies::kv_key_strategy::KvKeySearchStrategy;
+use crate::strategies::lines_strategy::LineSearchStrategy;
+use crate::strategies::toml_key_strategy::TomlKeySearchStrategy;
+use crate::strategies::traits::frequency_strategy::FrequencyStrategy;
+use crate::strategies::traits::search_strategy::SearchStrategy;
+use crate::strategies::xml_path_strategy::XmlPathSearchStrategy;
+use crate::strategies::yaml_path_strategy::YamlPathSearchStrategy;
 use clap::ArgMatches;
 
 /// Factory for creating search strategies based on command line arguments.
@@ -12,6 +30,20 @@ pub struct SearchStrategyFactory;
 impl SearchStrategyFactory {
     /// Creates a search strategy based on the provided command line arguments.
     ///
+    /// Every `--find_string` or `--find_regex` given is searched for in the same scan.
+    /// The lines of `--patterns-file` are searched for as exact strings.
+    /// With `--json-path`, `--yaml-path`, or `--toml-key`, the values the path selects
+    /// in a JSON, YAML, or TOML source are the hits.
+    /// With `--xml-path` or `--css-selector`, the element text or attribute values selected
+    /// in an XML or HTML source are the hits.
+    /// With `--kv-key`, the values of the key in a `.env`, INI, or `.properties` source are the hits,
+    /// only under the `--kv-section` header if given, or else only before the first section header.
+    /// Regexes are matched within each line, or across lines with `--multiline` or `--dotall`.
+    /// With `--lines`, the patterns are matched within each line and each matching line is a hit.
+    /// With `--csv` or `--tsv`, the patterns are matched within each field, only in `--column` if given.
+    /// The first record is a header that is not searched, unless `--no-header` is given.
+    /// With `--invert`, each line without a hit is a hit instead.
+    ///
     /// # Arguments
     ///
     /// * `inputs` - A reference to `ArgMatches` containing the seerd command line arguments.
@@ -23,118 +55,640 @@ impl SearchStrategyFactory {
     ///
     /// # Panics
     ///
-    /// Panics if no valid search strategy is provided.
+    /// Panics if no valid search strategy is provided, the patterns file cannot be read,
+    /// the JSON, YAML, TOML, or XML path or the CSS selector is invalid.
     ///
     /// # Examples
     ///
     /// ```
     /// use clap::{Command, Arg};
-    /// use seer::search_strategies::search_strategy_factory::SearchStrategyFactory;
-    /// use seer::frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory;
-    /// use seer::search_strategies::search_strategy_type::SearchStrategyType;
+    /// use seer::strategy_factories::search_strategy_factory::SearchStrategyFactory;
+    /// use seer::strategy_factories::freq_strategy_factory::FrequencyStrategyFactory;
+    /// use seer::strategies::enums::strategy_type::StrategyType;
     ///
     /// let cmd = Command::new("test")
-    ///     .arg(Arg::new("exact").long("exact").value_name("EXACT").action(clap::ArgAction::Set));
-    /// let matches = cmd.try_get_matches_from(vec!["test", "--exact", "foo"]).unwrap();
+    ///     .arg(Arg::new("find_string").long("find_string").value_name("FIND_STRING").action(clap::ArgAction::Append))
+    ///     .arg(Arg::new("patterns-file").long("patterns-file").value_name("FILE_PATH"))
+    ///     .arg(Arg::new("ignore-case").long("ignore-case").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("smart-case").long("smart-case").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("word").long("word").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("multiline").long("multiline").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("dotall").long("dotall").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("lines").long("lines").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("csv").long("csv").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("tsv").long("tsv").action(clap::ArgAction::SetTrue))
+    ///     .arg(Arg::new("invert").long("invert").action(clap::ArgAction::SetTrue));
+    /// let matches = cmd.try_get_matches_from(vec!["test", "--find_string", "foo"]).unwrap();
     ///
     /// let strategy = SearchStrategyFactory::make(
     ///     &matches,
     ///     FrequencyStrategyFactory::make_for_testing()
     /// );
-    /// assert_eq!(strategy.strategy_type(), SearchStrategyType::Exact);
+    /// assert_eq!(strategy.strategy_type(), StrategyType::FindString);
     /// ```
     pub fn make(
         inputs: &ArgMatches,
         frequency_strategy: Box<dyn FrequencyStrategy>,
     ) -> Box<dyn SearchStrategy> {
-        if let Some(exact) = inputs.get_one::<String>("exact") {
-            return Box::new(ExactSearchStrategy::new(exact.clone(), frequency_strategy));
-        } else if let Some(regex) = inputs.get_one::<String>("regex") {
-            return Box::new(RegexSearchStrategy::new(regex.clone(), frequency_strategy));
-        } else if let Some(between) = inputs.get_many::<String>("between") {
-            let mut between_iter = between.into_iter();
-            let from = between_iter.next().unwrap().clone();
-            let to = between_iter.next().unwrap().clone();
+        if inputs.get_flag("invert") {
+            // The inverted strategy keeps every hit, since the lines without one are what is counted.
+            return Box::new(InvertSearchStrategy::new(
+                Self::make_line_strategy(inputs, Box::new(AllStrategy::new())),
+                frequency_strategy,
+            ));
+        }
+
+        Self::make_line_strategy(inputs, frequency_strategy)
+    }
+
+    // Creates the search strategy for the search patterns, matched within each line with `--lines`
+    // or within each field with `--csv` or `--tsv`.
+    fn make_line_strategy(
+        inputs: &ArgMatches,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Box<dyn SearchStrategy> {
+        if inputs.get_flag("lines") {
+            // The pattern strategy keeps every match, since the lines are what is counted.
+            return Box::new(LineSearchStrategy::new(
+                Self::make_pattern_strategy(inputs, Box::new(AllStrategy::new())),
+                frequency_strategy,
+            ));
+        } else if let Some(delimiter) = Self::make_delimiter(inputs) {
+            // The pattern strategy keeps every match, since the fields to search are picked here.
+            return Box::new(ColumnSearchStrategy::new(
+                Self::make_pattern_strategy(inputs, Box::new(AllStrategy::new())),
+                delimiter,
+                inputs.get_one::<Column>("column").cloned(),
+                !inputs.get_flag("no-header"),
+                frequency_strategy,
+            ));
+        }
+
+        Self::make_pattern_strategy(inputs, frequency_strategy)
+    }
+
+    // Returns the field delimiter of a `--csv` or `--tsv` source.
+    fn make_delimiter(inputs: &ArgMatches) -> Option<char> {
+        if inputs.get_flag("csv") {
+            Some(',')
+        } else if inputs.get_flag("tsv") {
+            Some('\t')
+        } else {
+            None
+        }
+    }
+
+    // Creates the search strategy for the given search patterns.
+    fn make_pattern_strategy(
+        inputs: &ArgMatches,
+        frequency_strategy: Box<dyn FrequencyStrategy>,
+    ) -> Box<dyn SearchStrategy> {
+        let search_modifiers = Self::make_modifiers(inputs);
+
+        if let Some(find_strings) = inputs.get_many::<String>("find_string") {
+            return Box::new(ExactSearchStrategy::new(
+                find_strings.cloned().collect(),
+                search_modifiers,
+                frequency_strategy,
+            ));
+        } else if let Some(find_regexes) = inputs.get_many::<String>("find_regex") {
+            return Box::new(
+                RegexSearchStrategy::new(
+                    find_regexes.cloned().collect(),
+                    search_modifiers,
+                    frequency_strategy,
+                )
+                .unwrap_or_else(|e| panic!("{}", e)),
+            );
+        } else if let Some(find_between) = inputs.get_many::<String>("find_between") {
+            let mut find_between_iter = find_between.into_iter();
+            let from = find_between_iter.next().unwrap().clone();
+            let to = find_between_iter.next().unwrap().clone();
             let exclude_matches = inputs.get_flag("exclude_matches");
-            return Box::new(BetweenSearchStrategy::new(
-                from,
-                to,
-                exclude_matches,
+            let balanced = inputs.get_flag("balanced");
+            let quotes = inputs
+                .get_one::<String>("quotes")
+                .map_or(DEFAULT_QUOTES, String::as_str)
+                .chars()
+                .collect();
+            return Box::new(
+                BetweenSearchStrategy::new(
+                    from,
+                    to,
+                    exclude_matches,
+                    balanced,
+                    quotes,
+                    search_modifiers,
+                    frequency_strategy,
+                )
+                .unwrap_or_else(|e| panic!("{}", e)),
+            );
+        } else if let Some(patterns_file) = inputs.get_one::<String>("patterns-file") {
+            return Box::new(ExactSearchStrategy::new(
+                Self::read_patterns_file(patterns_file),
+                search_modifiers,
+                frequency_strategy,
+            ));
+        } else if let Some(json_path) = inputs.get_one::<String>("json-path") {
+            return Box::new(JsonPathSearchStrategy::new(
+                JsonPath::parse(json_path).unwrap_or_else(|e| panic!("{}", e)),
+                frequency_strategy,
+            ));
+        } else if let Some(yaml_path) = inputs.get_one::<String>("yaml-path") {
+            return Box::new(YamlPathSearchStrategy::new(
+                YamlPath::parse(yaml_path).unwrap_or_else(|e| panic!("{}", e)),
+                frequency_strategy,
+            ));
+        } else if let Some(toml_key) = inputs.get_one::<String>("toml-key") {
+            return Box::new(TomlKeySearchStrategy::new(
+                TomlKey::parse(toml_key).unwrap_or_else(|e| panic!("{}", e)),
+                frequency_strategy,
+            ));
+        } else if let Some(xml_path) = inputs.get_one::<String>("xml-path") {
+            return Box::new(XmlPathSearchStrategy::new(
+                XmlPath::parse(xml_path).unwrap_or_else(|e| panic!("{}", e)),
+                frequency_strategy,
+            ));
+        } else if let Some(css_selector) = inputs.get_one::<String>("css-selector") {
+            return Box::new(CssSelectorSearchStrategy::new(
+                CssSelector::parse(css_selector).unwrap_or_else(|e| panic!("{}", e)),
+                frequency_strategy,
+            ));
+        } else if let Some(kv_key) = inputs.get_one::<String>("kv-key") {
+            return Box::new(KvKeySearchStrategy::new(
+                kv_key.clone(),
+                inputs.get_one::<String>("kv-section").cloned(),
                 frequency_strategy,
             ));
         }
 
         panic!("A search strategy must be provided");
     }
+
+    /// Creates the search modifiers based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the seerd command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// The `SearchModifiers` shared by every search pattern.
+    pub fn make_modifiers(inputs: &ArgMatches) -> SearchModifiers {
+        let case_sensitivity = if inputs.get_flag("ignore-case") {
+            CaseSensitivity::Insensitive
+        } else if inputs.get_flag("smart-case") {
+            CaseSensitivity::Smart
+        } else {
+            CaseSensitivity::Sensitive
+        };
+
+        let mut search_modifiers = SearchModifiers::new(case_sensitivity, inputs.get_flag("word"));
+        search_modifiers.set_multi_line(inputs.get_flag("multiline"));
+        search_modifiers.set_dot_all(inputs.get_flag("dotall"));
+        search_modifiers
+    }
+
+    // Reads one exact string per line, skipping empty lines. The lines are never regexes.
+    fn read_patterns_file(file_path: &str) -> Vec<String> {
+        read_file(file_path)
+            .unwrap_or_else(|e| panic!("{}", e))
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        frequency_strategies::frequency_strategy_factory::FrequencyStrategyFactory,
-        search_strategies::search_strategy_type::SearchStrategyType,
+        strategies::enums::strategy_type::StrategyType,
+        strategy_factories::freq_strategy_factory::FrequencyStrategyFactory,
     };
     use clap::{Arg, Command};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
 
     fn get_command() -> Command {
         Command::new("test")
             .arg(
-                Arg::new("exact")
-                    .long("exact")
-                    .value_name("EXACT")
-                    .action(clap::ArgAction::Set),
+                Arg::new("find_string")
+                    .long("find_string")
+                    .value_name("FIND_STRING")
+                    .action(clap::ArgAction::Append),
             )
             .arg(
-                Arg::new("regex")
-                    .long("regex")
-                    .value_name("REGEX")
-                    .action(clap::ArgAction::Set),
+                Arg::new("find_regex")
+                    .long("find_regex")
+                    .value_name("FIND_REGEX")
+                    .action(clap::ArgAction::Append),
             )
             .arg(
-                Arg::new("between")
-                    .long("between")
+                Arg::new("find_between")
+                    .long("find_between")
                     .num_args(2)
-                    .value_names(&["begin", "end"]),
+                    .value_names(["begin", "end"]),
+            )
+            .arg(
+                Arg::new("patterns-file")
+                    .long("patterns-file")
+                    .value_name("FILE_PATH"),
+            )
+            .arg(
+                Arg::new("json-path")
+                    .long("json-path")
+                    .value_name("JSON_PATH"),
+            )
+            .arg(
+                Arg::new("yaml-path")
+                    .long("yaml-path")
+                    .value_name("YAML_PATH"),
+            )
+            .arg(Arg::new("toml-key").long("toml-key").value_name("TOML_KEY"))
+            .arg(Arg::new("xml-path").long("xml-path").value_name("XML_PATH"))
+            .arg(
+                Arg::new("css-selector")
+                    .long("css-selector")
+                    .value_name("CSS_SELECTOR"),
+            )
+            .arg(Arg::new("kv-key").long("kv-key").value_name("KEY"))
+            .arg(
+                Arg::new("kv-section")
+                    .long("kv-section")
+                    .value_name("SECTION"),
             )
             .arg(
                 Arg::new("exclude_matches")
                     .long("exclude_matches")
                     .action(clap::ArgAction::SetTrue),
             )
+            .arg(
+                Arg::new("balanced")
+                    .long("balanced")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(Arg::new("quotes").long("quotes").value_name("QUOTES"))
+            .arg(
+                Arg::new("ignore-case")
+                    .long("ignore-case")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("smart-case")
+                    .long("smart-case")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("word")
+                    .long("word")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("multiline")
+                    .long("multiline")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("dotall")
+                    .long("dotall")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("lines")
+                    .long("lines")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(Arg::new("csv").long("csv").action(clap::ArgAction::SetTrue))
+            .arg(Arg::new("tsv").long("tsv").action(clap::ArgAction::SetTrue))
+            .arg(
+                Arg::new("column")
+                    .long("column")
+                    .value_name("COLUMN")
+                    .value_parser(clap::value_parser!(Column)),
+            )
+            .arg(
+                Arg::new("no-header")
+                    .long("no-header")
+                    .action(clap::ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("invert")
+                    .long("invert")
+                    .action(clap::ArgAction::SetTrue),
+            )
     }
 
     #[test]
     fn test_exact_search_strategy() {
         let cmd = get_command();
         let matches = cmd
-            .try_get_matches_from(vec!["test", "--exact", "foo"])
+            .try_get_matches_from(vec!["test", "--find_string", "foo"])
             .unwrap();
         let strategy =
             SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
-        assert_eq!(strategy.strategy_type(), SearchStrategyType::Exact);
+        assert_eq!(strategy.strategy_type(), StrategyType::FindString);
     }
 
     #[test]
     fn test_regex_search_strategy() {
         let cmd = get_command();
         let matches = cmd
-            .try_get_matches_from(vec!["test", "--regex", "foo"])
+            .try_get_matches_from(vec!["test", "--find_regex", "foo"])
             .unwrap();
         let strategy =
             SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
-        assert_eq!(strategy.strategy_type(), SearchStrategyType::Regex);
+        assert_eq!(strategy.strategy_type(), StrategyType::FindRegex);
+    }
+
+    #[test]
+    #[should_panic(expected = "unclosed group")]
+    fn test_invalid_regex() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "foo", "--find_regex", "(bar"])
+            .unwrap();
+        SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
     }
 
     #[test]
     fn test_between_search_strategy() {
         let cmd = get_command();
         let matches = cmd
-            .try_get_matches_from(vec!["test", "--between", "foo", "bar"])
+            .try_get_matches_from(vec!["test", "--find_between", "foo", "bar"])
             .unwrap();
         let strategy =
             SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
-        assert_eq!(strategy.strategy_type(), SearchStrategyType::Between);
+        assert_eq!(strategy.strategy_type(), StrategyType::FindBetween);
+    }
+
+    #[test]
+    #[should_panic(expected = "unclosed group")]
+    fn test_invalid_between_regex() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_between", "(", ")"])
+            .unwrap();
+        SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+    }
+
+    #[test]
+    fn test_lines_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "^b", "--lines"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::Lines);
+
+        let searchable = "ab
+bc";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "bc");
+    }
+
+    #[test]
+    fn test_invert_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_string", "b", "--invert"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::Invert);
+
+        let searchable = "ab
+c
+bd";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "c");
+    }
+
+    #[test]
+    fn test_invert_lines_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "^b", "--lines", "--invert"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+
+        let searchable = "ab
+c
+bd";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_value(searchable), "ab");
+        assert_eq!(hits[1].get_value(searchable), "c");
+    }
+
+    #[test]
+    fn test_balanced_between_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--find_between",
+                r"\{",
+                r"\}",
+                "--balanced",
+                "--quotes",
+                "`",
+            ])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        let searchable = "{ `}` { '}' }";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), searchable);
+    }
+
+    #[test]
+    fn test_repeated_find_string_searches_every_string() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_string", "foo", "--find_string", "bar"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        let hits = strategy.search("bar foo");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_pattern_index(), 0);
+    }
+
+    #[test]
+    fn test_repeated_find_regex_searches_every_regex() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--find_regex",
+                r"\d+",
+                "--find_regex",
+                "[a-z]+",
+            ])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::FindRegex);
+        assert_eq!(strategy.search("abc 123").len(), 2);
+    }
+
+    #[test]
+    fn test_patterns_file_search_strategy() {
+        let mut patterns_file = NamedTempFile::new().unwrap();
+        writeln!(patterns_file, "old_api\n\nlegacy_call").unwrap();
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--patterns-file",
+                patterns_file.path().to_str().unwrap(),
+            ])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::FindString);
+
+        let hits = strategy.search("legacy_call(old_api)");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].get_pattern_index(), 1);
+        assert_eq!(hits[1].get_pattern_index(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read file")]
+    fn test_missing_patterns_file() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--patterns-file", "missing_patterns_file"])
+            .unwrap();
+        SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+    }
+
+    #[test]
+    fn test_json_path_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--json-path", "$.services[*].image"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::JsonPath);
+
+        let searchable = r#"{"services": [{"image": "nginx"}]}"#;
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "nginx");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid JSON path 'services'")]
+    fn test_invalid_json_path() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--json-path", "services"])
+            .unwrap();
+        SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+    }
+
+    #[test]
+    fn test_yaml_path_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--yaml-path", "spec.containers[0].image"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::YamlPath);
+
+        let searchable = "spec:\n  containers:\n    - image: nginx # latest\n";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "nginx");
+    }
+
+    #[test]
+    fn test_toml_key_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--toml-key", "dependencies.serde.version"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::TomlKey);
+
+        let searchable = "[dependencies]\nserde.version = \"1.0\"\n";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "1.0");
+    }
+
+    #[test]
+    fn test_xml_path_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--xml-path", "//dependency/version"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::XmlPath);
+
+        let searchable = "<dependency><version>1.0</version></dependency>";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "1.0");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid XML path 'version'")]
+    fn test_invalid_xml_path() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--xml-path", "version"])
+            .unwrap();
+        SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+    }
+
+    #[test]
+    fn test_css_selector_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--css-selector", "a::attr(href)"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::CssSelector);
+
+        let searchable = "<p><a href=\"/home\">Home</a></p>";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "/home");
+    }
+
+    #[test]
+    fn test_kv_key_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--kv-key", "port", "--kv-section", "db"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::KvKey);
+
+        let searchable = "port=80
+[db]
+port=5432
+";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_value(searchable), "5432");
     }
 
     #[test]
@@ -144,4 +698,115 @@ mod tests {
         let matches = cmd.try_get_matches_from(vec!["test"]).unwrap();
         SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
     }
+
+    #[test]
+    fn test_default_modifiers() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_string", "foo"])
+            .unwrap();
+        let modifiers = SearchStrategyFactory::make_modifiers(&matches);
+        assert_eq!(modifiers, SearchModifiers::default());
+    }
+
+    #[test]
+    fn test_ignore_case_and_word_modifiers() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--find_string",
+                "foo",
+                "--ignore-case",
+                "--word",
+            ])
+            .unwrap();
+        let modifiers = SearchStrategyFactory::make_modifiers(&matches);
+        assert_eq!(
+            modifiers.get_case_sensitivity(),
+            CaseSensitivity::Insensitive
+        );
+        assert!(modifiers.is_whole_word());
+    }
+
+    #[test]
+    fn test_smart_case_modifier() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "foo", "--smart-case"])
+            .unwrap();
+        let modifiers = SearchStrategyFactory::make_modifiers(&matches);
+        assert_eq!(modifiers.get_case_sensitivity(), CaseSensitivity::Smart);
+    }
+
+    #[test]
+    fn test_regex_flag_modifiers() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--find_regex",
+                "^foo.",
+                "--multiline",
+                "--dotall",
+            ])
+            .unwrap();
+        let modifiers = SearchStrategyFactory::make_modifiers(&matches);
+        assert!(modifiers.is_multi_line());
+        assert!(modifiers.is_dot_all());
+
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.search("x\nfoo\n").len(), 1);
+    }
+
+    #[test]
+    fn test_column_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "test",
+                "--find_regex",
+                "b",
+                "--csv",
+                "--column",
+                "note",
+            ])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+        assert_eq!(strategy.strategy_type(), StrategyType::Column);
+
+        let searchable = "b,note\nb,\"a,b\"\n";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_position(), 12);
+    }
+
+    #[test]
+    fn test_column_index_search_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "b", "--tsv", "--column", "2"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+
+        let searchable = "b\tb\nb\tab\n";
+        let hits = strategy.search(searchable);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].get_position(), 7);
+    }
+
+    #[test]
+    fn test_column_search_strategy_without_header() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--find_regex", "b", "--csv", "--no-header"])
+            .unwrap();
+        let mut strategy =
+            SearchStrategyFactory::make(&matches, FrequencyStrategyFactory::make_for_testing());
+
+        assert_eq!(strategy.search("b\nb\n").len(), 2);
+    }
 }
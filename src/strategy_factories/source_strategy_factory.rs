@@ -1,9 +1,19 @@
-use super::{
-    dir::DirSourceStrategy, file::FileSourceStrategy, files::FilesSourceStrategy,
-    pipe::PipeSourceStrategy, source_strategy::SourceStrategy, text::TextSourceStrategy,
-};
-use crate::source::source_strategies::source_command_builder::SourceCommandBuilder;
+use crate::helpers::encoding::parse_encoding;
+use crate::strategies::archive_strategy::ArchiveSourceStrategy;
+use crate::strategies::dir_strategy::DirSourceStrategy;
+use crate::strategies::enums::binary_policy::BinaryPolicy;
+use crate::strategies::enums::encoding::Encoding;
+use crate::strategies::enums::git_selection::GitSelection;
+use crate::strategies::enums::mmap_mode::MmapMode;
+use crate::strategies::file_strategy::FileSourceStrategy;
+use crate::strategies::files_strategy::FilesSourceStrategy;
+use crate::strategies::git_rev_strategy::GitRevSourceStrategy;
+use crate::strategies::git_strategy::GitSourceStrategy;
+use crate::strategies::pipe_strategy::PipeSourceStrategy;
+use crate::strategies::text_strategy::TextSourceStrategy;
+use crate::strategies::traits::source_strategy::SourceStrategy;
 use clap::ArgMatches;
+use std::path::PathBuf;
 
 /// Factory for creating source strategies based on command line arguments and pipe content.
 pub struct SourceStrategyFactory;
@@ -11,10 +21,15 @@ pub struct SourceStrategyFactory;
 impl SourceStrategyFactory {
     /// Creates a source strategy based on the provided command line arguments and pipe content.
     ///
+    /// A source given on the command line always wins over piped text, which is only searched
+    /// when no source is given. `--stdin` and `--file -` search stdin explicitly.
+    /// With `--at-rev`, `--dir` and `--files` are read from that git revision instead of the working copy.
+    /// At most one source may be given, which the `source_arg_group` ensures.
+    ///
     /// # Arguments
     ///
     /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
-    /// * `pipe` - An `Option<String>` containing the content of the pipe, if any.
+    /// * `pipe` - Whether text is piped to stdin, rather than stdin being a terminal.
     ///
     /// # Returns
     ///
@@ -22,39 +37,242 @@ impl SourceStrategyFactory {
     ///
     /// # Panics
     ///
-    /// Panics if no valid source strategy is provided.
-    pub fn make(inputs: &ArgMatches, pipe: Option<String>) -> Box<dyn SourceStrategy> {
-        if let Some(pipe_content) = pipe {
-            return Box::new(PipeSourceStrategy::new(pipe_content));
-        } else if inputs.contains_id("dir") {
-            return Box::new(DirSourceStrategy::new(inputs));
-        } else if inputs.contains_id("file") {
-            return Box::new(FileSourceStrategy::new(inputs));
-        } else if inputs.contains_id("files") {
-            return Box::new(FilesSourceStrategy::new(inputs));
-        } else if inputs.contains_id("text") {
-            return Box::new(TextSourceStrategy::new(inputs));
+    /// Panics if no source is given and nothing is piped, or if stdin must be read but is a terminal,
+    /// since reading it would wait for input forever.
+    pub fn make(inputs: &ArgMatches, pipe: bool) -> Box<dyn SourceStrategy> {
+        if inputs.get_flag("stdin") {
+            return Self::make_stdin(pipe, "--stdin");
+        } else if let Some(dir) = inputs.get_one::<String>("dir") {
+            let max_depth = inputs.get_one::<u64>("max-depth").copied();
+            if let Some(rev) = inputs.get_one::<String>("at-rev") {
+                return Box::new(GitRevSourceStrategy::new(
+                    PathBuf::new(),
+                    rev.clone(),
+                    vec![dir.clone()],
+                    max_depth,
+                ));
+            }
+            return Box::new(DirSourceStrategy::new(
+                dir.clone(),
+                max_depth,
+                Self::make_mmap_mode(inputs),
+                inputs.get_flag("search-zip"),
+            ));
+        } else if let Some(file) = inputs.get_one::<String>("file") {
+            if file == "-" {
+                return Self::make_stdin(pipe, "--file -");
+            }
+            return Box::new(FileSourceStrategy::new(
+                file.clone(),
+                Self::make_mmap_mode(inputs),
+                inputs.get_flag("search-zip"),
+            ));
+        } else if let Some(files) = inputs.get_many::<String>("files") {
+            if let Some(rev) = inputs.get_one::<String>("at-rev") {
+                return Box::new(GitRevSourceStrategy::new(
+                    PathBuf::new(),
+                    rev.clone(),
+                    files.cloned().collect(),
+                    None,
+                ));
+            }
+            return Box::new(FilesSourceStrategy::new(
+                files.cloned().collect(),
+                Self::make_mmap_mode(inputs),
+                inputs.get_flag("search-zip"),
+            ));
+        } else if let Some(list_path) = inputs.get_one::<String>("files-from") {
+            if list_path == "-" && !pipe {
+                panic!("{}", Self::get_terminal_message("--files-from -"));
+            }
+            return Box::new(FilesSourceStrategy::from_list(
+                list_path.clone(),
+                inputs.get_flag("null"),
+                Self::make_mmap_mode(inputs),
+                inputs.get_flag("search-zip"),
+            ));
+        } else if let Some(archive) = inputs.get_one::<String>("archive") {
+            return Box::new(ArchiveSourceStrategy::new(archive.clone()));
+        } else if let Some(selection) = Self::make_git_selection(inputs) {
+            return Box::new(GitSourceStrategy::new(
+                PathBuf::new(),
+                selection,
+                inputs.get_flag("git-hunks"),
+                Self::make_mmap_mode(inputs),
+            ));
+        } else if let Some(text) = inputs.get_one::<String>("text") {
+            return Box::new(TextSourceStrategy::new(text.clone()));
+        } else if pipe {
+            return Box::new(PipeSourceStrategy);
+        }
+
+        panic!(
+            "A source strategy must be provided. Give a source like --file, or pipe text to stdin"
+        );
+    }
+
+    // Creates the strategy for stdin, given as the source with `arg`.
+    fn make_stdin(pipe: bool, arg: &str) -> Box<dyn SourceStrategy> {
+        if !pipe {
+            panic!("{}", Self::get_terminal_message(arg));
+        }
+        Box::new(PipeSourceStrategy)
+    }
+
+    // Returns the error for reading stdin with `arg` while stdin is a terminal.
+    fn get_terminal_message(arg: &str) -> String {
+        format!(
+            "{} reads stdin, but stdin is a terminal. Pipe text to seer, or give another source",
+            arg
+        )
+    }
+
+    /// Creates the selection of git changes to search based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// The `GitSelection` given with `--git-changed`, `--git-staged`, or `--git-diff`, or `None` if none was given.
+    pub fn make_git_selection(inputs: &ArgMatches) -> Option<GitSelection> {
+        if inputs.get_flag("git-changed") {
+            Some(GitSelection::Changed)
+        } else if inputs.get_flag("git-staged") {
+            Some(GitSelection::Staged)
+        } else {
+            inputs
+                .get_one::<String>("git-diff")
+                .map(|range| GitSelection::Diff(range.clone()))
+        }
+    }
+
+    /// Creates the policy for sources that look binary based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// The `BinaryPolicy` given with `--binary`, or `BinaryPolicy::Skip` if none was given.
+    pub fn make_binary_policy(inputs: &ArgMatches) -> BinaryPolicy {
+        match inputs.get_one::<String>("binary").map(String::as_str) {
+            Some("text") => BinaryPolicy::Text,
+            Some("report") => BinaryPolicy::Report,
+            _ => BinaryPolicy::Skip,
         }
+    }
 
-        panic!("A source strategy must be provided");
+    /// Creates the encoding to read sources in based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// The `Encoding` given with `--encoding`, or `None` to detect the encoding of each source.
+    pub fn make_encoding(inputs: &ArgMatches) -> Option<Encoding> {
+        inputs
+            .get_one::<String>("encoding")
+            .and_then(|encoding| parse_encoding(encoding))
+    }
+
+    /// Creates the mmap mode for file sources based on the provided command line arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - A reference to `ArgMatches` containing the command line arguments.
+    ///
+    /// # Returns
+    ///
+    /// `MmapMode::Never` with `--edit_in_place` or `--no-mmap`, `MmapMode::Always` with `--mmap`,
+    /// and `MmapMode::Auto` otherwise.
+    /// Edited files are never mapped, since writing one back would change the text still being read from it.
+    pub fn make_mmap_mode(inputs: &ArgMatches) -> MmapMode {
+        if inputs.get_flag("edit_in_place") {
+            MmapMode::Never
+        } else if inputs.get_flag("mmap") {
+            MmapMode::Always
+        } else if inputs.get_flag("no-mmap") {
+            MmapMode::Never
+        } else {
+            MmapMode::Auto
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use clap::Command;
+    use crate::strategies::enums::strategy_type::StrategyType;
+    use clap::{Arg, ArgAction, Command};
 
     fn get_command() -> Command {
-        SourceCommandBuilder::build(Command::new("test"))
+        Command::new("test")
+            .arg(Arg::new("dir").long("dir"))
+            .arg(
+                Arg::new("max-depth")
+                    .long("max-depth")
+                    .value_parser(clap::value_parser!(u64)),
+            )
+            .arg(Arg::new("file").long("file"))
+            .arg(
+                Arg::new("files")
+                    .long("files")
+                    .value_delimiter(',')
+                    .num_args(1..),
+            )
+            .arg(Arg::new("text").long("text"))
+            .arg(Arg::new("archive").long("archive"))
+            .arg(Arg::new("files-from").long("files-from"))
+            .arg(Arg::new("null").long("null").action(ArgAction::SetTrue))
+            .arg(Arg::new("stdin").long("stdin").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("git-changed")
+                    .long("git-changed")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("git-staged")
+                    .long("git-staged")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("git-diff").long("git-diff"))
+            .arg(Arg::new("at-rev").long("at-rev"))
+            .arg(
+                Arg::new("git-hunks")
+                    .long("git-hunks")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("binary").long("binary"))
+            .arg(Arg::new("encoding").long("encoding"))
+            .arg(Arg::new("mmap").long("mmap").action(ArgAction::SetTrue))
+            .arg(
+                Arg::new("no-mmap")
+                    .long("no-mmap")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("search-zip")
+                    .long("search-zip")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("edit_in_place")
+                    .long("edit_in_place")
+                    .action(ArgAction::SetTrue),
+            )
     }
 
     #[test]
     fn test_pipe_source_strategy() {
         let cmd = get_command();
         let matches = cmd.try_get_matches_from(vec!["test"]).unwrap();
-        let strategy = SourceStrategyFactory::make(&matches, Some("pipe content".to_string()));
-        assert_eq!(strategy.strategy_type(), SourceStrategyType::Pipe);
+        let strategy = SourceStrategyFactory::make(&matches, true);
+        assert_eq!(strategy.strategy_type(), StrategyType::Pipe);
     }
 
     #[test]
@@ -63,8 +281,8 @@ mod tests {
         let matches = cmd
             .try_get_matches_from(vec!["test", "--dir", "path/to/dir"])
             .unwrap();
-        let strategy = SourceStrategyFactory::make(&matches, None);
-        assert_eq!(strategy.strategy_type(), SourceStrategyType::Dir);
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::Dir);
     }
 
     #[test]
@@ -73,8 +291,8 @@ mod tests {
         let matches = cmd
             .try_get_matches_from(vec!["test", "--file", "path/to/file"])
             .unwrap();
-        let strategy = SourceStrategyFactory::make(&matches, None);
-        assert_eq!(strategy.strategy_type(), SourceStrategyType::File);
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::File);
     }
 
     #[test]
@@ -83,8 +301,91 @@ mod tests {
         let matches = cmd
             .try_get_matches_from(vec!["test", "--files", "file1.txt,file2.txt"])
             .unwrap();
-        let strategy = SourceStrategyFactory::make(&matches, None);
-        assert_eq!(strategy.strategy_type(), SourceStrategyType::Files);
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::Files);
+        assert_eq!(
+            strategy.get_names().unwrap(),
+            vec!["file1.txt", "file2.txt"]
+        );
+    }
+
+    #[test]
+    fn test_archive_source_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--archive", "bundle.zip"])
+            .unwrap();
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::Archive);
+    }
+
+    #[test]
+    fn test_files_from_source_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--files-from", "-", "--null"])
+            .unwrap();
+        let strategy = SourceStrategyFactory::make(&matches, true);
+        assert_eq!(strategy.strategy_type(), StrategyType::Files);
+    }
+
+    #[test]
+    fn test_given_source_strategy_before_pipe() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--file", "path/to/file"])
+            .unwrap();
+        let strategy = SourceStrategyFactory::make(&matches, true);
+        assert_eq!(strategy.strategy_type(), StrategyType::File);
+    }
+
+    #[test]
+    fn test_stdin_source_strategy() {
+        for args in [vec!["test", "--stdin"], vec!["test", "--file", "-"]] {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            let strategy = SourceStrategyFactory::make(&matches, true);
+            assert_eq!(strategy.strategy_type(), StrategyType::Pipe);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "--stdin reads stdin, but stdin is a terminal")]
+    fn test_stdin_source_strategy_with_terminal() {
+        let cmd = get_command();
+        let matches = cmd.try_get_matches_from(vec!["test", "--stdin"]).unwrap();
+        SourceStrategyFactory::make(&matches, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "--files-from - reads stdin, but stdin is a terminal")]
+    fn test_files_from_stdin_with_terminal() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--files-from", "-"])
+            .unwrap();
+        SourceStrategyFactory::make(&matches, false);
+    }
+
+    #[test]
+    fn test_git_source_strategy() {
+        let cmd = get_command();
+        let matches = cmd
+            .try_get_matches_from(vec!["test", "--git-staged", "--git-hunks"])
+            .unwrap();
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::Git);
+    }
+
+    #[test]
+    fn test_git_rev_source_strategy() {
+        for args in [
+            vec!["test", "--dir", "src", "--at-rev", "v1"],
+            vec!["test", "--files", "a.rs,b.rs", "--at-rev", "v1"],
+        ] {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            let strategy = SourceStrategyFactory::make(&matches, false);
+            assert_eq!(strategy.strategy_type(), StrategyType::GitRev);
+        }
     }
 
     #[test]
@@ -93,8 +394,8 @@ mod tests {
         let matches = cmd
             .try_get_matches_from(vec!["test", "--text", "example text"])
             .unwrap();
-        let strategy = SourceStrategyFactory::make(&matches, None);
-        assert_eq!(strategy.strategy_type(), SourceStrategyType::Text);
+        let strategy = SourceStrategyFactory::make(&matches, false);
+        assert_eq!(strategy.strategy_type(), StrategyType::Text);
     }
 
     #[test]
@@ -102,6 +403,81 @@ mod tests {
     fn test_no_source_strategy() {
         let cmd = get_command();
         let matches = cmd.try_get_matches_from(vec!["test"]).unwrap();
-        SourceStrategyFactory::make(&matches, None);
+        SourceStrategyFactory::make(&matches, false);
+    }
+
+    #[test]
+    fn test_mmap_mode() {
+        let cases = [
+            (vec!["test"], MmapMode::Auto),
+            (vec!["test", "--mmap"], MmapMode::Always),
+            (vec!["test", "--no-mmap"], MmapMode::Never),
+            (vec!["test", "--mmap", "--edit_in_place"], MmapMode::Never),
+        ];
+
+        for (args, mmap_mode) in cases {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            assert_eq!(SourceStrategyFactory::make_mmap_mode(&matches), mmap_mode);
+        }
+    }
+
+    #[test]
+    fn test_binary_policy() {
+        let cases = [
+            (vec!["test"], BinaryPolicy::Skip),
+            (vec!["test", "--binary", "skip"], BinaryPolicy::Skip),
+            (vec!["test", "--binary", "text"], BinaryPolicy::Text),
+            (vec!["test", "--binary", "report"], BinaryPolicy::Report),
+        ];
+
+        for (args, binary_policy) in cases {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            assert_eq!(
+                SourceStrategyFactory::make_binary_policy(&matches),
+                binary_policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        let cases = [
+            (vec!["test"], None),
+            (vec!["test", "--encoding", "auto"], None),
+            (
+                vec!["test", "--encoding", "utf-16be"],
+                Some(Encoding::Utf16Be),
+            ),
+            (
+                vec!["test", "--encoding", "windows-1252"],
+                Some(Encoding::Windows1252),
+            ),
+        ];
+
+        for (args, encoding) in cases {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            assert_eq!(SourceStrategyFactory::make_encoding(&matches), encoding);
+        }
+    }
+
+    #[test]
+    fn test_git_selection() {
+        let cases = [
+            (vec!["test"], None),
+            (vec!["test", "--git-changed"], Some(GitSelection::Changed)),
+            (vec!["test", "--git-staged"], Some(GitSelection::Staged)),
+            (
+                vec!["test", "--git-diff", "main..HEAD"],
+                Some(GitSelection::Diff(String::from("main..HEAD"))),
+            ),
+        ];
+
+        for (args, selection) in cases {
+            let matches = get_command().try_get_matches_from(args).unwrap();
+            assert_eq!(
+                SourceStrategyFactory::make_git_selection(&matches),
+                selection
+            );
+        }
     }
 }